@@ -65,11 +65,11 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         .animation()
         .repeat(Repeat::Infinitely)
         .insert(parallel((
-            snap_rotate(triangles[4].clone(), secs, 7, 4., ease),
-            snap_rotate(triangles[3].clone(), secs, 7, 6., ease),
-            snap_rotate(triangles[2].clone(), secs, 7, 8., ease),
-            snap_rotate(triangles[1].clone(), secs, 7, 10., ease),
-            snap_rotate(triangles[0].clone(), secs, 7, 12., ease),
+            snap_rotate(triangles[4].clone(), secs, 7, 4., ease.clone()),
+            snap_rotate(triangles[3].clone(), secs, 7, 6., ease.clone()),
+            snap_rotate(triangles[2].clone(), secs, 7, 8., ease.clone()),
+            snap_rotate(triangles[1].clone(), secs, 7, 10., ease.clone()),
+            snap_rotate(triangles[0].clone(), secs, 7, 12., ease.clone()),
         )));
 
     let dotted_line_target = AnimationTarget.into_target();
@@ -112,7 +112,7 @@ fn snap_rotate(
             let i = i as f32;
             tween_exact(
                 secs(i / max * dur)..secs((i + 1.) / max * dur),
-                ease,
+                ease.clone(),
                 target.with(angle_z(
                     rev * TAU * (max - i) / max,
                     rev * TAU * (max - i - 1.) / max,
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{parallel, tween},
+    interpolate::{scale, translation_arc},
+    prelude::*,
+};
+
+const COUNTER: Vec3 = Vec3::new(250., 150., 0.);
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultTweenPlugins))
+        .init_resource::<Coins>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (spawn_coin, update_counter))
+        .run();
+}
+
+#[derive(Default, Resource)]
+struct Coins {
+    collected: u32,
+    spawned: u32,
+}
+
+#[derive(Component)]
+struct Counter;
+
+#[derive(Component)]
+struct CoinSpawner(Timer);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Sprite {
+            color: Color::srgb(0.3, 0.3, 0.4),
+            custom_size: Some(Vec2::new(120., 60.)),
+            ..default()
+        },
+        Transform::from_translation(COUNTER - Vec3::Z),
+    ));
+    commands.spawn((
+        Counter,
+        Text2d::new("0"),
+        Transform::from_translation(COUNTER),
+    ));
+    commands.spawn(CoinSpawner(Timer::from_seconds(0.4, TimerMode::Repeating)));
+}
+
+fn spawn_coin(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut coins: ResMut<Coins>,
+    mut q_spawner: Query<&mut CoinSpawner>,
+) {
+    for mut spawner in &mut q_spawner {
+        if !spawner.0.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let x = -300. + (coins.spawned % 5) as f32 * 80.;
+        let start = Vec3::new(x, -200., 1.);
+        coins.spawned += 1;
+
+        let coin = commands
+            .spawn((
+                Sprite {
+                    color: Color::srgb(1., 0.8, 0.2),
+                    custom_size: Some(Vec2::new(24., 24.)),
+                    ..default()
+                },
+                Transform::from_translation(start),
+            ))
+            .id();
+        let target = coin.into_target();
+        commands
+            .entity(coin)
+            .animation()
+            .on_complete(
+                |root: In<Entity>,
+                 mut commands: Commands,
+                 mut coins: ResMut<Coins>| {
+                    coins.collected += 1;
+                    commands.entity(*root).despawn_recursive();
+                },
+            )
+            .insert(parallel((
+                // Linear so the horizontal motion is constant like a thrown coin
+                tween(
+                    Duration::from_secs(1),
+                    EaseKind::Linear,
+                    target.with(translation_arc(
+                        start,
+                        COUNTER.with_z(1.),
+                        250.,
+                    )),
+                ),
+                tween(
+                    Duration::from_secs(1),
+                    EaseKind::QuadraticIn,
+                    target.with(scale(Vec3::ONE, Vec3::splat(0.5))),
+                ),
+            )));
+    }
+}
+
+fn update_counter(
+    coins: Res<Coins>,
+    mut q_counter: Query<&mut Text2d, With<Counter>>,
+) {
+    if !coins.is_changed() {
+        return;
+    }
+    for mut text in &mut q_counter {
+        text.0 = coins.collected.to_string();
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{parallel, sequence, tween},
+    debug_overlay::{TweenDebugOverlay, TweenDebugOverlayPlugin},
+    interpolate::{angle_z, scale, sprite_color, translation},
+    prelude::*,
+};
+
+fn secs(secs: f32) -> Duration {
+    Duration::from_secs_f32(secs)
+}
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            DefaultTweenPlugins,
+            TweenDebugOverlayPlugin,
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, control_overlay)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Text::new(
+            "F1: toggle the overlay\nUp/Down: scroll the overlay\nSpace: spawn another animation",
+        ),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(16.),
+            left: Val::Px(16.),
+            ..default()
+        },
+    ));
+    for i in 0..3 {
+        spawn_animation(&mut commands, i);
+    }
+}
+
+fn spawn_animation(commands: &mut Commands, i: usize) {
+    let x = (i % 8) as f32 * 80. - 280.;
+    let y = -100. - (i / 8) as f32 * 80.;
+    let start = Vec3::new(x, y, 0.);
+    let end = start + Vec3::new(0., 120., 0.);
+    let sprite = commands
+        .spawn((
+            Sprite {
+                custom_size: Some(Vec2::new(50., 50.)),
+                ..default()
+            },
+            Transform::from_translation(start),
+        ))
+        .id()
+        .into_target();
+    let duration = secs(0.5 + (i % 4) as f32 * 0.25);
+    commands
+        .animation()
+        .repeat(Repeat::Infinitely)
+        .repeat_style(RepeatStyle::PingPong)
+        .insert(sequence((
+            parallel((
+                tween(
+                    duration,
+                    EaseKind::QuadraticInOut,
+                    sprite.with(translation(start, end)),
+                ),
+                tween(
+                    duration.mul_f32(0.5),
+                    EaseKind::SineOut,
+                    sprite.with(sprite_color(
+                        Color::WHITE,
+                        Color::srgb(1., 0.5, 0.2),
+                    )),
+                ),
+            )),
+            tween(
+                secs(0.5),
+                EaseKind::BackOut,
+                sprite.with(angle_z(0., std::f32::consts::PI)),
+            ),
+            tween(
+                secs(0.4),
+                EaseKind::CubicOut,
+                sprite.with(scale(Vec3::ONE, Vec3::splat(0.6))),
+            ),
+        )));
+}
+
+fn control_overlay(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<TweenDebugOverlay>,
+    mut spawned: Local<usize>,
+) {
+    if keyboard.just_pressed(KeyCode::F1) {
+        overlay.toggle();
+    }
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        overlay.scroll += 1;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        overlay.scroll = overlay.scroll.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::Space) {
+        spawn_animation(&mut commands, 3 + *spawned);
+        *spawned += 1;
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy_tween::{
     bevy_time_runner::TimeRunnerEnded,
     combinator::{event, forward, sequence},
+    handle::AnimationEnded,
     prelude::*,
 };
 
@@ -26,7 +27,10 @@ fn setup(mut commands: Commands) {
     commands
         .animation()
         .repeat(Repeat::times(5))
-        .insert(forward(Duration::from_secs_f32(0.5)));
+        .insert(forward(Duration::from_secs_f32(0.5)))
+        .observe(|trigger: Trigger<AnimationEnded>| {
+            println!("{} done!", trigger.entity());
+        });
 
     commands.animation().insert(sequence((
         forward(Duration::from_secs_f32(3.)),
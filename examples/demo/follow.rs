@@ -149,7 +149,7 @@ fn jeb_follows_cursor(
             .animation()
             .insert_tween_here(
                 config.tween_duration,
-                config.tween_ease,
+                config.tween_ease.clone(),
                 (
                     jeb.with(translation(
                         jeb_transform.translation,
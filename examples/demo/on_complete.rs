@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{parallel, tween},
+    interpolate::{scale, sprite_color, translation},
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultTweenPlugins))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    for i in 0..5 {
+        let x = -200. + i as f32 * 100.;
+        let start = Vec3::new(x, -200., 0.);
+        let end = Vec3::new(x, 200., 0.);
+        let sprite = commands
+            .spawn((
+                Sprite {
+                    custom_size: Some(Vec2::new(50., 50.)),
+                    ..default()
+                },
+                Transform::from_translation(start),
+            ))
+            .id();
+        let target = sprite.into_target();
+        // The sprite is the animation root so despawning it removes the
+        // animation as well
+        commands
+            .entity(sprite)
+            .animation()
+            .on_complete(|root: In<Entity>, mut commands: Commands| {
+                commands.entity(*root).despawn_recursive();
+            })
+            .insert(parallel((
+                tween(
+                    Duration::from_secs_f32(1. + i as f32 * 0.5),
+                    EaseKind::QuadraticInOut,
+                    target.with(translation(start, end)),
+                ),
+                tween(
+                    Duration::from_secs_f32(1. + i as f32 * 0.5),
+                    EaseKind::QuadraticIn,
+                    (
+                        target.with(scale(Vec3::ONE, Vec3::splat(0.2))),
+                        target.with(sprite_color(
+                            Color::WHITE,
+                            Color::srgba(1., 0.2, 0.2, 0.),
+                        )),
+                    ),
+                ),
+            )));
+    }
+}
@@ -0,0 +1,77 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::tween,
+    interpolate::{rotation, scale, transform, translation},
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultTweenPlugins))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn sprite(start: Transform, color: Color) -> (Sprite, Transform) {
+    (
+        Sprite {
+            custom_size: Some(Vec2::new(50., 50.)),
+            color,
+            ..default()
+        },
+        start,
+    )
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let secs = 2.;
+    let ease = EaseKind::CubicInOut;
+    let pose = |y: f32| {
+        (
+            Transform::from_xyz(-300., y, 0.),
+            Transform::from_xyz(300., y, 0.)
+                .with_rotation(Quat::from_rotation_z(PI))
+                .with_scale(Vec3::splat(2.)),
+        )
+    };
+
+    // One tween entity driving the whole transform
+    let (start, end) = pose(100.);
+    let single = commands
+        .spawn(sprite(start, Color::WHITE))
+        .id()
+        .into_target();
+    commands
+        .animation()
+        .repeat(Repeat::Infinitely)
+        .repeat_style(RepeatStyle::PingPong)
+        .insert(tween(
+            Duration::from_secs_f32(secs),
+            ease.clone(),
+            single.with(transform(start, end)),
+        ));
+
+    // The same animation with three tween entities
+    let (start, end) = pose(-100.);
+    let separate = commands
+        .spawn(sprite(start, Color::srgb(0.5, 0.5, 0.5)))
+        .id()
+        .into_target();
+    commands
+        .animation()
+        .repeat(Repeat::Infinitely)
+        .repeat_style(RepeatStyle::PingPong)
+        .insert_tween_here(
+            Duration::from_secs_f32(secs),
+            ease,
+            (
+                separate.with(translation(start.translation, end.translation)),
+                separate.with(rotation(start.rotation, end.rotation)),
+                separate.with(scale(start.scale, end.scale)),
+            ),
+        );
+}
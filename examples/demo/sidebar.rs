@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy_tween::{
+    bevy_time_runner::TimeRunner, interpolate::node_width, prelude::*,
+};
+
+fn secs(secs: f32) -> Duration {
+    Duration::from_secs_f32(secs)
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultTweenPlugins))
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle)
+        .run();
+}
+
+#[derive(Component)]
+struct SidebarAnimator;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            ..default()
+        })
+        .with_children(|c| {
+            let mut sidebar = c.spawn((
+                Node {
+                    width: Val::Px(0.),
+                    height: Val::Percent(100.),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                SidebarAnimator,
+            ));
+            let target = sidebar.id().into_target();
+            // Stays closed until toggled
+            sidebar
+                .animation()
+                .direction(TimeDirection::Backward)
+                .insert_tween_here(
+                    secs(0.4),
+                    EaseKind::CubicOut,
+                    target.with(node_width(Val::Px(0.), Val::Px(300.))),
+                );
+            sidebar.with_children(|c| {
+                c.spawn((
+                    Text::new("Sidebar"),
+                    Node {
+                        margin: UiRect::all(Val::Px(16.)),
+                        ..default()
+                    },
+                ));
+            });
+            c.spawn((
+                Text::new("Press Space to toggle the sidebar"),
+                Node {
+                    margin: UiRect::all(Val::Px(16.)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn toggle(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut q_animator: Query<&mut TimeRunner, With<SidebarAnimator>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+    for mut time_runner in &mut q_animator {
+        let direction = match time_runner.direction() {
+            TimeDirection::Forward => TimeDirection::Backward,
+            TimeDirection::Backward => TimeDirection::Forward,
+        };
+        time_runner.set_direction(direction);
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{stagger, tween},
+    interpolate::{sprite_color, translation},
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultTweenPlugins))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let duration = Duration::from_millis(400);
+    let ease = EaseKind::QuarticOut;
+    let mut fade_in = |i: usize| {
+        let x = (i as f32 - 4.5) * 60.;
+        let start = Vec3::new(x, -40., 0.);
+        let end = Vec3::new(x, 0., 0.);
+        let item = commands
+            .spawn((
+                Sprite {
+                    custom_size: Some(Vec2::new(50., 50.)),
+                    color: Color::NONE,
+                    ..default()
+                },
+                Transform::from_translation(start),
+            ))
+            .id()
+            .into_target();
+        tween(
+            duration,
+            ease.clone(),
+            (
+                item.with(sprite_color(Color::NONE, Color::WHITE)),
+                item.with(translation(start, end)),
+            ),
+        )
+    };
+    let items = (
+        fade_in(0),
+        fade_in(1),
+        fade_in(2),
+        fade_in(3),
+        fade_in(4),
+        fade_in(5),
+        fade_in(6),
+        fade_in(7),
+        fade_in(8),
+        fade_in(9),
+    );
+
+    commands
+        .animation()
+        .repeat(Repeat::Infinitely)
+        .repeat_style(RepeatStyle::PingPong)
+        .insert(stagger(Duration::from_millis(50), items));
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy_tween::{
+    bevy_time_runner::TimeRunner, interpolate::text_font_size, prelude::*,
+};
+
+fn secs(secs: f32) -> Duration {
+    Duration::from_secs_f32(secs)
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultTweenPlugins))
+        .add_systems(Startup, setup)
+        .add_systems(Update, hover)
+        .run();
+}
+
+#[derive(Component)]
+struct LabelAnimator;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|c| {
+            c.spawn((Button, Node::default())).with_children(|c| {
+                let mut label = c.spawn((
+                    Text::new("Hover me"),
+                    TextFont::from_font_size(32.),
+                    LabelAnimator,
+                ));
+                let target = label.id().into_target();
+                // Stays at the start until hovered
+                label
+                    .animation()
+                    .direction(TimeDirection::Backward)
+                    .insert_tween_here(
+                        secs(0.3),
+                        EaseKind::BackOut,
+                        target.with(text_font_size(32., 48.)),
+                    );
+            });
+        });
+}
+
+fn hover(
+    q_button: Query<(&Interaction, &Children), Changed<Interaction>>,
+    mut q_animator: Query<&mut TimeRunner, With<LabelAnimator>>,
+) {
+    for (interaction, children) in &q_button {
+        let Ok(mut time_runner) = q_animator.get_mut(children[0]) else {
+            continue;
+        };
+        time_runner.set_direction(match interaction {
+            Interaction::Hovered | Interaction::Pressed => {
+                TimeDirection::Forward
+            }
+            Interaction::None => TimeDirection::Backward,
+        });
+    }
+}
@@ -0,0 +1,194 @@
+//! Module containing implementations for pulsing to a beat
+//!
+//! # Beat
+//!
+//! **Plugins**:
+//! - [`BeatPulsePlugin`]
+//!
+//! **Resources**:
+//! - [`Beat`]
+//!
+//! **Components**:
+//! - [`BeatPulse`]
+//!
+//! **Systems**
+//! - [`beat_pulse_system`]
+//!
+//! Rhythm games usually drive visuals from beats detected or scheduled by
+//! their own audio code rather than from fixed timelines. Beats are events so
+//! a pulse isn't a tween with a fixed start: [`beat_pulse_system`] restarts
+//! the pulse of every [`BeatPulse`] whenever the [`Beat`] resource reports a
+//! new beat and decays it back on its own.
+//!
+//! # Resource contract
+//!
+//! [`Beat`] is provided by the user:
+//! - Insert it as a resource. Without it, nothing pulses.
+//! - Call [`Beat::beat`] once on every beat before the tween schedule runs
+//!   (`PostUpdate` by default), for example, in `Update`.
+//! - Multiple beats in the same frame restart pulses once.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::interpolation::EaseKind;
+
+/// Plugin for [`beat_pulse_system`].
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+pub struct BeatPulsePlugin;
+
+impl Plugin for BeatPulsePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist");
+        app.add_systems(
+            app_resource.schedule,
+            beat_pulse_system.in_set(crate::TweenSystemSet::ApplyTween),
+        )
+        .register_type::<Beat>()
+        .register_type::<BeatPulse>();
+    }
+}
+
+/// Beats counted by the user's audio code.
+/// See the [module documentation](self#resource-contract) for the contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct Beat {
+    count: u64,
+}
+
+impl Beat {
+    /// Report a beat
+    pub fn beat(&mut self) {
+        self.count = self.count.wrapping_add(1);
+    }
+
+    /// Number of beats reported so far
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// Pulse [`Transform`]'s scale to `peak` on every [`Beat`] then decay back to
+/// `base` over `decay`.
+///
+/// Scale is only set while decaying so other tweens may change it between
+/// beats. The pulse doesn't start on a beat reported before this component
+/// was added.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::beat::{Beat, BeatPulse, BeatPulsePlugin};
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_plugins(BeatPulsePlugin).init_resource::<Beat>();
+///
+/// let peak = Vec3::splat(1.5);
+/// let entity = app
+///     .world_mut()
+///     .spawn((
+///         Transform::default(),
+///         BeatPulse::new(Vec3::ONE, peak, Duration::from_millis(200)),
+///     ))
+///     .id();
+///
+/// let mut pulses = 0;
+/// for frame in 0..17 {
+///     if [2, 7, 12].contains(&frame) {
+///         app.world_mut().resource_mut::<Beat>().beat();
+///     }
+///     app.update();
+///     let scale = app.world().get::<Transform>(entity).unwrap().scale;
+///     if scale == peak {
+///         pulses += 1;
+///     }
+/// }
+/// assert_eq!(pulses, 3);
+///
+/// // Decayed back to the base
+/// let scale = app.world().get::<Transform>(entity).unwrap().scale;
+/// assert_eq!(scale, Vec3::ONE);
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct BeatPulse {
+    /// Scale at rest
+    pub base: Vec3,
+    /// Scale on a beat
+    pub peak: Vec3,
+    /// Duration to decay from `peak` to `base`
+    pub decay: Duration,
+    /// Ease of the decay
+    pub ease: EaseKind,
+    elapsed: Option<Duration>,
+    last_beat: Option<u64>,
+}
+
+impl BeatPulse {
+    /// Create a new [`BeatPulse`] decaying with [`EaseKind::QuadraticOut`]
+    pub fn new(base: Vec3, peak: Vec3, decay: Duration) -> BeatPulse {
+        BeatPulse {
+            base,
+            peak,
+            decay,
+            ease: EaseKind::QuadraticOut,
+            elapsed: None,
+            last_beat: None,
+        }
+    }
+
+    /// Decay with the provided ease instead
+    pub fn with_ease(mut self, ease: EaseKind) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Returns `true` if currently decaying from a beat
+    pub fn is_pulsing(&self) -> bool {
+        self.elapsed.is_some()
+    }
+}
+
+/// Restart [`BeatPulse`]s on new [`Beat`]s and decay their scale
+pub fn beat_pulse_system(
+    beat: Option<Res<Beat>>,
+    time: Res<Time>,
+    mut q_pulse: Query<(&mut BeatPulse, &mut Transform)>,
+) {
+    let Some(beat) = beat else {
+        return;
+    };
+    for (mut pulse, mut transform) in &mut q_pulse {
+        if pulse.last_beat.is_some_and(|last| last != beat.count) {
+            pulse.elapsed = Some(Duration::ZERO);
+        }
+        pulse.last_beat = Some(beat.count);
+
+        let Some(elapsed) = pulse.elapsed else {
+            continue;
+        };
+        let ratio = if pulse.decay.is_zero() {
+            1.
+        } else {
+            (elapsed.as_secs_f32() / pulse.decay.as_secs_f32()).min(1.)
+        };
+        transform.scale = pulse.peak.lerp(pulse.base, pulse.ease.sample(ratio));
+        pulse.elapsed = if ratio >= 1. {
+            None
+        } else {
+            Some(elapsed + time.delta())
+        };
+    }
+}
@@ -0,0 +1,506 @@
+//! Module containing animations authored as assets
+//!
+//! # Animation clip
+//!
+//! **Plugins**:
+//! - [`AnimationClipPlugin`]
+//!
+//! **Assets**:
+//! - [`AnimationClip`] loaded by [`AnimationClipLoader`]
+//!
+//! **Components**:
+//! - [`AnimationClipPlayer`]
+//! - [`AnimationClipStarted`]
+//!
+//! **Systems**
+//! - [`animation_clip_player_system`]
+//!
+//! Requires the `"serde"` and `"bevy_asset"` features.
+//!
+//! [`AnimationClip`] describes an animation as data, for example, in a
+//! `.tween.ron` file, instead of combinators written in code. Every
+//! [`AnimationTrack`] becomes a tween with the same entity structure
+//! [`tween_exact`](crate::combinator::tween_exact) creates: a child of the
+//! animation's entity with a [`TimeSpan`], an [`EaseKind`] and a
+//! [`ComponentTween`](crate::tween::ComponentTween).
+//!
+//! ```ron
+//! (
+//!     tracks: [
+//!         (
+//!             target: Root,
+//!             start: 0.0,
+//!             end: 1.0,
+//!             ease: QuadraticOut,
+//!             interpolator: Translation(
+//!                 start: (0.0, 0.0, 0.0),
+//!                 end: (100.0, 0.0, 0.0),
+//!             ),
+//!         ),
+//!         (
+//!             target: Name("shadow"),
+//!             start: 0.5,
+//!             end: 1.5,
+//!             ease: Linear,
+//!             interpolator: Scale(
+//!                 start: (1.0, 1.0, 1.0),
+//!                 end: (2.0, 2.0, 1.0),
+//!             ),
+//!         ),
+//!     ],
+//! )
+//! ```
+//!
+//! # Interpolators
+//!
+//! Interpolators are generic types which can't be deserialized from a name
+//! alone, so a track picks one of the built-in interpolators listed in
+//! [`TrackInterpolator`]. Animations using custom interpolators still have to
+//! be built with combinators.
+//!
+//! # Targets
+//!
+//! A track targets the entity the clip is played on with [`TrackTarget::Root`]
+//! or one of its descendants by [`Name`] with [`TrackTarget::Name`]. Targets
+//! are resolved once when the animation is spawned, so a descendant spawned
+//! later isn't animated. Tracks whose target isn't found are skipped.
+
+use std::time::Duration;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use bevy_time_runner::TimeSpan;
+use serde::{Deserialize, Serialize};
+
+use crate::combinator::{AnimationBuilderExt, AnimationCommands};
+use crate::interpolate;
+use crate::interpolation::EaseKind;
+use crate::tween::IntoTarget;
+
+/// Plugin for loading [`AnimationClip`]s and playing them with
+/// [`AnimationClipPlayer`].
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins), and [`AssetPlugin`].
+pub struct AnimationClipPlugin;
+
+impl Plugin for AnimationClipPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist");
+        app.add_systems(
+            app_resource.schedule,
+            animation_clip_player_system
+                .before(bevy_time_runner::TimeRunnerSet::TickTimer),
+        )
+        .init_asset::<AnimationClip>()
+        .init_asset_loader::<AnimationClipLoader>()
+        .register_type::<AnimationClipPlayer>()
+        .register_type::<AnimationClipStarted>();
+    }
+}
+
+/// Animation described as data. See the [module](self) for the format.
+#[derive(
+    Debug, Default, Clone, PartialEq, Asset, TypePath, Serialize, Deserialize,
+)]
+pub struct AnimationClip {
+    /// Tracks of the clip, each becomes a tween
+    pub tracks: Vec<AnimationTrack>,
+}
+
+impl AnimationClip {
+    /// Deserialize a clip from RON
+    pub fn from_ron(
+        ron: &str,
+    ) -> Result<AnimationClip, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Length of the clip which is the latest end of its tracks
+    pub fn length(&self) -> Duration {
+        self.tracks
+            .iter()
+            .filter_map(|track| Duration::try_from_secs_f32(track.end).ok())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Combinator for spawning the tracks starting at the current position.
+    /// `resolve` finds the entity of every [`TrackTarget`] or returns `None`
+    /// to skip its tracks, [`animation_clip_player_system`] resolves them
+    /// [against the root](self#targets).
+    ///
+    /// Position is at the end of the clip, see [`AnimationClip::length`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_tween::bevy_time_runner::{TimeRunner, TimeSpan};
+    /// use bevy_tween::clip::{AnimationClip, TrackTarget};
+    /// use bevy_tween::combinator::{forward, sequence};
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let clip = AnimationClip::from_ron(
+    ///     "(
+    ///         tracks: [
+    ///             (
+    ///                 target: Root,
+    ///                 start: 0.0,
+    ///                 end: 1.0,
+    ///                 ease: QuadraticOut,
+    ///                 interpolator: Translation(start: (0.0, 0.0, 0.0), end: (1.0, 0.0, 0.0)),
+    ///             ),
+    ///             (
+    ///                 target: Name(\"missing\"),
+    ///                 start: 0.0,
+    ///                 end: 2.0,
+    ///                 ease: Linear,
+    ///                 interpolator: AngleZ(start: 0.0, end: 1.0),
+    ///             ),
+    ///         ],
+    ///     )",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(clip.length(), Duration::from_secs(2));
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Transform::default()).id();
+    /// world.commands().entity(entity).animation().insert(sequence((
+    ///     forward(Duration::from_secs(1)),
+    ///     clip.animation(|target| match target {
+    ///         TrackTarget::Root => Some(entity),
+    ///         TrackTarget::Name(_) => None,
+    ///     }),
+    /// )));
+    /// world.flush();
+    ///
+    /// // The track with a missing target is skipped but the clip keeps its length
+    /// let children = world.get::<Children>(entity).unwrap();
+    /// assert_eq!(children.len(), 1);
+    /// assert_eq!(
+    ///     world.get::<TimeSpan>(children[0]),
+    ///     Some(
+    ///         &TimeSpan::try_from(Duration::from_secs(1)..Duration::from_secs(2))
+    ///             .unwrap()
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     world.get::<EaseKind>(children[0]),
+    ///     Some(&EaseKind::QuadraticOut)
+    /// );
+    /// let runner = world.get::<TimeRunner>(entity).unwrap();
+    /// assert_eq!(runner.length(), Duration::from_secs(3));
+    /// ```
+    pub fn animation(
+        &self,
+        mut resolve: impl FnMut(&TrackTarget) -> Option<Entity>,
+    ) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+        let length = self.length();
+        let tracks = self
+            .tracks
+            .iter()
+            .map(|track| (track.clone(), resolve(&track.target)))
+            .collect::<Vec<_>>();
+        move |a, pos| {
+            for (track, target) in tracks {
+                let Some(target) = target else {
+                    warn!(
+                        "Target {:?} of an animation clip's track isn't found, the track is skipped",
+                        track.target
+                    );
+                    continue;
+                };
+                let Some(span) = track.span(*pos) else {
+                    warn!(
+                        "Animation clip's track spanning {}..{} seconds is invalid, the track is skipped",
+                        track.start, track.end
+                    );
+                    continue;
+                };
+                track.interpolator.spawn(a, span, track.ease, target);
+            }
+            *pos += length;
+        }
+    }
+}
+
+/// Tween of an [`AnimationClip`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnimationTrack {
+    /// Entity to animate
+    pub target: TrackTarget,
+    /// Start of the tween in seconds from the start of the clip
+    pub start: f32,
+    /// End of the tween in seconds from the start of the clip
+    pub end: f32,
+    /// Ease of the tween
+    pub ease: EaseKind,
+    /// What to animate
+    pub interpolator: TrackInterpolator,
+}
+
+impl AnimationTrack {
+    /// Span of the track in a clip starting at `offset` or `None` if the
+    /// track doesn't end after it starts
+    pub fn span(&self, offset: Duration) -> Option<TimeSpan> {
+        let start = Duration::try_from_secs_f32(self.start).ok()?;
+        let end = Duration::try_from_secs_f32(self.end).ok()?;
+        TimeSpan::try_from(offset + start..offset + end).ok()
+    }
+}
+
+/// Entity targeted by an [`AnimationTrack`], see [targets](self#targets)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TrackTarget {
+    /// Entity the clip is played on
+    Root,
+    /// Descendant of the root with this [`Name`]
+    Name(String),
+}
+
+/// Built-in interpolator of an [`AnimationTrack`], see
+/// [interpolators](self#interpolators)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum TrackInterpolator {
+    /// [`interpolate::Translation`]
+    Translation { start: Vec3, end: Vec3 },
+    /// [`interpolate::Rotation`]
+    Rotation { start: Quat, end: Quat },
+    /// [`interpolate::Scale`]
+    Scale { start: Vec3, end: Vec3 },
+    /// [`interpolate::AngleZ`]
+    AngleZ { start: f32, end: f32 },
+    /// [`interpolate::SpriteColor`]
+    #[cfg(feature = "bevy_sprite")]
+    SpriteColor { start: Color, end: Color },
+}
+
+impl TrackInterpolator {
+    fn spawn(
+        self,
+        a: &mut AnimationCommands,
+        span: TimeSpan,
+        ease: EaseKind,
+        target: Entity,
+    ) {
+        let target = target.into_target();
+        match self {
+            TrackInterpolator::Translation { start, end } => {
+                a.spawn((
+                    span,
+                    ease,
+                    target.with(interpolate::translation(start, end)),
+                ));
+            }
+            TrackInterpolator::Rotation { start, end } => {
+                a.spawn((
+                    span,
+                    ease,
+                    target.with(interpolate::rotation(start, end)),
+                ));
+            }
+            TrackInterpolator::Scale { start, end } => {
+                a.spawn((
+                    span,
+                    ease,
+                    target.with(interpolate::scale(start, end)),
+                ));
+            }
+            TrackInterpolator::AngleZ { start, end } => {
+                a.spawn((
+                    span,
+                    ease,
+                    target.with(interpolate::angle_z(start, end)),
+                ));
+            }
+            #[cfg(feature = "bevy_sprite")]
+            TrackInterpolator::SpriteColor { start, end } => {
+                a.spawn((
+                    span,
+                    ease,
+                    target.with(interpolate::sprite_color(start, end)),
+                ));
+            }
+        }
+    }
+}
+
+/// [`AssetLoader`] for [`AnimationClip`]s in RON with the `.tween.ron`
+/// extension
+#[derive(Debug, Default)]
+pub struct AnimationClipLoader;
+
+/// Error from [`AnimationClipLoader`]
+#[derive(Debug)]
+pub enum AnimationClipLoaderError {
+    /// The file couldn't be read
+    Io(std::io::Error),
+    /// The file isn't a valid clip
+    Ron(ron::error::SpannedError),
+}
+
+impl core::error::Error for AnimationClipLoaderError {}
+
+impl core::fmt::Display for AnimationClipLoaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Couldn't read the animation clip: {e}"),
+            Self::Ron(e) => write!(f, "Couldn't parse the animation clip: {e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for AnimationClipLoaderError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for AnimationClipLoaderError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        Self::Ron(e)
+    }
+}
+
+impl AssetLoader for AnimationClipLoader {
+    type Asset = AnimationClip;
+    type Settings = ();
+    type Error = AnimationClipLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationClip, AnimationClipLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tween.ron"]
+    }
+}
+
+/// Play an [`AnimationClip`] on this entity once it's loaded.
+///
+/// [`animation_clip_player_system`] inserts the clip's animation like
+/// [`AnimationBuilder::insert`](crate::combinator::AnimationBuilder::insert)
+/// so this entity becomes the animation's [`TimeRunner`] and the clip plays
+/// once. [`AnimationClipStarted`] is inserted alongside so a finished or
+/// canceled clip doesn't start over. Remove [`AnimationClipStarted`] to play
+/// the clip again.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::{TimeRunner, TimeSpan};
+/// use bevy_tween::clip::{
+///     AnimationClipPlayer, AnimationClipPlugin, AnimationClipStarted,
+/// };
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_plugins((AssetPlugin::default(), AnimationClipPlugin));
+///
+/// let clip = app
+///     .world()
+///     .resource::<AssetServer>()
+///     .load("slide_in.tween.ron");
+/// let root = app
+///     .world_mut()
+///     .spawn((Transform::default(), AnimationClipPlayer(clip)))
+///     .with_child((Name::new("shadow"), Transform::default()))
+///     .id();
+///
+/// for _ in 0..500 {
+///     if app.world().get::<TimeRunner>(root).is_some() {
+///         break;
+///     }
+///     app.update();
+///     std::thread::sleep(Duration::from_millis(1));
+/// }
+/// assert!(app.world().get::<TimeRunner>(root).is_some());
+///
+/// let secs = Duration::from_secs_f32;
+/// let mut q_tween = app.world_mut().query::<(&Parent, &TimeSpan, &EaseKind)>();
+/// let mut tweens = q_tween
+///     .iter(app.world())
+///     .filter(|(parent, ..)| parent.get() == root)
+///     .map(|(_, span, ease)| (*span, ease.clone()))
+///     .collect::<Vec<_>>();
+/// tweens.sort_by_key(|(span, _)| span.min().duration());
+/// assert_eq!(
+///     tweens,
+///     [
+///         (
+///             TimeSpan::try_from(secs(0.)..secs(1.)).unwrap(),
+///             EaseKind::QuadraticOut
+///         ),
+///         (
+///             TimeSpan::try_from(secs(0.5)..secs(1.5)).unwrap(),
+///             EaseKind::Linear
+///         ),
+///     ]
+/// );
+///
+/// // Removing the animation doesn't start the clip over
+/// app.world_mut().entity_mut(root).remove::<TimeRunner>();
+/// app.update();
+/// assert!(app.world().get::<TimeRunner>(root).is_none());
+/// assert!(app.world().get::<AnimationClipStarted>(root).is_some());
+/// ```
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[derive(Debug, Default, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AnimationClipPlayer(pub Handle<AnimationClip>);
+
+/// Marker for [`AnimationClipPlayer`] entities whose clip has been inserted.
+/// Inserted by [`animation_clip_player_system`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AnimationClipStarted;
+
+/// Insert the animation of loaded [`AnimationClip`]s into
+/// [`AnimationClipPlayer`] entities without [`AnimationClipStarted`]
+pub fn animation_clip_player_system(
+    mut commands: Commands,
+    clips: Res<Assets<AnimationClip>>,
+    q_player: Query<
+        (Entity, &AnimationClipPlayer),
+        Without<AnimationClipStarted>,
+    >,
+    q_children: Query<&Children>,
+    q_name: Query<&Name>,
+) {
+    for (entity, player) in &q_player {
+        let Some(clip) = clips.get(&player.0) else {
+            continue;
+        };
+        let animation = clip.animation(|target| match target {
+            TrackTarget::Root => Some(entity),
+            TrackTarget::Name(name) => {
+                q_children.iter_descendants(entity).find(|descendant| {
+                    q_name.get(*descendant).is_ok_and(|n| n.as_str() == name)
+                })
+            }
+        });
+        commands
+            .entity(entity)
+            .insert(AnimationClipStarted)
+            .animation()
+            .insert(animation);
+    }
+}
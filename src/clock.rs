@@ -0,0 +1,160 @@
+//! Module containing the clock ticking animations
+//!
+//! # Clock
+//!
+//! **Resources**:
+//! - [`TweenClock`]
+//!
+//! **Components**:
+//! - [`TweenClock`]
+//!
+//! **Systems**
+//! - [`tween_clock_scale_system`]
+//! - [`tween_clock_restore_system`]
+//!
+//! [`TimeRunner`]s are ticked by [`bevy_time_runner`] with [`Time<Real>`] so
+//! animations keep running while the game is paused through
+//! [`Time<Virtual>`]. [`TweenClock`] selects the clock an animation follows
+//! instead:
+//! - The [`TweenClock`] resource is the default of every animation and is
+//!   [`TweenClock::Real`] unless changed.
+//! - The [`TweenClock`] component on an animation root overrides the resource
+//!   for that animation, for example, to keep UI animations on real time
+//!   while gameplay animations pause with virtual time.
+//!
+//! Animations following [`TweenClock::Virtual`] advance by the virtual delta
+//! time, so they freeze while [`Time<Virtual>`] is paused and follow its
+//! relative speed. This is done by [`tween_clock_scale_system`] scaling the
+//! [`TimeRunner`]'s time scale by the ratio of the virtual and real delta
+//! times only while it's ticked, then [`tween_clock_restore_system`]
+//! restores the time scale so it can be read and changed as usual.
+//!
+//! # Examples
+//!
+//! ```
+//! use bevy_tween::bevy_time_runner::TimeRunner;
+//! use bevy_tween::clock::TweenClock;
+//! use bevy_tween::combinator::tween;
+//! use bevy_tween::interpolate::translation;
+//!
+#![doc = crate::utils::doc_app_test_boilerplate!()]
+//! app.insert_resource(TweenClock::Virtual);
+//!
+//! let mut spawn = |clock: Option<TweenClock>| {
+//!     let entity = app.world_mut().spawn(Transform::default()).id();
+//!     let root = {
+//!         let mut commands = app.world_mut().commands();
+//!         let mut root = commands.animation().insert(tween(
+//!             Duration::from_secs(10),
+//!             EaseKind::Linear,
+//!             entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+//!         ));
+//!         if let Some(clock) = clock {
+//!             root.insert(clock);
+//!         }
+//!         root.id()
+//!     };
+//!     app.world_mut().flush();
+//!     (root, entity)
+//! };
+//! let (gameplay, gameplay_target) = spawn(None);
+//! let (_, ui_target) = spawn(Some(TweenClock::Real));
+//!
+//! let x = |app: &App, entity| {
+//!     app.world().get::<Transform>(entity).unwrap().translation.x
+//! };
+//! for _ in 0..5 {
+//!     app.update();
+//! }
+//!
+//! app.world_mut().resource_mut::<Time<Virtual>>().pause();
+//! let (gameplay_x, ui_x) = (x(&app, gameplay_target), x(&app, ui_target));
+//! for _ in 0..5 {
+//!     app.update();
+//! }
+//! // Gameplay is paused while UI keeps running
+//! assert_eq!(x(&app, gameplay_target), gameplay_x);
+//! assert!(x(&app, ui_target) > ui_x);
+//! let time_runner = app.world().get::<TimeRunner>(gameplay).unwrap();
+//! assert_eq!(time_runner.time_scale(), 1.);
+//!
+//! app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+//! for _ in 0..5 {
+//!     app.update();
+//! }
+//! assert!(x(&app, gameplay_target) > gameplay_x);
+//! ```
+
+use bevy::prelude::*;
+use bevy_time_runner::{SkipTimeRunner, TimeRunner};
+
+/// Clock ticking animations.
+/// See the [module documentation](self) for how it's selected.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Resource,
+    Component,
+    Reflect,
+)]
+#[reflect(Resource, Component)]
+pub enum TweenClock {
+    /// Follow [`Time<Real>`], running while the game is paused
+    #[default]
+    Real,
+    /// Follow [`Time<Virtual>`], pausing and changing speed with the game
+    Virtual,
+}
+
+/// Time scales of [`TimeRunner`]s changed by [`tween_clock_scale_system`]
+/// to be restored by [`tween_clock_restore_system`]
+#[derive(Debug, Default, Resource)]
+pub struct TweenClockScaled(Vec<(Entity, f32)>);
+
+/// Scale the time scale of [`TimeRunner`]s following [`TweenClock::Virtual`]
+/// by the ratio of the virtual and real delta times before they're ticked.
+pub fn tween_clock_scale_system(
+    clock: Option<Res<TweenClock>>,
+    real: Res<Time<Real>>,
+    virtual_time: Res<Time<Virtual>>,
+    mut scaled: ResMut<TweenClockScaled>,
+    mut q_time_runner: Query<
+        (Entity, &mut TimeRunner, Option<&TweenClock>),
+        Without<SkipTimeRunner>,
+    >,
+) {
+    let default_clock = clock.as_deref().copied().unwrap_or_default();
+    let real_delta = real.delta_secs();
+    let virtual_ratio = if real_delta > 0. {
+        virtual_time.delta_secs() / real_delta
+    } else {
+        1.
+    };
+    for (entity, mut time_runner, clock) in &mut q_time_runner {
+        let ratio = match clock.copied().unwrap_or(default_clock) {
+            TweenClock::Real => continue,
+            TweenClock::Virtual => virtual_ratio,
+        };
+        let time_scale = time_runner.time_scale();
+        scaled.0.push((entity, time_scale));
+        time_runner.set_time_scale(time_scale * ratio);
+    }
+}
+
+/// Restore the time scales changed by [`tween_clock_scale_system`] after
+/// [`TimeRunner`]s are ticked.
+pub fn tween_clock_restore_system(
+    mut scaled: ResMut<TweenClockScaled>,
+    mut q_time_runner: Query<&mut TimeRunner>,
+) {
+    for (entity, time_scale) in scaled.0.drain(..) {
+        if let Ok(mut time_runner) = q_time_runner.get_mut(entity) {
+            time_runner.set_time_scale(time_scale);
+        }
+    }
+}
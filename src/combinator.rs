@@ -2,35 +2,244 @@
 
 use std::time::Duration;
 
-use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy::{ecs::system::EntityCommands, prelude::*, utils::HashMap};
 use bevy_time_runner::{
     Repeat, RepeatStyle, SkipTimeRunner, TimeDirection, TimeRunner, TimeSpan,
+    TimeSpanProgress,
 };
 
 mod animation_combinators;
+mod registry;
 mod state;
 pub use animation_combinators::*;
+pub use registry::{AnimationFactory, AnimationRegistry};
 pub use state::{TargetState, TransformTargetState, TransformTargetStateExt};
 
+use crate::interpolation::{DefaultEase, EaseKind};
+use crate::tween::{
+    AnimationSeed, AnimationTarget, EaseFirstPlayOnly, FirstCycleOnly,
+    PrewarmTween, RetainFinalValue, SkipTween, TargetGracePeriod,
+    TimelineMarkers, TweenInterpolationValue,
+};
+use crate::tween_event::AnyTweenEvent;
+use crate::utils::splitmix64;
+
 /// Commands to use within an animation combinator
 pub struct AnimationCommands<'r, 'a> {
     child_builder: &'r mut ChildBuilder<'a>,
+    spawn_markers: SpawnMarkers,
+    default_ease: Option<EaseKind>,
+    seed: u64,
+    seed_noise: bool,
+    markers: HashMap<String, Duration>,
+    recorded: Option<Vec<Entity>>,
+    spawned: usize,
 }
 
 impl<'r, 'a> AnimationCommands<'r, 'a> {
     pub(crate) fn new(
         child_builder: &'r mut ChildBuilder<'a>,
     ) -> AnimationCommands<'r, 'a> {
-        AnimationCommands { child_builder }
+        AnimationCommands {
+            child_builder,
+            spawn_markers: SpawnMarkers::default(),
+            default_ease: None,
+            seed: 0,
+            seed_noise: false,
+            markers: HashMap::default(),
+            recorded: None,
+            spawned: 0,
+        }
+    }
+
+    /// Draw the next seed from a deterministic stream started from
+    /// [`AnimationBuilder::with_seed`].
+    ///
+    /// [`Self::spawn`] already draws from this to seed [`EaseKind::Noise`] in
+    /// seeded animations. Use this for other randomness, such as custom
+    /// noise-based interpolators, so every tween gets a different but
+    /// reproducible seed.
+    ///
+    /// If the animation isn't seeded, the stream is started from the bits of
+    /// the animation root [`Entity`] so animations built without a seed still
+    /// draw different seeds from each other. These aren't reproducible across
+    /// runs, use [`AnimationBuilder::with_seed`] for that.
+    ///
+    /// ```
+    /// use bevy_tween::combinator::AnimationCommands;
+    #[doc = crate::utils::doc_test_boilerplate!()]
+    ///
+    /// let mut seeds = Vec::new();
+    /// for _ in 0..2 {
+    ///     commands
+    ///         .animation()
+    ///         .insert(|a: &mut AnimationCommands, _: &mut Duration| {
+    ///             seeds.push(a.next_seed());
+    ///         });
+    /// }
+    /// assert_ne!(seeds[0], seeds[1]);
+    /// ```
+    pub fn next_seed(&mut self) -> u64 {
+        splitmix64(&mut self.seed)
     }
 
     /// Spawn an entity as a child.
     /// Currently always spawn as a child of animation root that should contains [`bevy_time_runner::TimeRunner`].
+    ///
+    /// Inside [`with_ease_per_segment`], the shared [`EaseKind`] is inserted
+    /// to the spawned entity as [`DefaultEase`].
+    /// Inside [`skipped`], [`SkipTween`] is inserted to the spawned entity.
+    /// Inside [`first_cycle_only`], [`FirstCycleOnly`] is inserted to the
+    /// spawned entity.
+    /// In an animation seeded with [`AnimationBuilder::with_seed`], a seed
+    /// drawn from [`Self::next_seed`] is mixed into every [`EaseKind::Noise`]
+    /// of the spawned entity's [`EaseKind`] or [`DefaultEase`].
+    ///
+    /// The spawned entity is treated as a tween and gets the tween markers
+    /// configured on [`AnimationBuilder`], such as [`PrewarmTween`]. Use
+    /// [`Self::spawn_event`] for entities without a tween.
     pub fn spawn(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
-        self.child_builder.spawn(bundle)
+        let spawn_markers = self.spawn_markers;
+        let default_ease = self.default_ease.clone();
+        let noise_seed = self.seed_noise.then(|| self.next_seed());
+        let mut entity_commands = self.spawn_recorded(bundle);
+        spawn_markers.insert(&mut entity_commands, true);
+        if let Some(ease) = default_ease {
+            entity_commands.insert(DefaultEase(ease));
+        }
+        if let Some(noise_seed) = noise_seed {
+            entity_commands.queue(reseed_noise(noise_seed));
+        }
+        entity_commands
+    }
+
+    /// Spawn an entity without a tween as a child, for example, a
+    /// [`TweenEventData`](crate::tween_event::TweenEventData) entity.
+    ///
+    /// Like [`Self::spawn`] but only [`SkipTween`] and [`FirstCycleOnly`] are
+    /// inserted since the other markers only affect tweens.
+    ///
+    /// ```
+    /// use bevy_tween::combinator::{event, parallel, tween};
+    /// use bevy_tween::interpolate::translation;
+    /// use bevy_tween::tween::PrewarmTween;
+    #[doc = crate::utils::doc_world_test_boilerplate!()]
+    /// let root = {
+    ///     let mut commands = Commands::new(&mut queue, &world);
+    ///     let target = commands.spawn(Transform::default()).id().into_target();
+    ///     commands
+    ///         .animation()
+    ///         .prewarm(true)
+    ///         .insert(parallel((
+    ///             tween(
+    ///                 Duration::from_secs(1),
+    ///                 EaseKind::Linear,
+    ///                 target.with(translation(Vec3::ZERO, Vec3::X)),
+    ///             ),
+    ///             event("started"),
+    ///         )))
+    ///         .id()
+    /// };
+    /// queue.apply(&mut world);
+    ///
+    /// let children = world.get::<Children>(root).unwrap();
+    /// assert!(world.get::<PrewarmTween>(children[0]).is_some());
+    /// assert!(world.get::<PrewarmTween>(children[1]).is_none());
+    /// ```
+    pub fn spawn_event(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
+        let spawn_markers = self.spawn_markers;
+        let mut entity_commands = self.spawn_recorded(bundle);
+        spawn_markers.insert(&mut entity_commands, false);
+        entity_commands
+    }
+
+    fn spawn_recorded(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
+        self.spawned += 1;
+        let entity_commands = self.child_builder.spawn(bundle);
+        if let Some(recorded) = &mut self.recorded {
+            recorded.push(entity_commands.id());
+        }
+        entity_commands
+    }
+}
+
+/// Markers inserted to every entity spawned by an animation
+#[derive(Debug, Default, Clone, Copy)]
+struct SpawnMarkers {
+    prewarm: bool,
+    ease_first_play_only: bool,
+    retain_final_value: bool,
+    target_grace_period: Option<TargetGracePeriod>,
+    skip_tweens: bool,
+    first_cycle_only: bool,
+}
+
+/// Mix `seed` into the noise eases of an entity
+fn reseed_noise(seed: u64) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity| {
+        if let Some(mut ease) = entity.get_mut::<EaseKind>() {
+            ease.reseed_noise(seed);
+        }
+        if let Some(mut ease) = entity.get_mut::<DefaultEase>() {
+            ease.0.reseed_noise(seed);
+        }
+    }
+}
+
+impl SpawnMarkers {
+    /// Insert the markers to an entity. Markers that only affect tweens are
+    /// skipped unless `tween` is true.
+    fn insert(&self, entity_commands: &mut EntityCommands, tween: bool) {
+        if tween {
+            if self.prewarm {
+                entity_commands.insert(PrewarmTween);
+            }
+            if self.ease_first_play_only {
+                entity_commands.insert(EaseFirstPlayOnly::default());
+            }
+            if self.retain_final_value {
+                entity_commands.insert(RetainFinalValue);
+            }
+            if let Some(target_grace_period) = self.target_grace_period {
+                entity_commands.insert(target_grace_period);
+            }
+        }
+        if self.skip_tweens {
+            entity_commands.insert(SkipTween);
+        }
+        if self.first_cycle_only {
+            entity_commands.insert(FirstCycleOnly);
+        }
     }
 }
 
+/// Boxed animation combinator.
+///
+/// Every combinator is a distinct closure type so they can't be stored together
+/// in a collection. Box them to store heterogeneous animations in a [`Vec`]
+/// and insert them later with [`AnimationBuilder::insert`], which accepts
+/// boxed combinators because `Box<dyn FnOnce(..)>` implements [`FnOnce`].
+///
+/// ```
+/// use bevy_tween::combinator::{event, forward, BoxedAnimation};
+///
+/// let mut animations: Vec<BoxedAnimation> = vec![
+///     Box::new(forward(Duration::from_secs(1))),
+///     Box::new(event("done")),
+/// ];
+///
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     commands.animation().insert(animations.pop().unwrap()).id()
+/// };
+/// queue.apply(&mut world);
+///
+/// assert_eq!(world.get::<Children>(root).unwrap().len(), 1);
+/// ```
+pub type BoxedAnimation =
+    Box<dyn FnOnce(&mut AnimationCommands, &mut Duration) + 'static>;
+
 /// Extension trait for types that can be used to make an animation.
 pub trait AnimationBuilderExt {
     /// Construct [`AnimationBuilder`] from [`Self`]
@@ -68,6 +277,8 @@ pub struct AnimationBuilder<'a> {
     time_runner: Option<TimeRunner>,
     custom_length: Option<Duration>,
     skipped: bool,
+    spawn_markers: SpawnMarkers,
+    seed: Option<u64>,
 }
 impl<'a> AnimationBuilder<'a> {
     /// Create new [`AnimationBuilder`]
@@ -77,9 +288,121 @@ impl<'a> AnimationBuilder<'a> {
             time_runner: None,
             custom_length: None,
             skipped: false,
+            spawn_markers: SpawnMarkers::default(),
+            seed: None,
         }
     }
 
+    /// Get the animation root [`Entity`] before inserting the animation.
+    ///
+    /// The root entity already exists when the builder is created so its id
+    /// can be used by combinators passed to [`Self::insert`], for example,
+    /// to make the animation target itself with [`TargetComponent::Entity`].
+    /// Tweens are spawned as children of this entity.
+    ///
+    /// ```
+    /// use bevy_tween::combinator::tween;
+    /// use bevy_tween::interpolate::{translation, Translation};
+    /// use bevy_tween::tween::TargetComponent;
+    #[doc = crate::utils::doc_world_test_boilerplate!()]
+    /// let root = {
+    ///     let mut commands = Commands::new(&mut queue, &world);
+    ///     let mut entity_commands = commands.spawn(Transform::default());
+    ///     let animation = entity_commands.animation();
+    ///     let root = animation.id();
+    ///     animation.insert(tween(
+    ///         Duration::from_secs(1),
+    ///         EaseKind::Linear,
+    ///         root.into_target().with(translation(Vec3::ZERO, Vec3::ONE)),
+    ///     ));
+    ///     root
+    /// };
+    /// queue.apply(&mut world);
+    ///
+    /// let children = world.get::<Children>(root).unwrap();
+    /// let tween = world
+    ///     .get::<ComponentTween<Translation>>(children[0])
+    ///     .unwrap();
+    /// assert_eq!(tween.target, TargetComponent::Entity(root));
+    /// ```
+    ///
+    /// [`TargetComponent::Entity`]: crate::tween::TargetComponent::Entity
+    pub fn id(&self) -> Entity {
+        self.entity_commands.id()
+    }
+
+    /// Get an [`AnimationHandle`] resolving when this animation completes.
+    ///
+    /// This inserts [`AnimationCompletion`] to the animation root right away.
+    /// See [`AnimationHandle`] for awaiting and canceling the animation.
+    ///
+    /// [`AnimationHandle`]: crate::handle::AnimationHandle
+    /// [`AnimationCompletion`]: crate::handle::AnimationCompletion
+    pub fn handle(&mut self) -> crate::handle::AnimationHandle {
+        let (completion, handle) =
+            crate::handle::AnimationCompletion::with_handle();
+        self.entity_commands.insert(completion);
+        handle
+    }
+
+    /// Run `system` once with the animation root as input when the animation
+    /// completes with all repeats exhausted, for example, to despawn the
+    /// animated entity.
+    ///
+    /// The system is registered right away and stored in
+    /// [`OnAnimationComplete`] on the animation root. Calling this again
+    /// replaces the previous system. The system doesn't run if the root is
+    /// despawned before completing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_tween::combinator::tween;
+    /// use bevy_tween::interpolate::translation;
+    ///
+    /// #[derive(Default, Resource)]
+    /// struct Completed(Vec<Entity>);
+    ///
+    #[doc = crate::utils::doc_app_test_boilerplate!()]
+    /// app.init_resource::<Completed>();
+    ///
+    /// let entity = app.world_mut().spawn(Transform::default()).id();
+    /// let root = app
+    ///     .world_mut()
+    ///     .commands()
+    ///     .animation()
+    ///     .repeat(Repeat::times(1))
+    ///     .on_complete(
+    ///         |root: In<Entity>, mut completed: ResMut<Completed>| {
+    ///             completed.0.push(*root);
+    ///         },
+    ///     )
+    ///     .insert(tween(
+    ///         Duration::from_millis(500),
+    ///         EaseKind::Linear,
+    ///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+    ///     ))
+    ///     .id();
+    /// app.world_mut().flush();
+    /// for _ in 0..20 {
+    ///     app.update();
+    /// }
+    ///
+    /// // Ran once after the repeat
+    /// assert_eq!(app.world().resource::<Completed>().0, [root]);
+    /// ```
+    ///
+    /// [`OnAnimationComplete`]: crate::handle::OnAnimationComplete
+    pub fn on_complete<M>(
+        mut self,
+        system: impl IntoSystem<In<Entity>, (), M> + 'static,
+    ) -> Self {
+        let system = self.entity_commands.commands().register_system(system);
+        self.entity_commands
+            .insert(crate::handle::OnAnimationComplete(system));
+        self
+    }
+
     /// Get the inner [`EntityCommands`]
     pub fn entity_commands(&mut self) -> &mut EntityCommands<'a> {
         &mut self.entity_commands
@@ -95,6 +418,63 @@ impl<'a> AnimationBuilder<'a> {
         &mut self.time_runner
     }
 
+    /// Clear the previous animation of this entity before inserting the new
+    /// one, so an animation root can be reused without leftover tweens piling
+    /// up. See [`clear_animation`].
+    ///
+    /// ```
+    /// use bevy_tween::combinator::{parallel, tween};
+    /// use bevy_tween::interpolate::{scale, translation};
+    #[doc = crate::utils::doc_world_test_boilerplate!()]
+    /// let (root, unrelated) = {
+    ///     let mut commands = Commands::new(&mut queue, &world);
+    ///     let target = commands.spawn(Transform::default()).id().into_target();
+    ///     let unrelated = commands.spawn_empty().id();
+    ///     let root = commands
+    ///         .animation()
+    ///         .insert(parallel((
+    ///             tween(
+    ///                 Duration::from_secs(1),
+    ///                 EaseKind::Linear,
+    ///                 target.with(translation(Vec3::ZERO, Vec3::X)),
+    ///             ),
+    ///             tween(
+    ///                 Duration::from_secs(1),
+    ///                 EaseKind::Linear,
+    ///                 target.with(scale(Vec3::ONE, Vec3::ZERO)),
+    ///             ),
+    ///         )))
+    ///         .add_child(unrelated)
+    ///         .id();
+    ///     (root, unrelated)
+    /// };
+    /// queue.apply(&mut world);
+    /// let old: Vec<Entity> = world.get::<Children>(root).unwrap().to_vec();
+    /// assert_eq!(old.len(), 3);
+    ///
+    /// {
+    ///     let mut commands = Commands::new(&mut queue, &world);
+    ///     let target = commands.spawn(Transform::default()).id().into_target();
+    ///     commands.entity(root).animation().clear().insert(tween(
+    ///         Duration::from_secs(2),
+    ///         EaseKind::Linear,
+    ///         target.with(translation(Vec3::X, Vec3::ZERO)),
+    ///     ));
+    /// }
+    /// queue.apply(&mut world);
+    ///
+    /// let children = world.get::<Children>(root).unwrap();
+    /// assert_eq!(children.len(), 2);
+    /// assert!(children.contains(&unrelated));
+    /// for entity in old.into_iter().filter(|entity| *entity != unrelated) {
+    ///     assert!(world.get_entity(entity).is_err());
+    /// }
+    /// ```
+    pub fn clear(mut self) -> Self {
+        self.entity_commands.queue(clear_animation);
+        self
+    }
+
     /// Configure [`TimeRunner`]'s [`Repeat`]
     pub fn repeat(mut self, repeat: Repeat) -> Self {
         let time_runner = self.time_runner_or_default();
@@ -138,6 +518,152 @@ impl<'a> AnimationBuilder<'a> {
         self
     }
 
+    /// Spawn every tween with [`SkipTween`] so they're present but don't
+    /// tween until the marker is removed.
+    ///
+    /// Unlike [`Self::skipped`] and [`Self::paused`], the [`TimeRunner`] keeps
+    /// running and removing [`SkipTween`] from a tween resumes it at the
+    /// current time. See [`skipped`] to skip only some of the tweens.
+    pub fn skip_tweens(mut self, skip_tweens: bool) -> Self {
+        self.spawn_markers.skip_tweens = skip_tweens;
+        self
+    }
+
+    /// Apply the start value of tweens starting at the beginning of the
+    /// animation as soon as they're spawned.
+    ///
+    /// Tweens are only applied after the [`TimeRunner`] has ticked which may
+    /// show the pre-start value for a frame, for example, when the animation
+    /// is paused or spawned after the tween systems already ran.
+    /// This inserts [`PrewarmTween`] to every spawned tween so they're sampled
+    /// at `0.` in [`TweenSystemSet::UpdateInterpolationValue`] and applied in
+    /// [`TweenSystemSet::ApplyTween`] of the next run of the tween schedule,
+    /// which is the same frame if spawned before it (`PostUpdate` by default).
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_tween::prelude::*;
+    /// use bevy_tween::interpolate::translation;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, DefaultTweenPlugins));
+    ///
+    /// let start = Vec3::new(10., 0., 0.);
+    /// let entity = app.world_mut().spawn(Transform::default()).id();
+    /// app.world_mut()
+    ///     .commands()
+    ///     .entity(entity)
+    ///     .animation()
+    ///     .paused(true)
+    ///     .prewarm(true)
+    ///     .insert_tween_here(
+    ///         Duration::from_secs(1),
+    ///         EaseKind::Linear,
+    ///         entity.into_target().with(translation(start, Vec3::ZERO)),
+    ///     );
+    /// app.world_mut().flush();
+    /// app.update();
+    ///
+    /// let transform = app.world().get::<Transform>(entity).unwrap();
+    /// assert_eq!(transform.translation, start);
+    /// ```
+    ///
+    /// [`TweenSystemSet::UpdateInterpolationValue`]: crate::TweenSystemSet::UpdateInterpolationValue
+    /// [`TweenSystemSet::ApplyTween`]: crate::TweenSystemSet::ApplyTween
+    pub fn prewarm(mut self, prewarm: bool) -> Self {
+        self.spawn_markers.prewarm = prewarm;
+        self
+    }
+
+    /// Ease tweens only on their first play and snap them to the end on
+    /// replays, for example, UI that should animate in the first time it's
+    /// shown but appear instantly afterward.
+    ///
+    /// This inserts [`EaseFirstPlayOnly`] to every spawned tween.
+    pub fn ease_first_play_only(mut self, ease_first_play_only: bool) -> Self {
+        self.spawn_markers.ease_first_play_only = ease_first_play_only;
+        self
+    }
+
+    /// Keep applying the final value of tweens after the animation completed
+    /// instead of stopping.
+    ///
+    /// This inserts [`RetainFinalValue`] to every spawned tween. Apply systems
+    /// keep running for them and tweens in the middle of a sequence will fight
+    /// with later tweens targeting the same component, see
+    /// [`RetainFinalValue`] for the tradeoff.
+    pub fn retain_final_value(mut self, retain_final_value: bool) -> Self {
+        self.spawn_markers.retain_final_value = retain_final_value;
+        self
+    }
+
+    /// Silently wait for tween targets to exist for the provided grace period
+    /// before logging errors, for example, when targets are spawned
+    /// asynchronously.
+    ///
+    /// This inserts the [`TargetGracePeriod`] to every spawned tween.
+    pub fn target_grace_period(
+        mut self,
+        target_grace_period: TargetGracePeriod,
+    ) -> Self {
+        self.spawn_markers.target_grace_period = Some(target_grace_period);
+        self
+    }
+
+    /// Seed this animation for deterministic noise-based tweens.
+    ///
+    /// This inserts [`AnimationSeed`] to the animation root and starts the
+    /// stream of [`AnimationCommands::next_seed`] from it. Every tween spawned
+    /// with [`AnimationCommands::spawn`] gets a seed drawn from the stream
+    /// mixed into its [`EaseKind::Noise`], so noisy tweens differ from each
+    /// other and building the same animation with the same seed produces
+    /// identical motion.
+    ///
+    /// ```
+    /// use bevy::ecs::world::CommandQueue;
+    /// use bevy::prelude::*;
+    /// use bevy_tween::combinator::{sequence, tween};
+    /// use bevy_tween::interpolate::translation;
+    /// use bevy_tween::prelude::*;
+    ///
+    /// fn shake(world: &mut World, seed: u64) -> Vec<f32> {
+    ///     let mut queue = CommandQueue::default();
+    ///     let root = {
+    ///         let mut commands = Commands::new(&mut queue, world);
+    ///         let target = commands.spawn(Transform::default()).id().into_target();
+    ///         let noisy = || {
+    ///             tween(
+    ///                 Duration::from_secs(1),
+    ///                 EaseKind::Noise { seed: 0, octaves: 3 },
+    ///                 target.clone().with(translation(Vec3::ZERO, Vec3::X)),
+    ///             )
+    ///         };
+    ///         commands
+    ///             .animation()
+    ///             .with_seed(seed)
+    ///             .insert(sequence((noisy(), noisy())))
+    ///             .id()
+    ///     };
+    ///     queue.apply(world);
+    ///     world
+    ///         .get::<Children>(root)
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|child| world.get::<EaseKind>(*child).unwrap().sample(0.3))
+    ///         .collect()
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let first = shake(&mut world, 42);
+    /// assert_ne!(first[0], first[1]);
+    /// assert_eq!(first, shake(&mut world, 42));
+    /// assert_ne!(first, shake(&mut world, 7));
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// [`Self::paused`] and [`Self::skipped`]
     pub fn disabled(self, disabled: bool) -> Self {
         self.paused(disabled).skipped(disabled)
@@ -152,12 +678,93 @@ impl<'a> AnimationBuilder<'a> {
     /// Configure [`TimeRunner`]'s time scale to adjust animation speed.
     /// Negative scale cause animation play in the opposite of [`TimeDirection`] and
     /// [`Repeat`] counter will tick backward.
+    ///
+    /// For example, `2.` fast-forwards at double speed and `0.5` plays in
+    /// slow-motion. A scale of `0.` doesn't advance the animation, similar to
+    /// [`Self::paused`] except that the tweens are still applied every frame.
+    ///
+    /// ```
+    /// use bevy_tween::combinator::tween;
+    /// use bevy_tween::interpolate::translation;
+    #[doc = crate::utils::doc_app_test_boilerplate!()]
+    ///
+    /// let mut spawn = |scale: f32| {
+    ///     let entity = app.world_mut().spawn(Transform::default()).id();
+    ///     app.world_mut()
+    ///         .commands()
+    ///         .animation()
+    ///         .time_scale(scale)
+    ///         .insert(tween(
+    ///             Duration::from_secs(10),
+    ///             EaseKind::Linear,
+    ///             entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+    ///         ));
+    ///     entity
+    /// };
+    /// let normal = spawn(1.);
+    /// let fast = spawn(2.);
+    /// let stopped = spawn(0.);
+    /// app.world_mut().flush();
+    /// for _ in 0..5 {
+    ///     app.update();
+    /// }
+    ///
+    /// let x = |entity| app.world().get::<Transform>(entity).unwrap().translation.x;
+    /// assert!(x(normal) > 0.);
+    /// assert!((x(fast) - x(normal) * 2.).abs() < 1e-5);
+    /// assert_eq!(x(stopped), 0.);
+    /// ```
     pub fn time_scale(mut self, scale: f32) -> Self {
         self.time_runner_or_default().set_time_scale(scale);
         self
     }
 
     /// Configure [`TimeRunner`]'s direction to play animation backward or forward.
+    ///
+    /// An animation inserted with [`TimeDirection::Backward`] starts at its
+    /// end and plays toward the start, for example, to visualize an undo.
+    /// A [`paused`](Self::paused) animation stays at the end until unpaused.
+    /// When repeating, [`RepeatStyle::WrapAround`] jumps back to the end and
+    /// [`RepeatStyle::PingPong`] plays forward on its second play.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_tween::bevy_time_runner::TimeRunner;
+    /// use bevy_tween::combinator::tween;
+    /// use bevy_tween::interpolate::translation;
+    #[doc = crate::utils::doc_app_test_boilerplate!()]
+    ///
+    /// let entity = app.world_mut().spawn(Transform::default()).id();
+    /// let root = app
+    ///     .world_mut()
+    ///     .commands()
+    ///     .animation()
+    ///     .direction(TimeDirection::Backward)
+    ///     .insert(tween(
+    ///         Duration::from_secs(1),
+    ///         EaseKind::Linear,
+    ///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+    ///     ))
+    ///     .id();
+    /// app.world_mut().flush();
+    ///
+    /// let time_runner = app.world().get::<TimeRunner>(root).unwrap();
+    /// assert_eq!(time_runner.direction(), TimeDirection::Backward);
+    ///
+    /// // Starts from the end
+    /// app.update();
+    /// let mut x = app.world().get::<Transform>(entity).unwrap().translation.x;
+    /// assert!(x >= 0.9);
+    ///
+    /// for _ in 0..15 {
+    ///     app.update();
+    ///     let new_x = app.world().get::<Transform>(entity).unwrap().translation.x;
+    ///     assert!(new_x <= x);
+    ///     x = new_x;
+    /// }
+    /// assert_eq!(x, 0.);
+    /// ```
     pub fn direction(mut self, direction: TimeDirection) -> Self {
         self.time_runner_or_default().set_direction(direction);
         self
@@ -173,6 +780,48 @@ impl<'a> AnimationBuilder<'a> {
     /// [`Self::length`].
     /// It's also possible to use combinator like [`go`], [`forward`], and [`backward`]
     /// as the last combinator to customize the length.
+    /// Boxed combinators ([`BoxedAnimation`]) are accepted as well.
+    ///
+    /// In debug builds, a warning is logged if the animation ends up with zero
+    /// length and no entities were spawned by the combinator, which is usually
+    /// a mistake such as an empty [`sequence`] or [`parallel`].
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use bevy::ecs::world::CommandQueue;
+    /// use bevy::log::tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    /// use bevy::log::tracing_subscriber::Registry;
+    /// use bevy::log::Level;
+    /// use bevy::prelude::*;
+    /// use bevy::utils::tracing::{self, Event, Subscriber};
+    /// use bevy_tween::prelude::*;
+    ///
+    /// struct CountWarnings(Arc<AtomicUsize>);
+    ///
+    /// impl<S: Subscriber> Layer<S> for CountWarnings {
+    ///     fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+    ///         if *event.metadata().level() == Level::WARN {
+    ///             self.0.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let warnings = Arc::new(AtomicUsize::new(0));
+    /// let subscriber = Registry::default().with(CountWarnings(warnings.clone()));
+    ///
+    /// let world = World::new();
+    /// let mut queue = CommandQueue::default();
+    /// tracing::subscriber::with_default(subscriber, || {
+    ///     let mut commands = Commands::new(&mut queue, &world);
+    ///     commands.animation().insert(|_, _| {});
+    /// });
+    ///
+    /// # if cfg!(debug_assertions) {
+    /// assert_eq!(warnings.load(Ordering::Relaxed), 1);
+    /// # }
+    /// ```
     pub fn insert<F>(self, animation: F) -> EntityCommands<'a>
     where
         F: FnOnce(&mut AnimationCommands, &mut Duration),
@@ -182,28 +831,113 @@ impl<'a> AnimationBuilder<'a> {
             time_runner,
             custom_length,
             skipped,
+            spawn_markers,
+            seed,
         } = self;
         let mut dur = Duration::ZERO;
+        let mut spawned = 0;
+        let mut markers = HashMap::default();
+        let root = entity_commands.id();
         entity_commands.with_children(|c| {
             let mut a = AnimationCommands::new(c);
+            a.spawn_markers = spawn_markers;
+            a.seed = seed.unwrap_or(root.to_bits());
+            a.seed_noise = seed.is_some();
             animation(&mut a, &mut dur);
+            spawned = a.spawned;
+            markers = std::mem::take(&mut a.markers);
         });
         let mut time_runner = time_runner.unwrap_or_default();
-        match custom_length {
-            Some(length) => {
-                time_runner.set_length(length);
-            }
-            None => {
-                time_runner.set_length(dur);
-            }
+        let length = custom_length.unwrap_or(dur);
+        time_runner.set_length(length);
+        start_from_direction(&mut time_runner);
+        #[cfg(debug_assertions)]
+        if length.is_zero() && spawned == 0 {
+            warn!(
+                "Animation {:?} has zero length and no tweens were spawned so it does nothing. \
+                The combinator passed to `insert` might be empty or spawn entities without `AnimationCommands::spawn`.",
+                entity_commands.id()
+            );
         }
+        #[cfg(not(debug_assertions))]
+        let _ = spawned;
         entity_commands.insert(time_runner);
+        if !markers.is_empty() {
+            entity_commands.insert(TimelineMarkers(markers));
+        }
         if skipped {
             entity_commands.insert(SkipTimeRunner);
         }
+        if let Some(seed) = seed {
+            entity_commands.insert(AnimationSeed(seed));
+        }
         entity_commands
     }
 
+    /// Insert an animation whose tweens animate this entity itself.
+    ///
+    /// An entity can only have one [`TimeSpan`] and one tween of each type so
+    /// the tweens can't all be inserted to this entity like
+    /// [`Self::insert_tween_here`] does. Instead, this entity gets
+    /// [`AnimationTarget`] and the animation is inserted like [`Self::insert`],
+    /// spawning the tweens as a flat list of children. Tweens targeting
+    /// [`AnimationTarget`] with [`TargetComponent::Marker`] then animate this
+    /// entity. [`TimeRunner`]'s length is the position the animation ends at
+    /// unless use [`Self::length`].
+    ///
+    /// [`TargetComponent::Marker`]: crate::tween::TargetComponent::Marker
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_tween::bevy_time_runner::TimeRunner;
+    /// use bevy_tween::combinator::{sequence, tween};
+    /// use bevy_tween::interpolate::{scale, translation};
+    /// use bevy_tween::tween::AnimationTarget;
+    #[doc = crate::utils::doc_app_test_boilerplate!()]
+    ///
+    /// let target = AnimationTarget.into_target();
+    /// let entity = app
+    ///     .world_mut()
+    ///     .commands()
+    ///     .spawn(Transform::default())
+    ///     .animation()
+    ///     .insert_animation_here(sequence((
+    ///         tween(
+    ///             Duration::from_secs(1),
+    ///             EaseKind::Linear,
+    ///             target.with(translation(Vec3::ZERO, Vec3::X)),
+    ///         ),
+    ///         tween(
+    ///             Duration::from_millis(500),
+    ///             EaseKind::Linear,
+    ///             target.with(scale(Vec3::ONE, Vec3::splat(2.))),
+    ///         ),
+    ///     )))
+    ///     .id();
+    /// app.world_mut().flush();
+    ///
+    /// let time_runner = app.world().get::<TimeRunner>(entity).unwrap();
+    /// assert_eq!(time_runner.length(), Duration::from_millis(1500));
+    ///
+    /// for _ in 0..20 {
+    ///     app.update();
+    /// }
+    /// let transform = app.world().get::<Transform>(entity).unwrap();
+    /// assert_eq!(transform.translation, Vec3::X);
+    /// assert_eq!(transform.scale, Vec3::splat(2.));
+    /// ```
+    pub fn insert_animation_here<F>(
+        mut self,
+        animation: F,
+    ) -> EntityCommands<'a>
+    where
+        F: FnOnce(&mut AnimationCommands, &mut Duration),
+    {
+        self.entity_commands.insert(AnimationTarget);
+        self.insert(animation)
+    }
+
     /// Insert tween components directly to this entity.
     /// Can be used to create a simple animation quickly.
     /// [`TimeRunner`]'s length is determined by provided `duration` unless use
@@ -223,6 +957,8 @@ impl<'a> AnimationBuilder<'a> {
             time_runner,
             custom_length,
             skipped,
+            spawn_markers,
+            seed,
         } = self;
         let mut time_runner = time_runner.unwrap_or_default();
         match custom_length {
@@ -233,6 +969,7 @@ impl<'a> AnimationBuilder<'a> {
                 time_runner.set_length(duration);
             }
         }
+        start_from_direction(&mut time_runner);
 
         entity_commands.insert((
             TimeSpan::try_from(Duration::ZERO..duration).unwrap(),
@@ -243,6 +980,361 @@ impl<'a> AnimationBuilder<'a> {
         if skipped {
             entity_commands.insert(SkipTimeRunner);
         }
+        spawn_markers.insert(&mut entity_commands, true);
+        if let Some(mut seed) = seed {
+            entity_commands
+                .insert(AnimationSeed(seed))
+                .queue(reseed_noise(splitmix64(&mut seed)));
+        }
         entity_commands
     }
 }
+
+/// Move a new [`TimeRunner`] playing backward to its end so it plays toward
+/// the start instead of ending right away
+fn start_from_direction(time_runner: &mut TimeRunner) {
+    if time_runner.direction() == TimeDirection::Backward {
+        time_runner.set_tick(time_runner.length().as_secs_f32());
+        time_runner.collaspe_elasped();
+    }
+}
+
+/// Remove the animation of an animation root so another animation can be
+/// inserted to it cleanly.
+///
+/// This is an [`EntityCommand`](bevy::ecs::system::EntityCommand) for the
+/// animation root which recursively despawns the children spawned by
+/// [`AnimationBuilder::insert`], identified by having a [`TimeSpan`], and
+/// removes [`TimeRunner`], [`SkipTimeRunner`], [`AnimationSeed`], and
+/// [`TimelineMarkers`] from the root. Other children are kept. Tween components inserted directly to the
+/// root by [`AnimationBuilder::insert_tween_here`] are not removed since their
+/// types aren't known.
+///
+/// See [`AnimationBuilder::clear`] to clear before inserting a new animation.
+pub fn clear_animation(entity: Entity, world: &mut World) {
+    let tweens: Vec<Entity> = world
+        .get::<Children>(entity)
+        .map(|children| {
+            children
+                .iter()
+                .copied()
+                .filter(|child| world.get::<TimeSpan>(*child).is_some())
+                .collect()
+        })
+        .unwrap_or_default();
+    for tween in tweens {
+        world.entity_mut(tween).despawn_recursive();
+    }
+    if let Ok(mut root) = world.get_entity_mut(entity) {
+        root.remove::<(
+            TimeRunner,
+            SkipTimeRunner,
+            AnimationSeed,
+            TimelineMarkers,
+        )>();
+    }
+}
+
+/// Snap an animation back to its start and keep it paused, for example, to
+/// reset a UI widget without playing its animation.
+///
+/// This is an [`EntityCommand`](bevy::ecs::system::EntityCommand) for the
+/// animation root which pauses the [`TimeRunner`] and sets its elapsed time
+/// to zero. Tweens starting at zero get their progress set to the start so
+/// their start values are applied in the same frame and kept while paused.
+/// Other tweens have their progress and [`TweenInterpolationValue`] removed so
+/// they stop being applied, even with [`RetainFinalValue`]. Unpause the
+/// [`TimeRunner`] to play the animation from the start again.
+///
+/// # Events
+///
+/// Resetting doesn't fire any event. The time runner jumps to the start
+/// without traversing the time in between, and entities with
+/// [`TweenEventData`] have their progress removed instead of set to the start,
+/// including tweens firing events with [`with_event`] which therefore don't
+/// apply their start value. Events at the start fire again once the animation
+/// is unpaused and plays from there.
+///
+/// [`TweenEventData`]: crate::tween_event::TweenEventData
+///
+/// Logs an error if the entity has no [`TimeRunner`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{reset_to_start, tween};
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let start = Vec3::new(-1., 0., 0.);
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .insert(tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         entity.into_target().with(translation(start, Vec3::X)),
+///     ))
+///     .id();
+/// app.world_mut().flush();
+/// for _ in 0..5 {
+///     app.update();
+/// }
+/// assert_ne!(app.world().get::<Transform>(entity).unwrap().translation, start);
+///
+/// app.world_mut().commands().entity(root).queue(reset_to_start);
+/// for _ in 0..5 {
+///     app.update();
+///     let transform = app.world().get::<Transform>(entity).unwrap();
+///     assert_eq!(transform.translation, start);
+/// }
+/// ```
+///
+/// Later segments of an animation with
+/// [`retain_final_value`](AnimationBuilder::retain_final_value) don't keep
+/// their final value over the start values.
+///
+/// ```
+/// use bevy_tween::combinator::{reset_to_start, sequence, tween};
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let start = Vec3::new(-1., 0., 0.);
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// let target = entity.into_target();
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .retain_final_value(true)
+///     .insert(sequence((
+///         tween(
+///             Duration::from_millis(500),
+///             EaseKind::Linear,
+///             target.clone().with(translation(start, Vec3::ZERO)),
+///         ),
+///         tween(
+///             Duration::from_millis(500),
+///             EaseKind::Linear,
+///             target.with(translation(Vec3::ZERO, Vec3::X)),
+///         ),
+///     )))
+///     .id();
+/// app.world_mut().flush();
+/// for _ in 0..15 {
+///     app.update();
+/// }
+/// assert_eq!(app.world().get::<Transform>(entity).unwrap().translation, Vec3::X);
+///
+/// app.world_mut().commands().entity(root).queue(reset_to_start);
+/// for _ in 0..5 {
+///     app.update();
+///     let transform = app.world().get::<Transform>(entity).unwrap();
+///     assert_eq!(transform.translation, start);
+/// }
+/// ```
+///
+/// Events are not fired by the reset.
+///
+/// ```
+/// use bevy_tween::combinator::{
+///     event, event_for, forward, parallel, reset_to_start,
+/// };
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .insert(parallel((
+///         event("start"),
+///         event_for(Duration::from_millis(300), "intro"),
+///         forward(Duration::from_secs(1)),
+///     )))
+///     .id();
+/// app.world_mut().flush();
+///
+/// let mut cursor = app
+///     .world()
+///     .resource::<Events<TweenEvent<&'static str>>>()
+///     .get_cursor();
+/// let mut fired = |app: &mut App| {
+///     app.update();
+///     let events = app.world().resource::<Events<TweenEvent<&'static str>>>();
+///     cursor.read(events).count()
+/// };
+/// let before_reset: usize = (0..5).map(|_| fired(&mut app)).sum();
+/// assert!(before_reset > 0);
+///
+/// app.world_mut().commands().entity(root).queue(reset_to_start);
+/// let after_reset: usize = (0..5).map(|_| fired(&mut app)).sum();
+/// assert_eq!(after_reset, 0);
+/// ```
+pub fn reset_to_start(entity: Entity, world: &mut World) {
+    let Some(mut time_runner) = world.get_mut::<TimeRunner>(entity) else {
+        error!("Animation {entity:?} cannot be reset without a TimeRunner");
+        return;
+    };
+    time_runner.set_paused(true);
+    time_runner.set_tick(0.);
+    time_runner.collaspe_elasped();
+
+    let mut tweens = vec![entity];
+    if let Some(children) = world.get::<Children>(entity) {
+        tweens.extend(children.iter().copied());
+    }
+    for tween in tweens {
+        let Some(span) = world.get::<TimeSpan>(tween) else {
+            continue;
+        };
+        let starts_at_zero = span.min().duration() == Duration::ZERO;
+        let mut tween = world.entity_mut(tween);
+        if starts_at_zero && !tween.contains::<AnyTweenEvent>() {
+            tween.insert(TimeSpanProgress {
+                now_percentage: 0.,
+                now: 0.,
+                previous_percentage: 0.,
+                previous: 0.,
+            });
+        } else {
+            tween.remove::<(TimeSpanProgress, TweenInterpolationValue)>();
+        }
+    }
+}
+
+/// Jump an animation to `elapsed` without ticking through the time in
+/// between, for example, to scrub a cutscene in an editor.
+///
+/// This returns an [`EntityCommand`](bevy::ecs::system::EntityCommand) for
+/// the animation root which sets the elapsed time of its [`TimeRunner`].
+/// `elapsed` is clamped to the length of the animation so seeking past the end
+/// lands on the end and doesn't repeat. The tweens' progress is computed from
+/// the new time by the next run of the tween schedule, so the values are
+/// applied in the same frame if this is queued before the schedule runs.
+///
+/// A running animation continues playing from `elapsed`. Pause the
+/// [`TimeRunner`] first to hold the animation there while scrubbing.
+///
+/// # Events
+///
+/// The time runner jumps to `elapsed` without traversing the time in between
+/// so events between the current position and `elapsed` are not fired.
+///
+/// Logs an error if the entity has no [`TimeRunner`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{seek, tween};
+/// use bevy_tween::interpolate::sprite_color;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let start = Color::srgb(1., 0., 0.);
+/// let end = Color::srgb(0., 0., 1.);
+/// let entity = app.world_mut().spawn(Sprite::default()).id();
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .paused(true)
+///     .insert(tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         entity.into_target().with(sprite_color(start, end)),
+///     ))
+///     .id();
+/// app.world_mut().flush();
+///
+/// let seek_to = |app: &mut App, elapsed| {
+///     app.world_mut()
+///         .commands()
+///         .entity(root)
+///         .queue(seek(elapsed));
+///     app.update();
+///     app.world().get::<Sprite>(entity).unwrap().color
+/// };
+///
+/// assert_eq!(seek_to(&mut app, Duration::from_millis(500)), start.mix(&end, 0.5));
+/// assert_eq!(seek_to(&mut app, Duration::ZERO), start);
+/// // Past the end is clamped
+/// assert_eq!(seek_to(&mut app, Duration::from_secs(5)), end);
+/// // Backward again
+/// assert_eq!(seek_to(&mut app, Duration::from_millis(250)), start.mix(&end, 0.25));
+/// ```
+pub fn seek(elapsed: Duration) -> impl FnOnce(Entity, &mut World) {
+    move |entity, world| {
+        let Some(mut time_runner) = world.get_mut::<TimeRunner>(entity) else {
+            error!("Animation {entity:?} cannot seek without a TimeRunner");
+            return;
+        };
+        let elapsed = elapsed.min(time_runner.length());
+        time_runner.set_tick(elapsed.as_secs_f32());
+        time_runner.collaspe_elasped();
+    }
+}
+
+/// Jump an animation to the marker named `name` recorded by the [`marker`]
+/// combinator, see [`seek`].
+///
+/// Logs an error if the entity has no [`TimelineMarkers`] or no marker with
+/// this name.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{marker, seek_to_marker, sequence, tween};
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::tween::TimelineMarkers;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// let target = entity.into_target();
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .paused(true)
+///     .insert(sequence((
+///         tween(
+///             Duration::from_secs(1),
+///             EaseKind::Linear,
+///             target.with(translation(Vec3::ZERO, Vec3::X)),
+///         ),
+///         marker("intro_end"),
+///         tween(
+///             Duration::from_secs(1),
+///             EaseKind::Linear,
+///             target.with(translation(Vec3::X, Vec3::Y)),
+///         ),
+///     )))
+///     .id();
+/// app.world_mut().flush();
+///
+/// let markers = app.world().get::<TimelineMarkers>(root).unwrap();
+/// assert_eq!(markers.get("intro_end"), Some(Duration::from_secs(1)));
+///
+/// app.world_mut()
+///     .commands()
+///     .entity(root)
+///     .queue(seek_to_marker("intro_end"));
+/// app.update();
+/// let transform = app.world().get::<Transform>(entity).unwrap();
+/// assert_eq!(transform.translation, Vec3::X);
+/// ```
+pub fn seek_to_marker(
+    name: impl Into<String>,
+) -> impl FnOnce(Entity, &mut World) {
+    let name = name.into();
+    move |entity, world| {
+        let Some(at) = world
+            .get::<TimelineMarkers>(entity)
+            .and_then(|markers| markers.get(&name))
+        else {
+            error!("Animation {entity:?} has no marker named {name:?}");
+            return;
+        };
+        seek(at)(entity, world);
+    }
+}
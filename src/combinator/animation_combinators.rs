@@ -1,7 +1,11 @@
 use super::AnimationCommands;
+use crate::interpolate::{Relative, RelativeInterpolator};
+use crate::interpolation::EaseKind;
 use crate::prelude::TweenEventData;
+use crate::tween::{ReverseTween, TargetComponent, Tween};
+use crate::tween_event::{AnyTweenEvent, RepeatableEvent};
 use bevy::prelude::*;
-use bevy_time_runner::TimeSpan;
+use bevy_time_runner::{TimeBound, TimeSpan};
 use std::time::Duration;
 
 /// Animations in sequence.
@@ -30,6 +34,835 @@ where
     move |b, pos| parallel.call(b, pos)
 }
 
+/// Animations in parallel built by calling `factory` for every item of
+/// `items`, for example, to animate a list of entities identically without
+/// the tuple limit of [`parallel`].
+///
+/// Each animation will receive the same starting position.
+/// Returns the longest offset from the built animations, or the starting
+/// position if `items` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::{parallel_each, tween, AnimationCommands};
+/// use bevy_tween::interpolate::translation;
+///
+/// let length = Duration::from_millis(300);
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let mut end = Duration::ZERO;
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let entities: Vec<Entity> =
+///         (0..5).map(|_| commands.spawn(Transform::default()).id()).collect();
+///     commands
+///         .animation()
+///         .insert(|a: &mut AnimationCommands, pos: &mut Duration| {
+///             parallel_each(entities, |entity: Entity| {
+///                 tween(
+///                     length,
+///                     EaseKind::Linear,
+///                     entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///                 )
+///             })(a, pos);
+///             end = *pos;
+///         })
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let spans: Vec<TimeSpan> = world
+///     .get::<Children>(root)
+///     .unwrap()
+///     .iter()
+///     .map(|child| world.get::<TimeSpan>(*child).unwrap().clone())
+///     .collect();
+/// assert_eq!(spans.len(), 5);
+/// assert!(spans.iter().all(|span| span.min().duration() == Duration::ZERO));
+/// assert_eq!(end, length);
+/// ```
+pub fn parallel_each<T, I, F, A>(
+    items: I,
+    factory: F,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> A,
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |b, pos| {
+        let start = *pos;
+        let mut furthest = start;
+        for item in items {
+            let mut branch = start;
+            factory(item)(b, &mut branch);
+            furthest = furthest.max(branch);
+        }
+        *pos = furthest;
+    }
+}
+
+/// Animations in parallel with each successive animation starting `step`
+/// later, for example, to animate list items or grid cells in one after
+/// another.
+///
+/// The animation at index `i` receives the starting position shifted by
+/// `step * i`. Nested tuples are staggered on their own starting from their
+/// shifted position.
+/// Returns the furthest offset from the passed animations.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::{stagger, tween, AnimationCommands};
+/// use bevy_tween::interpolate::sprite_color;
+///
+/// let step = Duration::from_millis(50);
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let mut end = Duration::ZERO;
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let mut fade_in = || {
+///         let item = commands.spawn(Sprite::default()).id().into_target();
+///         tween(
+///             Duration::from_millis(300),
+///             EaseKind::Linear,
+///             item.with(sprite_color(Color::NONE, Color::WHITE)),
+///         )
+///     };
+///     let items = (fade_in(), fade_in(), fade_in(), fade_in());
+///     commands
+///         .animation()
+///         .insert(|a: &mut AnimationCommands, pos: &mut Duration| {
+///             stagger(step, items)(a, pos);
+///             end = *pos;
+///         })
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let starts: Vec<Duration> = world
+///     .get::<Children>(root)
+///     .unwrap()
+///     .iter()
+///     .map(|child| world.get::<TimeSpan>(*child).unwrap().min().duration())
+///     .collect();
+/// assert_eq!(starts, [step * 0, step, step * 2, step * 3]);
+/// assert_eq!(end, step * 3 + Duration::from_millis(300));
+/// ```
+pub fn stagger<S>(
+    step: Duration,
+    stagger: S,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    S: Stagger,
+{
+    move |b, pos| stagger.call(b, step, pos)
+}
+
+/// Merge two separately authored animations onto one [`TimeRunner`] so they
+/// pause, seek, and repeat together.
+///
+/// Each animation has its own time base starting at zero. `first` is aligned
+/// to the current position and `second` is aligned `offset` after it, so the
+/// same animation can be overlaid with a delay without changing how it's
+/// authored. Unlike [`parallel`] which takes animations of the same
+/// composition, this is intended for combining animations built by separate
+/// pieces of code, for example, [`BoxedAnimation`](super::BoxedAnimation)s.
+///
+/// Position is at whichever animation ends later.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::{TimeRunner, TimeSpan};
+/// use bevy_tween::combinator::{merge, tween};
+/// use bevy_tween::interpolate::{scale, translation};
+///
+/// let secs = Duration::from_secs_f32;
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let target = commands.spawn(Transform::default()).id().into_target();
+///     let movement = tween(
+///         secs(1.),
+///         EaseKind::Linear,
+///         target.with(translation(Vec3::ZERO, Vec3::X)),
+///     );
+///     let pulse = tween(
+///         secs(1.),
+///         EaseKind::Linear,
+///         target.with(scale(Vec3::ONE, Vec3::splat(2.))),
+///     );
+///     commands
+///         .animation()
+///         .insert(merge(movement, pulse, secs(0.5)))
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// // Both tweens are under the same runner
+/// assert_eq!(world.query::<&TimeRunner>().iter(&world).count(), 1);
+/// assert!(world.get::<TimeRunner>(root).is_some());
+/// let children = world.get::<Children>(root).unwrap();
+/// assert_eq!(children.len(), 2);
+/// assert_eq!(
+///     world.get::<TimeSpan>(children[0]),
+///     Some(&TimeSpan::try_from(secs(0.)..secs(1.)).unwrap())
+/// );
+/// assert_eq!(
+///     world.get::<TimeSpan>(children[1]),
+///     Some(&TimeSpan::try_from(secs(0.5)..secs(1.5)).unwrap())
+/// );
+/// ```
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+pub fn merge<A, B>(
+    first: A,
+    second: B,
+    offset: Duration,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+    B: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let start = *pos;
+        let mut first_pos = start;
+        first(a, &mut first_pos);
+        let mut second_pos = start + offset;
+        second(a, &mut second_pos);
+        *pos = first_pos.max(second_pos);
+    }
+}
+
+/// Count of [`repeat_times`] above which a warning is logged since every
+/// repetition spawns its own entities.
+pub const REPEAT_TIMES_WARN_COUNT: usize = 1000;
+
+/// Animation repeated `count` times in sequence, for example, to loop a
+/// segment inside a larger sequence. Unlike
+/// [`AnimationBuilder::repeat`](super::AnimationBuilder::repeat) which repeats
+/// the whole [`TimeRunner`](bevy_time_runner::TimeRunner), this unrolls the
+/// repetitions by spawning the animation's entities `count` times, so a
+/// warning is logged for counts above [`REPEAT_TIMES_WARN_COUNT`].
+///
+/// `animation` is a factory called for every repetition, for example,
+/// `|| tween(..)`, since combinators can only be called once.
+///
+/// Returns position from the last repetition. A `count` of zero spawns nothing
+/// and the position is unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::{
+///     forward, repeat_times, sequence, tween, AnimationCommands,
+/// };
+/// use bevy_tween::interpolate::scale;
+///
+/// let secs = Duration::from_secs;
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let mut end = Duration::ZERO;
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let target = commands.spawn(Transform::default()).id().into_target();
+///     let pulse = target.with(scale(Vec3::ONE, Vec3::splat(1.2)));
+///     commands
+///         .animation()
+///         .insert(|a: &mut AnimationCommands, pos: &mut Duration| {
+///             sequence((
+///                 forward(secs(1)),
+///                 repeat_times(3, move || {
+///                     tween(secs(1), EaseKind::Linear, pulse.clone())
+///                 }),
+///                 // Nothing is spawned and the position is unchanged
+///                 repeat_times(0, || forward(secs(1))),
+///             ))(a, pos);
+///             end = *pos;
+///         })
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let children = world.get::<Children>(root).unwrap();
+/// assert_eq!(children.len(), 3);
+/// for (i, child) in children.iter().enumerate() {
+///     let start = secs(1 + i as u64);
+///     assert_eq!(
+///         world.get::<TimeSpan>(*child),
+///         Some(&TimeSpan::try_from(start..start + secs(1)).unwrap())
+///     );
+/// }
+/// assert_eq!(end, secs(4));
+/// ```
+pub fn repeat_times<F, A>(
+    count: usize,
+    animation: F,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    F: Fn() -> A,
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        if count > REPEAT_TIMES_WARN_COUNT {
+            warn!(
+                "`repeat_times` spawns the animation {count} times which may create a lot of entities. \
+                Consider repeating the whole animation with `AnimationBuilder::repeat` instead."
+            );
+        }
+        for _ in 0..count {
+            animation()(a, pos);
+        }
+    }
+}
+
+/// Animations in sequence built by a [`SequenceBuilder`] which remembers the
+/// last added animation so it can be repeated with
+/// [`SequenceBuilder::repeat_last`].
+///
+/// Returns position from the last animation.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::{sequence_with, tween};
+/// use bevy_tween::interpolate::scale;
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let target = commands.spawn(Transform::default()).id().into_target();
+///     let pulse = target.with(scale(Vec3::ONE, Vec3::splat(1.2)));
+///     commands
+///         .animation()
+///         .insert(sequence_with(|s| {
+///             s.then(move || {
+///                 tween(
+///                     Duration::from_secs(1),
+///                     EaseKind::Linear,
+///                     pulse.clone(),
+///                 )
+///             })
+///             .repeat_last(2);
+///         }))
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let children = world.get::<Children>(root).unwrap();
+/// assert_eq!(children.len(), 3);
+/// for (i, child) in children.iter().enumerate() {
+///     let start = Duration::from_secs(i as u64);
+///     let end = start + Duration::from_secs(1);
+///     assert_eq!(
+///         world.get::<TimeSpan>(*child),
+///         Some(&TimeSpan::try_from(start..end).unwrap())
+///     );
+/// }
+/// ```
+pub fn sequence_with<F>(
+    f: F,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    F: FnOnce(&mut SequenceBuilder),
+{
+    move |a, pos| {
+        let mut builder = SequenceBuilder {
+            commands: a,
+            pos,
+            last: None,
+        };
+        f(&mut builder);
+    }
+}
+
+type RepeatableAnimation =
+    Box<dyn Fn(&mut AnimationCommands, &mut Duration) + 'static>;
+
+/// Stateful builder used in [`sequence_with`]
+pub struct SequenceBuilder<'s, 'r, 'a> {
+    commands: &'s mut AnimationCommands<'r, 'a>,
+    pos: &'s mut Duration,
+    last: Option<RepeatableAnimation>,
+}
+
+impl SequenceBuilder<'_, '_, '_> {
+    /// Add an animation to the sequence and remember it for
+    /// [`Self::repeat_last`].
+    ///
+    /// `animation` is a factory called once now and again for every
+    /// repetition, for example, `|| tween(..)` as shown in [`sequence_with`],
+    /// since combinators can only be called once.
+    pub fn then<F, A>(&mut self, animation: F) -> &mut Self
+    where
+        F: Fn() -> A + 'static,
+        A: FnOnce(&mut AnimationCommands, &mut Duration),
+    {
+        let last: RepeatableAnimation =
+            Box::new(move |a, pos| animation()(a, pos));
+        last(self.commands, self.pos);
+        self.last = Some(last);
+        self
+    }
+
+    /// Add an animation to the sequence without remembering it.
+    /// [`Self::repeat_last`] will still repeat the previously remembered animation.
+    pub fn then_once<A>(&mut self, animation: A) -> &mut Self
+    where
+        A: FnOnce(&mut AnimationCommands, &mut Duration),
+    {
+        animation(self.commands, self.pos);
+        self
+    }
+
+    /// Repeat the last animation added by [`Self::then`] `n` more times,
+    /// each one starting after the previous one.
+    /// Does nothing if no animation has been added.
+    pub fn repeat_last(&mut self, n: usize) -> &mut Self {
+        if let Some(last) = &self.last {
+            for _ in 0..n {
+                last(self.commands, self.pos);
+            }
+        }
+        self
+    }
+
+    /// Add animations in parallel built by a [`ParallelBuilder`], matching the
+    /// [`parallel`] combinator.
+    ///
+    /// Every animation added within the scope starts at the current position
+    /// and the sequence continues from whichever ends the latest.
+    /// [`Self::repeat_last`] will still repeat the previously remembered
+    /// animation.
+    ///
+    /// Scopes can be nested: [`ParallelBuilder::sequence`] opens a sequence
+    /// inside the parallel scope which can open another parallel scope and so
+    /// on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_tween::bevy_time_runner::TimeSpan;
+    /// use bevy_tween::combinator::{sequence_with, tween};
+    /// use bevy_tween::interpolate::{scale, translation};
+    ///
+    /// let secs = Duration::from_secs;
+    #[doc = crate::utils::doc_world_test_boilerplate!()]
+    /// let root = {
+    ///     let mut commands = Commands::new(&mut queue, &world);
+    ///     let target = commands.spawn(Transform::default()).id().into_target();
+    ///     commands
+    ///         .animation()
+    ///         .insert(sequence_with(|s| {
+    ///             s.then_once(tween(
+    ///                 secs(1),
+    ///                 EaseKind::Linear,
+    ///                 target.with(translation(Vec3::ZERO, Vec3::X)),
+    ///             ))
+    ///             .parallel(|p| {
+    ///                 p.add(tween(
+    ///                     secs(2),
+    ///                     EaseKind::Linear,
+    ///                     target.with(translation(Vec3::X, Vec3::Y)),
+    ///                 ))
+    ///                 .add(tween(
+    ///                     secs(1),
+    ///                     EaseKind::Linear,
+    ///                     target.with(scale(Vec3::ONE, Vec3::splat(2.))),
+    ///                 ));
+    ///             })
+    ///             .then_once(tween(
+    ///                 secs(1),
+    ///                 EaseKind::Linear,
+    ///                 target.with(scale(Vec3::splat(2.), Vec3::ONE)),
+    ///             ));
+    ///         }))
+    ///         .id()
+    /// };
+    /// queue.apply(&mut world);
+    ///
+    /// let children = world.get::<Children>(root).unwrap();
+    /// let span = |i: usize| world.get::<TimeSpan>(children[i]).unwrap();
+    /// // Both parallel tweens start after the first one
+    /// assert_eq!(span(1), &TimeSpan::try_from(secs(1)..secs(3)).unwrap());
+    /// assert_eq!(span(2), &TimeSpan::try_from(secs(1)..secs(2)).unwrap());
+    /// // The sequence continues after the longest one
+    /// assert_eq!(span(3), &TimeSpan::try_from(secs(3)..secs(4)).unwrap());
+    /// ```
+    pub fn parallel<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut ParallelBuilder),
+    {
+        let mut builder = ParallelBuilder {
+            commands: self.commands,
+            start: *self.pos,
+            end: *self.pos,
+        };
+        f(&mut builder);
+        *self.pos = builder.end;
+        self
+    }
+}
+
+/// Stateful builder used in [`SequenceBuilder::parallel`]
+pub struct ParallelBuilder<'s, 'r, 'a> {
+    commands: &'s mut AnimationCommands<'r, 'a>,
+    start: Duration,
+    end: Duration,
+}
+
+impl ParallelBuilder<'_, '_, '_> {
+    /// Add an animation starting at the start of this scope
+    pub fn add<A>(&mut self, animation: A) -> &mut Self
+    where
+        A: FnOnce(&mut AnimationCommands, &mut Duration),
+    {
+        let mut pos = self.start;
+        animation(self.commands, &mut pos);
+        self.end = self.end.max(pos);
+        self
+    }
+
+    /// Add animations in sequence built by a [`SequenceBuilder`] starting at
+    /// the start of this scope, like [`sequence_with`].
+    pub fn sequence<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut SequenceBuilder),
+    {
+        self.add(sequence_with(f))
+    }
+}
+
+/// Use one [`EaseKind`] for every tween created within `animation`.
+///
+/// Pass `()` as the interpolation of the inner tweens to use the shared ease.
+/// The ease is inserted to the tweens as [`DefaultEase`] which is only
+/// sampled for tweens without their own interpolation. Events are spawned
+/// without it.
+///
+/// # Precedence
+///
+/// - A tween that is given an interpolation explicitly, such as an
+///   [`EaseKind`] or an [`EaseClosure`](crate::interpolation::EaseClosure),
+///   keeps its own interpolation.
+/// - The innermost `with_ease_per_segment` wins when nested.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{sequence, tween, with_ease_per_segment};
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::interpolation::DefaultEase;
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let target = commands.spawn(Transform::default()).id().into_target();
+///     let secs = Duration::from_secs;
+///     commands
+///         .animation()
+///         .insert(with_ease_per_segment(
+///             EaseKind::QuadraticInOut,
+///             sequence((
+///                 tween(secs(1), (), target.with(translation(Vec3::ZERO, Vec3::X))),
+///                 tween(secs(1), (), target.with(translation(Vec3::X, Vec3::Y))),
+///                 tween(
+///                     secs(1),
+///                     EaseKind::Linear,
+///                     target.with(translation(Vec3::Y, Vec3::Z)),
+///                 ),
+///             )),
+///         ))
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let children = world.get::<Children>(root).unwrap();
+/// let default_ease = DefaultEase(EaseKind::QuadraticInOut);
+/// assert_eq!(world.get::<DefaultEase>(children[0]), Some(&default_ease));
+/// assert_eq!(world.get::<EaseKind>(children[0]), None);
+/// assert_eq!(world.get::<DefaultEase>(children[1]), Some(&default_ease));
+/// // Sampled with its own ease instead of the default
+/// assert_eq!(world.get::<EaseKind>(children[2]), Some(&EaseKind::Linear));
+/// ```
+///
+/// [`DefaultEase`]: crate::interpolation::DefaultEase
+pub fn with_ease_per_segment<A>(
+    ease: EaseKind,
+    animation: A,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let previous = a.default_ease.replace(ease);
+        animation(a, pos);
+        a.default_ease = previous;
+    }
+}
+
+/// Spawn every tween created within `animation` with [`SkipTween`] so they're
+/// present but inactive, for example, to pre-build parts of an animation that
+/// are enabled later by removing the marker.
+///
+/// Skipped tweens are not applied and skipped events don't
+/// fire, while the [`TimeRunner`] and the rest of the animation keep running.
+/// This is different from [`AnimationBuilder::paused`] which stops the whole
+/// animation's time. Removing [`SkipTween`] resumes a tween at the current
+/// time. Use [`AnimationBuilder::skip_tweens`] to skip every tween of an
+/// animation.
+///
+/// Position is unchanged from `animation`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{parallel, skipped, tween};
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::tween::SkipTween;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let a = app.world_mut().spawn(Transform::default()).id();
+/// let b = app.world_mut().spawn(Transform::default()).id();
+/// app.world_mut().commands().animation().insert(parallel((
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         a.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     ),
+///     skipped(tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         b.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     )),
+/// )));
+/// app.world_mut().flush();
+/// for _ in 0..5 {
+///     app.update();
+/// }
+///
+/// let mut q_skipped = app.world_mut().query_filtered::<Entity, With<SkipTween>>();
+/// assert_eq!(q_skipped.iter(app.world()).count(), 1);
+/// assert_ne!(app.world().get::<Transform>(a).unwrap().translation, Vec3::ZERO);
+/// assert_eq!(app.world().get::<Transform>(b).unwrap().translation, Vec3::ZERO);
+/// ```
+///
+/// [`SkipTween`]: crate::tween::SkipTween
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+/// [`AnimationBuilder::paused`]: super::AnimationBuilder::paused
+/// [`AnimationBuilder::skip_tweens`]: super::AnimationBuilder::skip_tweens
+pub fn skipped<A>(
+    animation: A,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let previous =
+            std::mem::replace(&mut a.spawn_markers.skip_tweens, true);
+        animation(a, pos);
+        a.spawn_markers.skip_tweens = previous;
+    }
+}
+
+/// Only play the tweens created within `animation` in the first cycle of a
+/// repeating animation, for example, an intro inside an infinitely repeating
+/// loop.
+///
+/// Every spawned entity gets [`FirstCycleOnly`] and is skipped with
+/// [`SkipTween`] as soon as the [`TimeRunner`] repeats for the first time.
+/// The tweens keep the values they applied in the first cycle. With
+/// [`RepeatStyle::PingPong`], the first cycle is the first forward pass.
+///
+/// Position is unchanged from `animation`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::Repeat;
+/// use bevy_tween::combinator::{first_cycle_only, sequence, tween};
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::tween::SkipTween;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let intro = app.world_mut().spawn(Transform::default()).id();
+/// let main = app.world_mut().spawn(Transform::default()).id();
+/// // Two cycles of a second
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .repeat(Repeat::times(1))
+///     .insert(sequence((
+///         first_cycle_only(tween(
+///             Duration::from_millis(500),
+///             EaseKind::Linear,
+///             intro.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///         )),
+///         tween(
+///             Duration::from_millis(500),
+///             EaseKind::Linear,
+///             main.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///         ),
+///     )));
+/// app.world_mut().flush();
+///
+/// let translation = |app: &App, entity| {
+///     app.world().get::<Transform>(entity).unwrap().translation
+/// };
+/// // Into the intro of the second cycle
+/// for _ in 0..12 {
+///     app.update();
+/// }
+/// assert_eq!(translation(&app, intro), Vec3::X);
+/// assert_eq!(translation(&app, main), Vec3::X);
+///
+/// for entity in [intro, main] {
+///     app.world_mut().get_mut::<Transform>(entity).unwrap().translation =
+///         Vec3::ZERO;
+/// }
+/// for _ in 0..15 {
+///     app.update();
+/// }
+///
+/// // Only the main tween played in the second cycle
+/// assert_eq!(translation(&app, intro), Vec3::ZERO);
+/// assert_eq!(translation(&app, main), Vec3::X);
+/// let mut q_skipped = app.world_mut().query_filtered::<Entity, With<SkipTween>>();
+/// assert_eq!(q_skipped.iter(app.world()).count(), 1);
+/// ```
+///
+/// [`FirstCycleOnly`]: crate::tween::FirstCycleOnly
+/// [`SkipTween`]: crate::tween::SkipTween
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+/// [`RepeatStyle::PingPong`]: bevy_time_runner::RepeatStyle::PingPong
+pub fn first_cycle_only<A>(
+    animation: A,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let previous =
+            std::mem::replace(&mut a.spawn_markers.first_cycle_only, true);
+        animation(a, pos);
+        a.spawn_markers.first_cycle_only = previous;
+    }
+}
+
+/// Play an animation backward, for example, to make a closing animation from
+/// an opening animation.
+///
+/// The animation is built as usual from the current position then the
+/// [`TimeSpan`] of every entity it spawned is mirrored within the part from
+/// the current position to the position it returned, so the last tween plays
+/// first. Every spawned entity gets [`ReverseTween`] so each tween also plays
+/// from its end to its start. Reversing twice plays forward again.
+///
+/// Position is shifted to where `animation` shifted it.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::{reverse, sequence, tween};
+/// use bevy_tween::interpolate::{scale, translation};
+/// use bevy_tween::tween::ReverseTween;
+///
+/// let secs = Duration::from_secs;
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     let target = commands.spawn(Transform::default()).id().into_target();
+///     let open = sequence((
+///         tween(
+///             secs(1),
+///             EaseKind::Linear,
+///             target.with(scale(Vec3::ZERO, Vec3::ONE)),
+///         ),
+///         tween(
+///             secs(2),
+///             EaseKind::Linear,
+///             target.with(translation(Vec3::ZERO, Vec3::X)),
+///         ),
+///     ));
+///     commands.animation().insert(reverse(open)).id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let children = world.get::<Children>(root).unwrap();
+/// // The first tween now occupies the last slot
+/// assert_eq!(
+///     world.get::<TimeSpan>(children[0]),
+///     Some(&TimeSpan::try_from(secs(2)..secs(3)).unwrap())
+/// );
+/// assert_eq!(
+///     world.get::<TimeSpan>(children[1]),
+///     Some(&TimeSpan::try_from(secs(0)..secs(2)).unwrap())
+/// );
+/// assert!(children
+///     .iter()
+///     .all(|child| world.get::<ReverseTween>(*child).is_some()));
+/// ```
+pub fn reverse<A>(
+    animation: A,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let start = *pos;
+        let previous = std::mem::replace(&mut a.recorded, Some(Vec::new()));
+        animation(a, pos);
+        let recorded =
+            std::mem::replace(&mut a.recorded, previous).unwrap_or_default();
+        let axis = start + *pos;
+        for entity in &recorded {
+            a.child_builder.enqueue_command(reverse_span(*entity, axis));
+        }
+        if let Some(previous) = &mut a.recorded {
+            previous.extend(recorded);
+        }
+    }
+}
+
+/// Command mirroring the [`TimeSpan`] of `entity` around `axis / 2` and
+/// toggling [`ReverseTween`]
+fn reverse_span(entity: Entity, axis: Duration) -> impl FnOnce(&mut World) {
+    move |world| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(span) = entity.get::<TimeSpan>() else {
+            return;
+        };
+        let with_duration = |bound: TimeBound, duration| match bound {
+            TimeBound::Inclusive(_) => TimeBound::Inclusive(duration),
+            TimeBound::Exclusive(_) => TimeBound::Exclusive(duration),
+        };
+        let min = axis.saturating_sub(span.max().duration());
+        let max = axis.saturating_sub(span.min().duration());
+        match TimeSpan::new(
+            with_duration(span.min(), min),
+            with_duration(span.max(), max),
+        ) {
+            Ok(span) => {
+                entity.insert(span);
+            }
+            Err(e) => {
+                error!("Cannot reverse the span of {:?}: {e:?}", entity.id());
+            }
+        }
+        if entity.contains::<ReverseTween>() {
+            entity.remove::<ReverseTween>();
+        } else {
+            entity.insert(ReverseTween);
+        }
+    }
+}
+
 /// Combinator for creating a basic tween using interpolation and a tween.
 ///
 /// Starts from last position and tween for provided `duration`
@@ -56,6 +889,231 @@ where
     }
 }
 
+/// Combinator for creating a tween moving `target` along a smooth path through
+/// every point in `points`.
+///
+/// This is a shortcut to [`tween`] with
+/// [`translation_catmull_rom`](crate::interpolate::translation_catmull_rom)
+/// using a tension of `0.5`, see there for how the path is made.
+///
+/// Starts from last position and tween for provided `duration`
+///
+/// Position is shifted to this tween's end.
+///
+/// Logs an error and spawns nothing if `points` is empty. Position is still
+/// shifted so the rest of the animation keeps its timing.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::path;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let waypoints = [
+///     Vec3::new(0., 0., 0.),
+///     Vec3::new(1., 1., 0.),
+///     Vec3::new(2., 1., 0.),
+///     Vec3::new(3., 0., 0.),
+/// ];
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// app.world_mut().commands().animation().insert(path(
+///     Duration::from_secs(1),
+///     EaseKind::Linear,
+///     entity.into_target(),
+///     waypoints,
+/// ));
+/// app.world_mut().flush();
+/// for _ in 0..15 {
+///     app.update();
+/// }
+///
+/// let transform = app.world().get::<Transform>(entity).unwrap();
+/// assert!(transform.translation.distance(waypoints[3]) < 1e-5);
+///
+/// // An empty path spawns nothing instead of panicking
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .insert(path(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         entity.into_target(),
+///         [],
+///     ))
+///     .id();
+/// app.world_mut().flush();
+/// let children = app.world().get::<Children>(root);
+/// assert_eq!(children.map_or(0, |children| children.len()), 0);
+/// ```
+pub fn path<I>(
+    duration: Duration,
+    interpolation: I,
+    target: TargetComponent,
+    points: impl IntoIterator<Item = Vec3>,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    I: Bundle,
+{
+    let path = crate::interpolate::translation_catmull_rom(0.5, points);
+    move |a, pos| match path {
+        Some(path) => tween(duration, interpolation, target.with(path))(a, pos),
+        None => {
+            error!("path combinator needs at least a point, spawning nothing");
+            *pos += duration;
+        }
+    }
+}
+
+/// Combinator for creating a basic tween with a duration randomized within
+/// `base - spread` to `base + spread`, for example, to desynchronize crowds of
+/// ambient animations like flickering or swaying.
+///
+/// Starts from last position and tween for the randomized duration. The
+/// duration is drawn from [`AnimationCommands::next_seed`] when the animation
+/// is built, so building the same animation with the same
+/// [`with_seed`](super::AnimationBuilder::with_seed) produces identical
+/// durations. Without a seed, every animation still draws different durations
+/// but they change between runs, see [`AnimationCommands::next_seed`].
+/// Durations below zero are clamped to zero.
+///
+/// Position is shifted to this tween's end.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::ecs::world::CommandQueue;
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::{sequence, tween_jitter_duration};
+/// use bevy_tween::interpolate::angle_z;
+/// use bevy_tween::prelude::*;
+///
+/// fn durations(world: &mut World, seed: u64) -> Vec<Duration> {
+///     let mut queue = CommandQueue::default();
+///     let root = {
+///         let mut commands = Commands::new(&mut queue, world);
+///         let target = commands.spawn(Transform::default()).id().into_target();
+///         let sway = || {
+///             tween_jitter_duration(
+///                 Duration::from_secs(2),
+///                 Duration::from_millis(500),
+///                 EaseKind::SineInOut,
+///                 target.with(angle_z(-0.1, 0.1)),
+///             )
+///         };
+///         commands
+///             .animation()
+///             .with_seed(seed)
+///             .insert(sequence((sway(), sway(), sway(), sway())))
+///             .id()
+///     };
+///     queue.apply(world);
+///     world
+///         .get::<Children>(root)
+///         .unwrap()
+///         .iter()
+///         .map(|child| {
+///             let span = world.get::<TimeSpan>(*child).unwrap();
+///             span.max().duration() - span.min().duration()
+///         })
+///         .collect()
+/// }
+///
+/// let mut world = World::new();
+/// let first = durations(&mut world, 7);
+/// assert!(first.iter().any(|d| *d != first[0]));
+/// for d in &first {
+///     assert!(*d >= Duration::from_millis(1500));
+///     assert!(*d <= Duration::from_millis(2500));
+/// }
+/// assert_eq!(first, durations(&mut world, 7));
+/// assert_ne!(first, durations(&mut world, 8));
+/// ```
+pub fn tween_jitter_duration<I, T>(
+    base: Duration,
+    spread: Duration,
+    interpolation: I,
+    tween: T,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    I: Bundle,
+    T: Bundle,
+{
+    move |a, pos| {
+        let ratio = (a.next_seed() >> 40) as f32 / (1u64 << 24) as f32;
+        let duration =
+            (base + spread).saturating_sub(spread.mul_f32(2. * (1. - ratio)));
+        self::tween(duration, interpolation, tween)(a, pos)
+    }
+}
+
+/// Combinator for creating a tween whose interpolator is applied as a change
+/// relative to the target's value at playback time.
+///
+/// The interpolator of `tween` only describes the change and is wrapped in
+/// [`Relative`], which captures the base from the target the first time the
+/// tween is applied. Unlike relative constructors such as
+/// [`translation_by`](crate::interpolate::translation_by) which compute the
+/// change from a state known when the animation is built, this picks up
+/// anything that moved the target before the tween starts, for example,
+/// procedural animations whose starting position is only known at runtime.
+/// See [`Relative`] for how repeats are handled.
+///
+/// Starts from last position and tween for provided `duration`
+///
+/// Position is shifted to this tween's end.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::relative;
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let delta = Vec3::new(10., -4., 0.);
+/// let entity = app.world_mut().spawn(Transform::from_xyz(3., 4., 0.)).id();
+/// app.world_mut().commands().animation().insert(relative(
+///     Duration::from_secs(1),
+///     EaseKind::QuadraticInOut,
+///     entity.into_target().with(translation(Vec3::ZERO, delta)),
+/// ));
+/// app.world_mut().flush();
+///
+/// // Moved by something else before the animation starts
+/// let start = Vec3::new(-2., 8., 0.);
+/// app.world_mut().get_mut::<Transform>(entity).unwrap().translation = start;
+///
+/// for _ in 0..15 {
+///     app.update();
+/// }
+/// let transform = app.world().get::<Transform>(entity).unwrap();
+/// assert_eq!(transform.translation, start + delta);
+/// ```
+pub fn relative<I, T, R>(
+    duration: Duration,
+    interpolation: I,
+    tween: Tween<T, R>,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    I: Bundle,
+    R: RelativeInterpolator,
+    Tween<T, Relative<R>>: Bundle,
+{
+    let Tween {
+        target,
+        interpolator,
+    } = tween;
+    self::tween(
+        duration,
+        interpolation,
+        Tween {
+            target,
+            interpolator: Relative::new(interpolator),
+        },
+    )
+}
+
 /// Combinator for creating a basic tween using interpolation and a tween.
 ///
 /// Starts and ends at provided span.
@@ -79,7 +1137,8 @@ where
 
 /// Combinator for creating an tween event.
 ///
-/// Event will be emitted at current position.
+/// Event will be emitted once at current position, see
+/// [instant events](crate::tween_event#instant-events).
 ///
 /// Position is not mutated because the event has no length.
 ///
@@ -95,16 +1154,45 @@ where
     Data: Send + Sync + 'static,
 {
     move |a, pos| {
-        a.spawn((
+        a.spawn_event((
             TimeSpan::try_from(*pos..=*pos).unwrap(),
             TweenEventData::with_data(event_data),
         ));
     }
 }
 
+/// Combinator for creating a [repeatable](RepeatableEvent) tween event.
+///
+/// Event will be emitted once in every cycle of a repeating animation the
+/// current position is crossed in, see
+/// [instant events](crate::tween_event#instant-events).
+///
+/// Position is not mutated because the event has no length.
+///
+/// <div class="warning">
+///
+/// Your event should be registered with [`TweenEventPlugin`](crate::tween_event::TweenEventPlugin).
+///
+/// </div>
+pub fn repeatable_event<Data>(
+    event_data: Data,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    Data: Send + Sync + 'static,
+{
+    move |a, pos| {
+        a.spawn_event((
+            TimeSpan::try_from(*pos..=*pos).unwrap(),
+            TweenEventData::with_data(event_data),
+            RepeatableEvent::default(),
+        ));
+    }
+}
+
 /// Combinator for creating an tween event.
 ///
-/// Event will be emitted at the provided position.
+/// Event will be emitted once at the provided position, see
+/// [instant events](crate::tween_event#instant-events).
 ///
 /// Position is not mutated because the operation is not relative.
 ///
@@ -121,7 +1209,7 @@ where
     Data: Send + Sync + 'static,
 {
     move |a, _pos| {
-        a.spawn((
+        a.spawn_event((
             TimeSpan::try_from(at..=at).unwrap(),
             TweenEventData::with_data(event_data),
         ));
@@ -149,7 +1237,7 @@ where
     move |a, pos| {
         let start = *pos;
         let end = start + length;
-        a.spawn((
+        a.spawn_event((
             TimeSpan::try_from(start..end).unwrap(),
             TweenEventData::with_data(event_data),
         ));
@@ -157,6 +1245,79 @@ where
     }
 }
 
+/// Combinator for creating a series of instant tween events.
+///
+/// Events will be emitted every `interval` starting at the current position
+/// for provided length. The final boundary is exclusive so an event is not
+/// emitted at the end, making the count `ceil(length / interval)`.
+/// For example, an interval of 0.2 seconds for 2 seconds emits 10 events at
+/// 0.0, 0.2, ..., 1.8 seconds.
+///
+/// Position is at the end of the length.
+///
+/// # Panics
+///
+/// Panics if `interval` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeSpan;
+/// use bevy_tween::combinator::event_every;
+#[doc = crate::utils::doc_world_test_boilerplate!()]
+/// let root = {
+///     let mut commands = Commands::new(&mut queue, &world);
+///     commands
+///         .animation()
+///         .insert(event_every(
+///             Duration::from_millis(200),
+///             Duration::from_secs(2),
+///             "spawn_particle",
+///         ))
+///         .id()
+/// };
+/// queue.apply(&mut world);
+///
+/// let children = world.get::<Children>(root).unwrap();
+/// assert_eq!(children.len(), 10);
+/// for (i, child) in children.iter().enumerate() {
+///     let at = Duration::from_millis(200 * i as u64);
+///     assert_eq!(
+///         world.get::<TimeSpan>(*child),
+///         Some(&TimeSpan::try_from(at..=at).unwrap())
+///     );
+/// }
+/// ```
+///
+/// <div class="warning">
+///
+/// Your event should be registered with [`TweenEventPlugin`](crate::tween_event::TweenEventPlugin).
+///
+/// </div>
+pub fn event_every<Data>(
+    interval: Duration,
+    length: Duration,
+    event_data: Data,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    Data: Clone + Send + Sync + 'static,
+{
+    assert!(!interval.is_zero(), "event_every interval must not be zero");
+    move |a, pos| {
+        let start = *pos;
+        let mut offset = Duration::ZERO;
+        while offset < length {
+            let at = start + offset;
+            a.spawn_event((
+                TimeSpan::try_from(at..=at).unwrap(),
+                TweenEventData::with_data(event_data.clone()),
+            ));
+            offset += interval;
+        }
+        *pos = start + length;
+    }
+}
+
 /// Combinator for creating an tween event.
 ///
 /// Event will be emitted at the provided span every frame.
@@ -178,13 +1339,141 @@ where
     Data: Send + Sync + 'static,
 {
     move |a, _pos| {
-        a.spawn((
+        a.spawn_event((
             span.try_into().unwrap(),
             TweenEventData::with_data(event_data),
         ));
     }
 }
 
+/// Combinator for firing a tween event from every entity spawned within
+/// `animation`, for example, to get the animated value of a tween in the event.
+///
+/// [`TweenEventData`] is inserted to the spawned entities so the event fires
+/// over their span every frame. Events fired from a tween carry the tween's
+/// sampled value in [`TweenEvent::interpolation_value`] while events placed
+/// on their own entity, such as [`event_exact`], have `None`.
+///
+/// Entities that already fire an event, such as ones spawned by [`event`]
+/// within `animation` or by a nested [`with_event`], keep their own event
+/// instead of getting this one.
+///
+/// Position is unchanged from `animation`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{
+///     event, event_exact, parallel, tween, with_event,
+/// };
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::interpolation::Interpolation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// let secs = Duration::from_secs;
+/// app.world_mut().commands().animation().insert(parallel((
+///     with_event(
+///         "tween",
+///         parallel((
+///             tween(
+///                 secs(1),
+///                 EaseKind::QuadraticIn,
+///                 entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///             ),
+///             event("inner"),
+///         )),
+///     ),
+///     event_exact(secs(0)..secs(1), "exact"),
+/// )));
+/// app.world_mut().flush();
+///
+/// let mut cursor = app
+///     .world()
+///     .resource::<Events<TweenEvent<&'static str>>>()
+///     .get_cursor();
+/// let mut fired = 0;
+/// let mut inner = 0;
+/// for _ in 0..15 {
+///     app.update();
+///     let events = app.world().resource::<Events<TweenEvent<&'static str>>>();
+///     for event in cursor.read(events) {
+///         let progress = event.progress.now_percentage.clamp(0., 1.);
+///         match event.data {
+///             "tween" => {
+///                 let value = event.interpolation_value.unwrap();
+///                 assert_eq!(value, EaseKind::QuadraticIn.sample(progress));
+///                 fired += 1;
+///             }
+///             data => {
+///                 assert_eq!(event.interpolation_value, None);
+///                 if data == "inner" {
+///                     inner += 1;
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// assert!(fired > 1);
+/// // The nested event keeps its own data
+/// assert_eq!(inner, 1);
+/// ```
+///
+/// [`TweenEvent::interpolation_value`]: crate::tween_event::TweenEvent::interpolation_value
+///
+/// <div class="warning">
+///
+/// Your event should be registered with [`TweenEventPlugin`](crate::tween_event::TweenEventPlugin).
+///
+/// </div>
+pub fn with_event<Data, A>(
+    event_data: Data,
+    animation: A,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    Data: Clone + Send + Sync + 'static,
+    A: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let previous = std::mem::replace(&mut a.recorded, Some(Vec::new()));
+        animation(a, pos);
+        let recorded =
+            std::mem::replace(&mut a.recorded, previous).unwrap_or_default();
+        for &entity in &recorded {
+            let event_data = TweenEventData::with_data(event_data.clone());
+            a.child_builder.enqueue_command(move |world: &mut World| {
+                if let Ok(mut entity) = world.get_entity_mut(entity) {
+                    if !entity.contains::<AnyTweenEvent>() {
+                        entity.insert(event_data);
+                    }
+                }
+            });
+        }
+        if let Some(previous) = &mut a.recorded {
+            previous.extend(recorded);
+        }
+    }
+}
+
+/// Combinator for naming the current position, for example, to seek to it
+/// with [`seek_to_marker`](super::seek_to_marker).
+///
+/// The position is recorded in [`TimelineMarkers`] of the animation root when
+/// the animation is inserted. Names should be unique per animation, a marker
+/// recorded later with the same name replaces the earlier one.
+///
+/// Position is unchanged.
+///
+/// [`TimelineMarkers`]: crate::tween::TimelineMarkers
+pub fn marker(
+    name: impl Into<String>,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    let name = name.into();
+    move |a, pos| {
+        a.markers.insert(name, *pos);
+    }
+}
+
 /// Shift the position forward by provided duration
 pub fn forward(
     by: Duration,
@@ -192,6 +1481,73 @@ pub fn forward(
     move |_, pos| *pos += by
 }
 
+/// Wait for the provided duration before the next combinator, the same as
+/// [`forward`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{delay, delay_until, sequence, tween};
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let [a, b, c] =
+///     [(); 3].map(|_| app.world_mut().spawn(Transform::from_xyz(0., 1., 0.)).id());
+/// let one_sec = Duration::from_secs(1);
+/// let move_x = |entity: Entity| {
+///     tween(
+///         one_sec,
+///         EaseKind::Linear,
+///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     )
+/// };
+/// app.world_mut().commands().animation().insert(sequence((
+///     move_x(a),
+///     // `b` starts one second after `a` ends
+///     delay(one_sec),
+///     move_x(b),
+///     // Already past one second so the position is unchanged
+///     delay_until(one_sec),
+///     move_x(c),
+/// )));
+/// app.world_mut().flush();
+///
+/// let untouched = |app: &App, entity| {
+///     app.world().get::<Transform>(entity).unwrap().translation == Vec3::Y
+/// };
+/// for frame in 1..=40 {
+///     app.update();
+///     let elapsed = Duration::from_millis(100 * frame);
+///     if elapsed < Duration::from_millis(1900) {
+///         assert!(untouched(&app, b));
+///     }
+///     if elapsed > Duration::from_millis(2100) {
+///         assert!(!untouched(&app, b));
+///     }
+///     if elapsed < Duration::from_millis(2900) {
+///         assert!(untouched(&app, c));
+///     }
+/// }
+/// assert!(!untouched(&app, c));
+/// ```
+pub fn delay(
+    duration: Duration,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    forward(duration)
+}
+
+/// Wait until the provided position before the next combinator.
+///
+/// The position is only moved if `at` is later than the current position so
+/// this never rewinds, for example, to line up the ends of several
+/// [`parallel`] branches of different lengths on a shared beat.
+/// See [`delay`] for an example.
+pub fn delay_until(
+    at: Duration,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    move |_, pos| *pos = (*pos).max(at)
+}
+
 /// Shift the position backward by provided duration
 pub fn backward(
     by: Duration,
@@ -220,6 +1576,14 @@ impl<T> Sequence for T where T: sealed::SequenceSealed {}
 pub trait Parallel: sealed::ParallelSealed {}
 impl<T> Parallel for T where T: sealed::ParallelSealed {}
 
+/// Tuple of FnOnces in [`stagger()`],
+/// support up to 16 indexes but can be circumvented by nesting tuples.
+///
+/// This trait is sealed and not meant to be implemented outside of the current crate.
+#[allow(private_bounds)]
+pub trait Stagger: sealed::StaggerSealed {}
+impl<T> Stagger for T where T: sealed::StaggerSealed {}
+
 mod sealed {
     use super::*;
 
@@ -243,6 +1607,26 @@ mod sealed {
         }
     }
 
+    pub(super) trait StaggerSealed {
+        fn call(
+            self,
+            a: &mut AnimationCommands,
+            step: Duration,
+            pos: &mut Duration,
+        );
+    }
+
+    impl<T: FnOnce(&mut AnimationCommands, &mut Duration)> StaggerSealed for T {
+        fn call(
+            self,
+            a: &mut AnimationCommands,
+            _step: Duration,
+            pos: &mut Duration,
+        ) {
+            self(a, pos)
+        }
+    }
+
     macro_rules! impl_sequence {
         ($($i:tt $t:ident)+) => {
             impl< $($t: SequenceSealed,)+ > SequenceSealed for ($($t,)*) {
@@ -274,6 +1658,27 @@ mod sealed {
         }
     }
 
+    macro_rules! impl_stagger {
+        ($($i:tt $t:ident)+) => {
+            impl< $($t: StaggerSealed,)+ > StaggerSealed for ($($t,)*) {
+                fn call(self, a: &mut AnimationCommands, step: Duration, main_pos: &mut Duration) {
+                    let mut furthest = *main_pos;
+                    let mut start = *main_pos;
+                    $(
+                        let mut pos = start;
+                        self.$i.call(a, step, &mut pos);
+                        if pos > furthest {
+                            furthest = pos;
+                        }
+                        #[allow(unused)]
+                        {start += step;}
+                    )*
+                    *main_pos = furthest;
+                }
+            }
+        }
+    }
+
     // It's possible to make a macro that use shorter input but i'm tryna make it simple here
     //
     // Built by using Helix macro:
@@ -317,4 +1722,21 @@ mod sealed {
     impl_parallel! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 }
     impl_parallel! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14 }
     impl_parallel! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14 15 T15 }
+
+    impl_stagger! { 0 T0 }
+    impl_stagger! { 0 T0 1 T1 }
+    impl_stagger! { 0 T0 1 T1 2 T2 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14 }
+    impl_stagger! { 0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14 15 T15 }
 }
@@ -0,0 +1,146 @@
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::{AnimationCommands, BoxedAnimation};
+
+/// Factory building a fresh [`BoxedAnimation`] targeting the provided entity
+pub type AnimationFactory =
+    Arc<dyn Fn(Entity) -> BoxedAnimation + Send + Sync + 'static>;
+
+/// Resource of animations registered under a key to be instanced by the key
+/// later, for example, animations defined once by a content pipeline and
+/// triggered by name.
+///
+/// An animation is registered as a factory building the combinator for a
+/// target entity. Every call to [`Self::instance`] builds a fresh combinator
+/// so each instance is independent: it's inserted with
+/// [`AnimationBuilder::insert`](super::AnimationBuilder::insert) like any
+/// other animation, spawning its own tweens under its own animation root with
+/// its own [`TimeRunner`](bevy_time_runner::TimeRunner). Changing or removing
+/// a registered animation doesn't affect instances that were already inserted.
+///
+/// The key is [`String`] by default but any hashable type can be used,
+/// for example, an enum of animation names. [`TweenCorePlugin`] initializes
+/// `AnimationRegistry<String>`, other key types have to be initialized with
+/// [`App::init_resource`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{tween, AnimationRegistry};
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// app.world_mut()
+///     .resource_mut::<AnimationRegistry>()
+///     .register("slide", |target| {
+///         tween(
+///             Duration::from_secs(1),
+///             EaseKind::Linear,
+///             target.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///         )
+///     });
+///
+/// let a = app.world_mut().spawn(Transform::default()).id();
+/// let b = app.world_mut().spawn(Transform::default()).id();
+/// let registry = app.world().resource::<AnimationRegistry>();
+/// let instances = [a, b].map(|target| registry.instance("slide", target).unwrap());
+/// for animation in instances {
+///     app.world_mut().commands().animation().insert(animation);
+/// }
+/// app.world_mut().flush();
+///
+/// for _ in 0..15 {
+///     app.update();
+/// }
+/// for target in [a, b] {
+///     let transform = app.world().get::<Transform>(target).unwrap();
+///     assert_eq!(transform.translation, Vec3::X);
+/// }
+/// ```
+///
+/// [`TweenCorePlugin`]: crate::TweenCorePlugin
+#[derive(Resource)]
+pub struct AnimationRegistry<K = String>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    animations: HashMap<K, AnimationFactory>,
+}
+
+impl<K> AnimationRegistry<K>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    /// Register an animation under `key` replacing the previous one.
+    ///
+    /// `factory` is called with the target entity every time the animation is
+    /// instanced.
+    pub fn register<F, A>(&mut self, key: impl Into<K>, factory: F)
+    where
+        F: Fn(Entity) -> A + Send + Sync + 'static,
+        A: FnOnce(&mut AnimationCommands, &mut Duration) + 'static,
+    {
+        self.animations.insert(
+            key.into(),
+            Arc::new(move |target| Box::new(factory(target)) as BoxedAnimation),
+        );
+    }
+
+    /// Build a fresh instance of the animation registered under `key`
+    /// targeting `target` or `None` if nothing is registered.
+    pub fn instance<Q>(&self, key: &Q, target: Entity) -> Option<BoxedAnimation>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.animations.get(key).map(|factory| factory(target))
+    }
+
+    /// Get the factory of the animation registered under `key`
+    pub fn get<Q>(&self, key: &Q) -> Option<&AnimationFactory>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.animations.get(key)
+    }
+
+    /// Returns `true` if an animation is registered under `key`
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.animations.contains_key(key)
+    }
+
+    /// Remove the animation registered under `key`
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<AnimationFactory>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.animations.remove(key)
+    }
+
+    /// Iterate over registered keys
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.animations.keys()
+    }
+}
+
+impl<K> Default for AnimationRegistry<K>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        AnimationRegistry {
+            animations: HashMap::default(),
+        }
+    }
+}
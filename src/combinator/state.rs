@@ -17,7 +17,7 @@ impl<T, V> TargetState<T, V> {
     /// Recommended to use other methods like:
     /// - [`TargetComponent::state`]
     /// - [`TargetAsset::state`](crate::tween::TargetAsset::state)
-    /// - [`TargetResource::state`](crate::tween::TargetAsset::state)
+    /// - [`TargetResource::state`](crate::tween::TargetResource::state)
     pub fn new(target: T, value: V) -> Self {
         TargetState { target, value }
     }
@@ -62,6 +62,51 @@ where
             interpolator,
         }
     }
+
+    /// Create [`ComponentTween`] of a value from this state toward the value
+    /// of `other` state using a relative interpolator constructor.
+    ///
+    /// The value of `other` is a snapshot taken when this is called, not a
+    /// live link. Changes made to `other` afterward are not followed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_tween::interpolate::sprite_color_to;
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let mut world = World::new();
+    /// let a = world.spawn(Sprite::from_color(Color::WHITE, Vec2::ONE)).id();
+    /// let b = world.spawn(Sprite::from_color(Color::BLACK, Vec2::ONE)).id();
+    ///
+    /// let mut a_color = a.into_target().state(Color::WHITE);
+    /// let mut b_color = b.into_target().state(Color::BLACK);
+    ///
+    /// let b_tween = b_color.with(sprite_color_to(Color::srgb(1., 0., 0.)));
+    /// let a_tween = a_color.tween_to_state_of(&b_color, sprite_color_to);
+    ///
+    /// for (entity, tween) in [(a, a_tween), (b, b_tween)] {
+    ///     let mut sprite = world.get_mut::<Sprite>(entity).unwrap();
+    ///     tween.interpolator.interpolate(&mut sprite, 1.);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     world.get::<Sprite>(a).unwrap().color,
+    ///     world.get::<Sprite>(b).unwrap().color,
+    /// );
+    /// ```
+    pub fn tween_to_state_of<U, F, I>(
+        &mut self,
+        other: &TargetState<U, V>,
+        f: impl FnOnce(V) -> F,
+    ) -> Tween<T, I>
+    where
+        V: Clone,
+        F: FnOnce(&mut V) -> I,
+    {
+        self.with(f(other.value.clone()))
+    }
 }
 
 /// Extension trait to create [`TransformTargetState`]
@@ -146,6 +191,11 @@ impl TransformTargetState {
         self.translation_with(translation_to(to))
     }
 
+    /// Create [`ComponentTween`] of transform's translation at Z axis tweening to provided input
+    pub fn z_to(&mut self, to: f32) -> ComponentTween<TranslationZ> {
+        self.transform_with(|v| translation_z_to(to)(&mut v.translation.z))
+    }
+
     /// Create [`ComponentTween`] of transform's rotation tweening to provided input
     pub fn rotation_to(&mut self, to: Quat) -> ComponentTween<Rotation> {
         self.rotation_with(rotation_to(to))
@@ -0,0 +1,127 @@
+//! Module containing a component for controlling animations
+//!
+//! # Control
+//!
+//! **Components**:
+//! - [`AnimationControl`]
+//!
+//! **Systems**
+//! - [`animation_control_system`]
+//!
+//! [`AnimationControl`] on an animation root pauses and changes the speed of
+//! the animation without touching its [`TimeRunner`], for example, to bind
+//! a pause button and a speed slider to the selected animation.
+//! [`animation_control_system`], added by
+//! [`TweenCorePlugin`](crate::TweenCorePlugin), applies the control to the
+//! [`TimeRunner`] whenever it changes.
+//!
+//! # Examples
+//!
+//! ```
+//! use bevy_tween::combinator::tween;
+//! use bevy_tween::control::AnimationControl;
+//! use bevy_tween::interpolate::translation;
+#![doc = crate::utils::doc_app_test_boilerplate!()]
+//!
+//! let entity = app.world_mut().spawn(Transform::default()).id();
+//! // Moves by 1 every second
+//! let root = app
+//!     .world_mut()
+//!     .commands()
+//!     .animation()
+//!     .insert(tween(
+//!         Duration::from_secs(10),
+//!         EaseKind::Linear,
+//!         entity
+//!             .into_target()
+//!             .with(translation(Vec3::ZERO, Vec3::new(10., 0., 0.))),
+//!     ))
+//!     .insert(AnimationControl::default())
+//!     .id();
+//! app.world_mut().flush();
+//!
+//! let x = |app: &App| app.world().get::<Transform>(entity).unwrap().translation.x;
+//! for _ in 0..3 {
+//!     app.update();
+//! }
+//! assert!(x(&app) > 0.);
+//!
+//! app.world_mut()
+//!     .get_mut::<AnimationControl>(root)
+//!     .unwrap()
+//!     .paused = true;
+//! app.update();
+//! let paused_at = x(&app);
+//! for _ in 0..3 {
+//!     app.update();
+//! }
+//! assert_eq!(x(&app), paused_at);
+//!
+//! let mut control = app.world_mut().get_mut::<AnimationControl>(root).unwrap();
+//! control.paused = false;
+//! control.speed = 2.;
+//! app.update();
+//! // Resumes from where it was paused at double speed
+//! assert!((x(&app) - (paused_at + 0.2)).abs() < 1e-4);
+//! ```
+
+use bevy::prelude::*;
+use bevy_time_runner::TimeRunner;
+
+/// Pause and speed of the animation whose root has this component.
+/// See the [module documentation](self).
+///
+/// The [`TimeRunner`] is only changed when this component changes, so
+/// changing the [`TimeRunner`] directly still works until then.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AnimationControl {
+    /// Stop the animation from advancing while keeping its progress
+    pub paused: bool,
+    /// Multiplier of how fast the animation advances, `1.` is the normal
+    /// speed. Changing it only affects time advanced afterward, so the
+    /// animation continues from where it is without jumping.
+    pub speed: f32,
+}
+
+impl Default for AnimationControl {
+    fn default() -> Self {
+        AnimationControl {
+            paused: false,
+            speed: 1.,
+        }
+    }
+}
+
+impl AnimationControl {
+    /// Create a new [`AnimationControl`] playing at normal speed
+    pub fn new() -> AnimationControl {
+        AnimationControl::default()
+    }
+
+    /// Start paused
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Start with this speed
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+/// Apply changed [`AnimationControl`]s to the [`TimeRunner`] on the same
+/// entity.
+pub fn animation_control_system(
+    mut q_control: Query<
+        (&AnimationControl, &mut TimeRunner),
+        Changed<AnimationControl>,
+    >,
+) {
+    for (control, mut time_runner) in &mut q_control {
+        time_runner.set_paused(control.paused);
+        time_runner.set_time_scale(control.speed);
+    }
+}
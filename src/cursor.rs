@@ -0,0 +1,162 @@
+//! Module containing implementations for following the cursor
+//!
+//! # Cursor
+//!
+//! **Plugins**:
+//! - [`CursorFollowPlugin`]
+//!
+//! **Components**:
+//! - [`CursorFollow`]
+//!
+//! **Systems**
+//! - [`cursor_follow_system`]
+//!
+//! Cursor-attached effects don't have a fixed end so they're not tweens:
+//! [`cursor_follow_system`] eases every [`CursorFollow`] entity toward the
+//! current cursor position on every frame.
+//!
+//! # Cursor resource
+//!
+//! This crate doesn't track the cursor itself because converting it to world
+//! space depends on the app's windows and cameras. The cursor position is read
+//! from a resource provided by the user that implements [`CursorWorldCoord`],
+//! for example, `MainCursorWorldCoord` in this crate's examples. The resource
+//! has to be updated before the tween schedule runs (`PostUpdate` by default)
+//! to follow the cursor without a frame of delay. Nothing follows if the
+//! resource doesn't exist or reports no position.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+/// Resource reporting the cursor position in world space for
+/// [`CursorFollowPlugin`]
+pub trait CursorWorldCoord: Resource {
+    /// Cursor position in world space or `None` if the cursor is outside of
+    /// the window
+    fn cursor_world_coord(&self) -> Option<Vec2>;
+}
+
+/// Plugin for [`cursor_follow_system`] following the cursor reported by `C`.
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+pub struct CursorFollowPlugin<C>
+where
+    C: CursorWorldCoord,
+{
+    marker: PhantomData<C>,
+}
+
+impl<C> Default for CursorFollowPlugin<C>
+where
+    C: CursorWorldCoord,
+{
+    fn default() -> Self {
+        CursorFollowPlugin {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Plugin for CursorFollowPlugin<C>
+where
+    C: CursorWorldCoord,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist");
+        app.add_systems(
+            app_resource.schedule,
+            cursor_follow_system::<C>.in_set(crate::TweenSystemSet::ApplyTween),
+        )
+        .register_type::<CursorFollow>();
+    }
+}
+
+/// Ease [`Transform`]'s translation toward the cursor on every frame.
+///
+/// `smoothing` is the rate of exponential decay of the distance to the cursor
+/// per second so the motion is the same at any frame rate. Higher values
+/// follow more tightly, `0.` doesn't move, and [`f32::INFINITY`] snaps to the
+/// cursor. For example, `f32::ln(10.)` leaves a tenth of the distance after a
+/// second. Only X and Y are changed.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::cursor::{CursorFollow, CursorFollowPlugin, CursorWorldCoord};
+///
+/// #[derive(Default, Resource)]
+/// struct MainCursorWorldCoord(Option<Vec2>);
+///
+/// impl CursorWorldCoord for MainCursorWorldCoord {
+///     fn cursor_world_coord(&self) -> Option<Vec2> {
+///         self.0
+///     }
+/// }
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_plugins(CursorFollowPlugin::<MainCursorWorldCoord>::default())
+///     .init_resource::<MainCursorWorldCoord>();
+///
+/// let entity = app
+///     .world_mut()
+///     .spawn((Transform::from_xyz(0., 0., 5.), CursorFollow::new(10.)))
+///     .id();
+///
+/// for cursor in [Vec2::new(100., 50.), Vec2::new(-20., 0.)] {
+///     app.world_mut().resource_mut::<MainCursorWorldCoord>().0 = Some(cursor);
+///     let mut distance = f32::INFINITY;
+///     for _ in 0..30 {
+///         app.update();
+///         let translation = app.world().get::<Transform>(entity).unwrap().translation;
+///         let new_distance = translation.truncate().distance(cursor);
+///         assert!(new_distance <= distance + 1e-4);
+///         distance = new_distance;
+///     }
+///     assert!(distance < 1e-3);
+/// }
+///
+/// let translation = app.world().get::<Transform>(entity).unwrap().translation;
+/// assert_eq!(translation.z, 5.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct CursorFollow {
+    /// Rate of exponential decay of the distance to the cursor per second
+    pub smoothing: f32,
+}
+
+impl CursorFollow {
+    /// Create a new [`CursorFollow`]
+    pub fn new(smoothing: f32) -> CursorFollow {
+        CursorFollow { smoothing }
+    }
+}
+
+/// Ease [`CursorFollow`] entities toward the cursor reported by `C`
+pub fn cursor_follow_system<C>(
+    cursor: Option<Res<C>>,
+    time: Res<Time>,
+    mut q_follow: Query<(&CursorFollow, &mut Transform)>,
+) where
+    C: CursorWorldCoord,
+{
+    let Some(target) = cursor.and_then(|c| c.cursor_world_coord()) else {
+        return;
+    };
+    let delta = time.delta_secs();
+    q_follow.iter_mut().for_each(|(follow, mut transform)| {
+        let mut position = transform.translation.truncate();
+        position.smooth_nudge(&target, follow.smoothing, delta);
+        transform.translation = position.extend(transform.translation.z);
+    });
+}
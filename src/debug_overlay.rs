@@ -0,0 +1,286 @@
+//! Module containing a lightweight overlay for debugging animations
+//!
+//! # Debug overlay
+//!
+//! **Plugins**:
+//! - [`TweenDebugOverlayPlugin`]
+//!
+//! **Resources**:
+//! - [`TweenDebugOverlay`]
+//!
+//! **Systems**
+//! - [`tween_debug_overlay_system`]
+//!
+//! [`TweenDebugOverlayPlugin`] draws a compact timeline of every
+//! [`TimeRunner`] in screen space with gizmos, without any UI or egui
+//! dependency. Every runner gets a row made of:
+//! - a bar spanning the runner's length with a cursor at the elapsed time,
+//! - the [`TimeSpan`]s of the runner and its children below the bar, stacked
+//!   in lanes when they overlap and highlighted while they have
+//!   [`TimeSpanProgress`].
+//!
+//! The overlay is drawn with [`Gizmos<TweenDebugOverlayGizmos>`] on the
+//! active camera with the highest order. The overlay draws on top of the scene
+//! by default and its line width or render layers can be changed in
+//! [`GizmoConfigStore`].
+//!
+//! # Many runners
+//!
+//! Rows are stacked downward from [`TweenDebugOverlay::position`] ordered by
+//! the runners' entities. At most [`TweenDebugOverlay::max_rows`]
+//! rows are drawn starting from the [`TweenDebugOverlay::scroll`]th runner and
+//! a chevron is drawn above or below the rows if there are hidden runners in
+//! that direction.
+
+use bevy::prelude::*;
+use bevy_time_runner::{TimeRunner, TimeSpan, TimeSpanProgress};
+
+/// Distance from the camera's near plane where the overlay is drawn
+const OVERLAY_DISTANCE: f32 = 0.01;
+const BAR_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+const COMPLETED_BAR_COLOR: Color = Color::srgb(0.3, 0.7, 0.4);
+const CURSOR_COLOR: Color = Color::WHITE;
+const SPAN_COLOR: Color = Color::srgb(0.35, 0.4, 0.55);
+const ACTIVE_SPAN_COLOR: Color = Color::srgb(1., 0.8, 0.2);
+
+/// Plugin for drawing the [debug overlay](self).
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+pub struct TweenDebugOverlayPlugin;
+
+impl Plugin for TweenDebugOverlayPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist");
+        app.add_systems(
+            app_resource.schedule,
+            tween_debug_overlay_system.after(crate::TweenSystemSet::ApplyTween),
+        )
+        .insert_gizmo_config(
+            TweenDebugOverlayGizmos,
+            GizmoConfig {
+                depth_bias: -1.,
+                ..Default::default()
+            },
+        )
+        .init_resource::<TweenDebugOverlay>()
+        .register_type::<TweenDebugOverlay>();
+    }
+}
+
+/// Gizmo config group of the [debug overlay](self)
+#[derive(Debug, Default, Reflect, GizmoConfigGroup)]
+pub struct TweenDebugOverlayGizmos;
+
+/// Layout of the [debug overlay](self). Sizes are in logical pixels.
+#[derive(Debug, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct TweenDebugOverlay {
+    /// Draw the overlay
+    pub enabled: bool,
+    /// Top-left corner of the overlay from the top-left of the viewport
+    pub position: Vec2,
+    /// Width of the rows
+    pub width: f32,
+    /// Height of a runner's bar
+    pub bar_height: f32,
+    /// Height of a lane of spans
+    pub lane_height: f32,
+    /// Space between rows
+    pub row_gap: f32,
+    /// Maximum number of rows drawn
+    pub max_rows: usize,
+    /// Number of runners skipped before the first row
+    pub scroll: usize,
+}
+
+impl Default for TweenDebugOverlay {
+    fn default() -> Self {
+        TweenDebugOverlay {
+            enabled: true,
+            position: Vec2::new(16., 16.),
+            width: 320.,
+            bar_height: 8.,
+            lane_height: 4.,
+            row_gap: 8.,
+            max_rows: 16,
+            scroll: 0,
+        }
+    }
+}
+
+impl TweenDebugOverlay {
+    /// Show the overlay if hidden and hide it otherwise
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+}
+
+/// Draw the [debug overlay](self) of every [`TimeRunner`].
+pub fn tween_debug_overlay_system(
+    overlay: Res<TweenDebugOverlay>,
+    mut gizmos: Gizmos<TweenDebugOverlayGizmos>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    q_time_runner: Query<(Entity, &TimeRunner, Option<&Children>)>,
+    q_span: Query<(&TimeSpan, Has<TimeSpanProgress>)>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+    let Some((camera, camera_transform)) = q_camera
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+    else {
+        return;
+    };
+    let mut painter = Painter {
+        gizmos: &mut gizmos,
+        camera,
+        camera_transform,
+    };
+
+    let mut time_runners = q_time_runner.iter().collect::<Vec<_>>();
+    time_runners.sort_by_key(|(entity, ..)| *entity);
+
+    let left = overlay.position.x;
+    let right = left + overlay.width;
+    let mut y = overlay.position.y;
+    if overlay.scroll > 0 && !time_runners.is_empty() {
+        painter.chevron(Vec2::new(left, y), -overlay.row_gap);
+    }
+    let mut rows = time_runners.iter().skip(overlay.scroll);
+    for (entity, time_runner, children) in rows.by_ref().take(overlay.max_rows)
+    {
+        let length = time_runner.length().as_secs_f32();
+        let x = |secs: f32| {
+            let ratio = if length > 0. { secs / length } else { 1. };
+            left + overlay.width * ratio.clamp(0., 1.)
+        };
+
+        let bar_color = if time_runner.is_completed() {
+            COMPLETED_BAR_COLOR
+        } else {
+            BAR_COLOR
+        };
+        let bottom = y + overlay.bar_height;
+        painter.line(Vec2::new(left, y), Vec2::new(right, y), bar_color);
+        painter.line(
+            Vec2::new(left, bottom),
+            Vec2::new(right, bottom),
+            bar_color,
+        );
+        painter.line(Vec2::new(left, y), Vec2::new(left, bottom), bar_color);
+        painter.line(Vec2::new(right, y), Vec2::new(right, bottom), bar_color);
+        let cursor = x(time_runner.elasped().now());
+        let middle = y + overlay.bar_height / 2.;
+        painter.line(
+            Vec2::new(left, middle),
+            Vec2::new(cursor, middle),
+            CURSOR_COLOR,
+        );
+        painter.line(
+            Vec2::new(cursor, y),
+            Vec2::new(cursor, bottom),
+            CURSOR_COLOR,
+        );
+
+        let mut spans = std::iter::once(*entity)
+            .chain(
+                children
+                    .iter()
+                    .flat_map(|children| children.iter())
+                    .copied(),
+            )
+            .filter_map(|entity| q_span.get(entity).ok())
+            .map(|(span, active)| {
+                (
+                    span.min().duration().as_secs_f32(),
+                    span.max().duration().as_secs_f32(),
+                    active,
+                )
+            })
+            .collect::<Vec<_>>();
+        spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        // End of the last span in each lane
+        let mut lanes: Vec<f32> = Vec::new();
+        for (start, end, active) in spans {
+            let lane =
+                match lanes.iter().position(|lane_end| *lane_end <= start) {
+                    Some(lane) => {
+                        lanes[lane] = end;
+                        lane
+                    }
+                    None => {
+                        lanes.push(end);
+                        lanes.len() - 1
+                    }
+                };
+            let color = if active {
+                ACTIVE_SPAN_COLOR
+            } else {
+                SPAN_COLOR
+            };
+            let lane_y = bottom + overlay.lane_height * (lane as f32 + 1.);
+            if start == end {
+                // Instant span
+                painter.line(
+                    Vec2::new(x(start), lane_y - overlay.lane_height / 2.),
+                    Vec2::new(x(start), lane_y),
+                    color,
+                );
+            } else {
+                painter.line(
+                    Vec2::new(x(start), lane_y),
+                    Vec2::new(x(end), lane_y),
+                    color,
+                );
+            }
+        }
+
+        y = bottom + overlay.lane_height * lanes.len() as f32 + overlay.row_gap;
+    }
+    if rows.next().is_some() {
+        painter.chevron(Vec2::new(left, y), overlay.row_gap);
+    }
+}
+
+/// Draws lines in viewport coordinates
+struct Painter<'a, 'w, 's> {
+    gizmos: &'a mut Gizmos<'w, 's, TweenDebugOverlayGizmos>,
+    camera: &'a Camera,
+    camera_transform: &'a GlobalTransform,
+}
+
+impl Painter<'_, '_, '_> {
+    fn to_world(&self, position: Vec2) -> Option<Vec3> {
+        self.camera
+            .viewport_to_world(self.camera_transform, position)
+            .ok()
+            .map(|ray| ray.get_point(OVERLAY_DISTANCE))
+    }
+
+    fn line(&mut self, start: Vec2, end: Vec2, color: Color) {
+        if let (Some(start), Some(end)) =
+            (self.to_world(start), self.to_world(end))
+        {
+            self.gizmos.line(start, end, color);
+        }
+    }
+
+    /// Chevron pointing toward `height`, downward if positive
+    fn chevron(&mut self, position: Vec2, height: f32) {
+        let tip = position + Vec2::new(height.abs(), height);
+        self.line(position, tip, BAR_COLOR);
+        self.line(tip, position + Vec2::new(height.abs() * 2., 0.), BAR_COLOR);
+    }
+}
@@ -0,0 +1,245 @@
+//! Module containing diagnostics for tween systems
+//!
+//! # Diagnostics
+//!
+//! **Plugins**:
+//! - [`TweenDiagnosticsPlugin`] with the `"diagnostics"` feature
+//!
+//! **Resources**:
+//! - [`TweenDiagnosticsCounters`] with the `"diagnostics"` feature
+//!
+//! **Systems**
+//! - [`tween_diagnostics_system`] with the `"diagnostics"` feature
+//!
+//! With the `"diagnostics"` feature enabled, the built-in sample, apply, and
+//! event systems count their work through the [`TweenCounters`] system param
+//! and [`TweenDiagnosticsPlugin`] reports the counts of every frame as Bevy
+//! [`Diagnostic`](bevy::diagnostic::Diagnostic)s under these paths:
+//!
+//! | Path                   | Count                                                      |
+//! |------------------------|------------------------------------------------------------|
+//! | `tween/tweens_sampled` | Interpolation values sampled by `sample_interpolations_system` |
+//! | `tween/tweens_applied` | Targets applied by the apply systems                       |
+//! | `tween/target_errors`  | Targets that the apply systems failed to resolve           |
+//! | `tween/events_fired`   | [`TweenEvent`](crate::tween_event::TweenEvent)s fired       |
+//!
+//! The counts can be read from [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore)
+//! or logged with [`LogDiagnosticsPlugin`](bevy::diagnostic::LogDiagnosticsPlugin).
+//! Without the feature, [`TweenCounters`] does nothing and accesses no data.
+//!
+//! Custom systems can count their work as well by adding [`TweenCounters`]
+//! to their parameters.
+
+#[cfg(not(feature = "diagnostics"))]
+use std::marker::PhantomData;
+#[cfg(feature = "diagnostics")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "diagnostics")]
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// System param for counting the work of tween systems for
+/// [`TweenDiagnosticsPlugin`].
+///
+/// Counts are only recorded with the `"diagnostics"` feature and when
+/// [`TweenDiagnosticsCounters`] exists, otherwise this does nothing.
+#[derive(SystemParam)]
+pub struct TweenCounters<'w> {
+    #[cfg(feature = "diagnostics")]
+    counters: Option<Res<'w, TweenDiagnosticsCounters>>,
+    #[cfg(not(feature = "diagnostics"))]
+    _marker: PhantomData<&'w ()>,
+}
+
+impl TweenCounters<'_> {
+    /// Count sampled interpolation values
+    #[inline]
+    pub fn add_sampled(&self, count: usize) {
+        #[cfg(feature = "diagnostics")]
+        if let Some(counters) = &self.counters {
+            counters
+                .tweens_sampled
+                .fetch_add(count as u64, Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "diagnostics"))]
+        let _ = count;
+    }
+
+    /// Count applied targets
+    #[inline]
+    pub fn add_applied(&self, count: usize) {
+        #[cfg(feature = "diagnostics")]
+        if let Some(counters) = &self.counters {
+            counters
+                .tweens_applied
+                .fetch_add(count as u64, Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "diagnostics"))]
+        let _ = count;
+    }
+
+    /// Count targets that failed to resolve
+    #[inline]
+    pub fn add_target_errors(&self, count: usize) {
+        #[cfg(feature = "diagnostics")]
+        if let Some(counters) = &self.counters {
+            counters
+                .target_errors
+                .fetch_add(count as u64, Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "diagnostics"))]
+        let _ = count;
+    }
+
+    /// Count fired events
+    #[inline]
+    pub fn add_events_fired(&self, count: usize) {
+        #[cfg(feature = "diagnostics")]
+        if let Some(counters) = &self.counters {
+            counters
+                .events_fired
+                .fetch_add(count as u64, Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "diagnostics"))]
+        let _ = count;
+    }
+}
+
+/// Plugin reporting the work of tween systems as Bevy diagnostics.
+/// See the [module documentation](crate::diagnostics) for the paths.
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+///
+/// # Examples
+///
+/// ```
+/// use bevy::diagnostic::DiagnosticsStore;
+/// use bevy_tween::combinator::{event, parallel, tween};
+/// use bevy_tween::diagnostics::TweenDiagnosticsPlugin;
+/// use bevy_tween::interpolate::translation;
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_plugins(TweenDiagnosticsPlugin);
+///
+/// let a = app.world_mut().spawn(Transform::default()).id();
+/// let b = app.world_mut().spawn(Transform::default()).id();
+/// let missing = app.world_mut().spawn_empty().id();
+/// app.world_mut().commands().animation().insert(parallel((
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         a.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     ),
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         [b, missing].into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     ),
+///     event("start"),
+/// )));
+/// app.world_mut().flush();
+/// for _ in 0..15 {
+///     app.update();
+/// }
+///
+/// // Sum the counts of every frame
+/// let total = |path| -> f64 {
+///     let store = app.world().resource::<DiagnosticsStore>();
+///     store.get(path).unwrap().values().sum()
+/// };
+/// let errors = total(&TweenDiagnosticsPlugin::TARGET_ERRORS);
+/// assert!(errors > 0.);
+/// assert_eq!(total(&TweenDiagnosticsPlugin::TWEENS_APPLIED), errors * 2.);
+/// assert!(total(&TweenDiagnosticsPlugin::TWEENS_SAMPLED) > 0.);
+/// assert_eq!(total(&TweenDiagnosticsPlugin::EVENTS_FIRED), 1.);
+/// ```
+#[cfg(feature = "diagnostics")]
+pub struct TweenDiagnosticsPlugin;
+
+#[cfg(feature = "diagnostics")]
+impl TweenDiagnosticsPlugin {
+    /// Interpolation values sampled in a frame
+    pub const TWEENS_SAMPLED: DiagnosticPath =
+        DiagnosticPath::const_new("tween/tweens_sampled");
+    /// Targets applied in a frame
+    pub const TWEENS_APPLIED: DiagnosticPath =
+        DiagnosticPath::const_new("tween/tweens_applied");
+    /// Targets failed to resolve in a frame
+    pub const TARGET_ERRORS: DiagnosticPath =
+        DiagnosticPath::const_new("tween/target_errors");
+    /// Events fired in a frame
+    pub const EVENTS_FIRED: DiagnosticPath =
+        DiagnosticPath::const_new("tween/events_fired");
+}
+
+#[cfg(feature = "diagnostics")]
+impl Plugin for TweenDiagnosticsPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let schedule = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist")
+            .schedule;
+        app.add_systems(
+            schedule,
+            tween_diagnostics_system
+                .after(crate::TweenSystemSet::ApplyTween)
+                .after(crate::TweenSystemSet::TweenEvent),
+        )
+        .init_resource::<TweenDiagnosticsCounters>()
+        .register_diagnostic(Diagnostic::new(Self::TWEENS_SAMPLED))
+        .register_diagnostic(Diagnostic::new(Self::TWEENS_APPLIED))
+        .register_diagnostic(Diagnostic::new(Self::TARGET_ERRORS))
+        .register_diagnostic(Diagnostic::new(Self::EVENTS_FIRED));
+    }
+}
+
+/// Counts of the current frame recorded through [`TweenCounters`]
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Default, Resource)]
+pub struct TweenDiagnosticsCounters {
+    tweens_sampled: AtomicU64,
+    tweens_applied: AtomicU64,
+    target_errors: AtomicU64,
+    events_fired: AtomicU64,
+}
+
+/// Report and reset [`TweenDiagnosticsCounters`]
+#[cfg(feature = "diagnostics")]
+pub fn tween_diagnostics_system(
+    counters: Res<TweenDiagnosticsCounters>,
+    mut diagnostics: Diagnostics,
+) {
+    let counts = [
+        (
+            &TweenDiagnosticsPlugin::TWEENS_SAMPLED,
+            &counters.tweens_sampled,
+        ),
+        (
+            &TweenDiagnosticsPlugin::TWEENS_APPLIED,
+            &counters.tweens_applied,
+        ),
+        (
+            &TweenDiagnosticsPlugin::TARGET_ERRORS,
+            &counters.target_errors,
+        ),
+        (
+            &TweenDiagnosticsPlugin::EVENTS_FIRED,
+            &counters.events_fired,
+        ),
+    ];
+    for (path, count) in counts {
+        let count = count.swap(0, Ordering::Relaxed);
+        diagnostics.add_measurement(path, || count as f64);
+    }
+}
@@ -0,0 +1,417 @@
+//! Module containing a handle for awaiting animation completion
+//!
+//! # Animation handle
+//!
+//! **Components**:
+//! - [`AnimationCompletion`]
+//! - [`OnAnimationComplete`]
+//!
+//! **Events**:
+//! - [`AnimationEnded`]
+//!
+//! **Systems**
+//! - [`animation_handle_system`]
+//! - [`animation_ended_trigger_system`]
+//! - [`on_animation_complete_system`]
+//!
+//! [`AnimationHandle`] is created by [`AnimationBuilder::handle`] and bridges
+//! animations with task-based flow control. It can be polled with
+//! [`AnimationHandle::outcome`] or awaited as a [`Future`], for example, inside
+//! a task spawned by Bevy's task pools.
+//!
+//! The handle shares its state with the [`AnimationCompletion`] component on
+//! the animation root through an [`Arc`] holding an atomic and the waker of the
+//! awaiting task. [`animation_handle_system`], added by
+//! [`TweenCorePlugin`](crate::TweenCorePlugin), resolves the handle when the
+//! root [`TimeRunner`] completes and carries out cancellation.
+//!
+//! For observers, [`animation_ended_trigger_system`] triggers [`AnimationEnded`]
+//! targeting the animation root once its [`TimeRunner`] completes, so
+//! [`EntityCommands::observe`] can be used on the root instead of reading
+//! [`TimeRunnerEnded`] events. For a single callback,
+//! [`AnimationBuilder::on_complete`] registers a one-shot system run by
+//! [`on_animation_complete_system`] when the animation completes.
+//!
+//! [`AnimationBuilder::handle`]: crate::combinator::AnimationBuilder::handle
+//! [`AnimationBuilder::on_complete`]: crate::combinator::AnimationBuilder::on_complete
+//! [`TimeRunner`]: bevy_time_runner::TimeRunner
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::system::SystemId;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use bevy_time_runner::{
+    Repeat, TimeDirection, TimeRunnerEnded, TimeSpanProgress,
+};
+
+use crate::combinator::clear_animation;
+use crate::tween::TweenInterpolationValue;
+
+const RUNNING: u8 = 0;
+const COMPLETED: u8 = 1;
+const CANCELED: u8 = 2;
+
+/// How an animation awaited by [`AnimationHandle`] finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationOutcome {
+    /// The root [`TimeRunner`] completed with all repeats exhausted
+    ///
+    /// [`TimeRunner`]: bevy_time_runner::TimeRunner
+    Completed,
+    /// The animation was canceled through [`AnimationHandle::cancel`] or the
+    /// animation root was despawned before completing
+    Canceled,
+}
+
+#[derive(Debug, Default)]
+struct HandleState {
+    outcome: AtomicU8,
+    cancel_requested: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl HandleState {
+    fn outcome(&self) -> Option<AnimationOutcome> {
+        match self.outcome.load(Ordering::Acquire) {
+            COMPLETED => Some(AnimationOutcome::Completed),
+            CANCELED => Some(AnimationOutcome::Canceled),
+            _ => None,
+        }
+    }
+
+    fn finish(&self, outcome: AnimationOutcome) {
+        let outcome = match outcome {
+            AnimationOutcome::Completed => COMPLETED,
+            AnimationOutcome::Canceled => CANCELED,
+        };
+        if self
+            .outcome
+            .compare_exchange(
+                RUNNING,
+                outcome,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_err()
+        {
+            return;
+        }
+        let waker = self.waker.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Handle resolving when an animation completes.
+///
+/// Created by [`AnimationBuilder::handle`]. The handle can be cloned and sent
+/// to other threads. Awaiting it outputs the [`AnimationOutcome`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::tasks::{block_on, poll_once};
+/// use bevy_tween::combinator::tween;
+/// use bevy_tween::handle::AnimationOutcome;
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// let mut handle = {
+///     let mut commands = app.world_mut().commands();
+///     let mut animation = commands.animation();
+///     let handle = animation.handle();
+///     animation.insert(tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     ));
+///     handle
+/// };
+/// app.world_mut().flush();
+///
+/// let mut outcome = None;
+/// for _ in 0..20 {
+///     app.update();
+///     outcome = block_on(poll_once(&mut handle));
+///     if outcome.is_some() {
+///         break;
+///     }
+/// }
+/// assert_eq!(outcome, Some(AnimationOutcome::Completed));
+/// let transform = app.world().get::<Transform>(entity).unwrap();
+/// assert_eq!(transform.translation, Vec3::X);
+/// ```
+///
+/// [`AnimationBuilder::handle`]: crate::combinator::AnimationBuilder::handle
+#[derive(Debug, Clone)]
+pub struct AnimationHandle(Arc<HandleState>);
+
+impl AnimationHandle {
+    /// Outcome of the animation or `None` if it's still running
+    pub fn outcome(&self) -> Option<AnimationOutcome> {
+        self.0.outcome()
+    }
+
+    /// Returns `true` if the animation has completed or been canceled
+    pub fn is_finished(&self) -> bool {
+        self.outcome().is_some()
+    }
+
+    /// Request the animation to be canceled.
+    ///
+    /// Cancellation is carried out by [`animation_handle_system`] on its next
+    /// run which stops the animation where it is: the animation is removed from
+    /// the animation root with [`clear_animation`], which removes the
+    /// [`TimeRunner`] and despawns the tween and event children. The root
+    /// entity and its other children are kept. Does nothing if the animation
+    /// already finished.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_tween::combinator::tween;
+    /// use bevy_tween::handle::AnimationOutcome;
+    /// use bevy_tween::interpolate::translation;
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, DefaultTweenPlugins));
+    ///
+    /// let entity = app.world_mut().spawn(Transform::default()).id();
+    /// let sprite = app.world_mut().spawn_empty().set_parent(entity).id();
+    /// let handle = {
+    ///     let mut commands = app.world_mut().commands();
+    ///     let mut entity_commands = commands.entity(entity);
+    ///     let mut animation = entity_commands.animation();
+    ///     let handle = animation.handle();
+    ///     animation.insert(tween(
+    ///         Duration::from_secs(1),
+    ///         EaseKind::Linear,
+    ///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+    ///     ));
+    ///     handle
+    /// };
+    /// app.world_mut().flush();
+    /// app.update();
+    ///
+    /// handle.cancel();
+    /// app.update();
+    ///
+    /// assert_eq!(handle.outcome(), Some(AnimationOutcome::Canceled));
+    /// // Only the tween is despawned
+    /// let children = app.world().get::<Children>(entity).unwrap();
+    /// assert_eq!(&**children, &[sprite]);
+    /// ```
+    ///
+    /// [`clear_animation`]: crate::combinator::clear_animation
+    /// [`TimeRunner`]: bevy_time_runner::TimeRunner
+    pub fn cancel(&self) {
+        self.0.cancel_requested.store(true, Ordering::Release);
+    }
+}
+
+impl Future for AnimationHandle {
+    type Output = AnimationOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker = self.0.waker.lock().unwrap_or_else(|e| e.into_inner());
+        match self.0.outcome() {
+            Some(outcome) => Poll::Ready(outcome),
+            None => {
+                *waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// State of an [`AnimationHandle`] on the animation root.
+///
+/// The handle resolves with [`AnimationOutcome::Canceled`] if this component
+/// is dropped before the animation completed, for example, when the root is
+/// despawned.
+#[derive(Debug, Component)]
+pub struct AnimationCompletion(Arc<HandleState>);
+
+impl AnimationCompletion {
+    /// Create a new [`AnimationCompletion`] and its [`AnimationHandle`]
+    pub fn with_handle() -> (AnimationCompletion, AnimationHandle) {
+        let state = Arc::new(HandleState::default());
+        (AnimationCompletion(state.clone()), AnimationHandle(state))
+    }
+}
+
+impl Drop for AnimationCompletion {
+    fn drop(&mut self) {
+        self.0.finish(AnimationOutcome::Canceled);
+    }
+}
+
+/// Resolve [`AnimationHandle`]s of completed [`TimeRunner`]s and cancel
+/// animations requested by [`AnimationHandle::cancel`].
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+pub fn animation_handle_system(
+    mut commands: Commands,
+    q_completion: Query<(Entity, &AnimationCompletion)>,
+    mut ended: EventReader<TimeRunnerEnded>,
+) {
+    for ended in ended.read() {
+        if !ended.is_completed() {
+            continue;
+        }
+        if let Ok((_, completion)) = q_completion.get(ended.time_runner) {
+            completion.0.finish(AnimationOutcome::Completed);
+        }
+    }
+    for (entity, completion) in &q_completion {
+        if completion.0.outcome().is_some()
+            || !completion.0.cancel_requested.load(Ordering::Acquire)
+        {
+            continue;
+        }
+        completion.0.finish(AnimationOutcome::Canceled);
+        commands
+            .entity(entity)
+            .remove::<(
+                AnimationCompletion,
+                TimeSpanProgress,
+                TweenInterpolationValue,
+            )>()
+            .queue(clear_animation);
+    }
+}
+
+/// Triggered by [`animation_ended_trigger_system`] targeting the animation
+/// root when its [`TimeRunner`] completes, carrying the same fields as
+/// [`TimeRunnerEnded`].
+///
+/// The trigger runs once per completion, when all repeats are exhausted, and
+/// not on every repeat. Read [`TimeRunnerEnded`] events to also be notified
+/// of repeats.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::forward;
+/// use bevy_tween::handle::AnimationEnded;
+///
+/// #[derive(Default, Resource)]
+/// struct Ended(usize);
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.init_resource::<Ended>();
+///
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .repeat(Repeat::times(2))
+///     .insert(forward(Duration::from_millis(500)))
+///     .observe(|trigger: Trigger<AnimationEnded>, mut ended: ResMut<Ended>| {
+///         assert!(trigger.is_completed());
+///         ended.0 += 1;
+///     });
+/// app.world_mut().flush();
+/// for _ in 0..30 {
+///     app.update();
+/// }
+///
+/// // Once after the third play instead of on every repeat
+/// assert_eq!(app.world().resource::<Ended>().0, 1);
+/// ```
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[derive(Debug, Clone, PartialEq, Event)]
+pub struct AnimationEnded {
+    /// Direction the time runner was playing in when it ended
+    pub current_direction: TimeDirection,
+    /// The repeat the time runner continues with or `None` if completed
+    pub with_repeat: Option<Repeat>,
+}
+
+impl AnimationEnded {
+    /// Returns `true` if the animation completed with all repeats exhausted
+    pub fn is_completed(&self) -> bool {
+        self.with_repeat.is_none()
+    }
+}
+
+impl From<&TimeRunnerEnded> for AnimationEnded {
+    fn from(ended: &TimeRunnerEnded) -> Self {
+        AnimationEnded {
+            current_direction: ended.current_direction,
+            with_repeat: ended.with_repeat,
+        }
+    }
+}
+
+/// Trigger [`AnimationEnded`] targeting the time runner entity for every
+/// completed [`TimeRunnerEnded`].
+pub fn animation_ended_trigger_system(
+    mut commands: Commands,
+    mut ended: EventReader<TimeRunnerEnded>,
+) {
+    for ended in ended.read() {
+        if !ended.is_completed() {
+            continue;
+        }
+        commands
+            .trigger_targets(AnimationEnded::from(ended), ended.time_runner);
+    }
+}
+
+/// One-shot system registered by [`AnimationBuilder::on_complete`] on the
+/// animation root, run with the root entity as input by
+/// [`on_animation_complete_system`] when the root [`TimeRunner`] completes.
+///
+/// This component is removed after the system runs. The system is
+/// unregistered whenever this component is removed or replaced, including
+/// when the root is despawned before completing, in which case the system
+/// never runs.
+///
+/// [`AnimationBuilder::on_complete`]: crate::combinator::AnimationBuilder::on_complete
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[derive(Debug, Component)]
+#[component(on_replace = unregister_on_animation_complete)]
+pub struct OnAnimationComplete(pub SystemId<In<Entity>>);
+
+fn unregister_on_animation_complete(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let Some(on_complete) = world.get::<OnAnimationComplete>(entity) else {
+        return;
+    };
+    let system = on_complete.0;
+    world.commands().unregister_system(system);
+}
+
+/// Run the [`OnAnimationComplete`] systems of completed [`TimeRunner`]s.
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+pub fn on_animation_complete_system(
+    mut commands: Commands,
+    q_on_complete: Query<&OnAnimationComplete>,
+    mut ended: EventReader<TimeRunnerEnded>,
+) {
+    for ended in ended.read() {
+        if !ended.is_completed() {
+            continue;
+        }
+        let Ok(on_complete) = q_on_complete.get(ended.time_runner) else {
+            continue;
+        };
+        commands.run_system_with_input(on_complete.0, ended.time_runner);
+        commands
+            .entity(ended.time_runner)
+            .remove::<OnAnimationComplete>();
+    }
+}
@@ -6,11 +6,108 @@
 //!
 //! **Built-in interpolators**:
 //! - [`Translation`]
+//! - [`TranslationSnapped`]
+//! - [`TranslationMaxSpeed`]
+//! - [`TranslationZ`]
+//! - [`TranslationWrapped`]
+//! - [`TranslationArc`]
+//! - [`TranslationAdd`]
 //! - [`Rotation`]
 //! - [`Scale`]
+//! - [`ScaleFromCurrent`]
 //! - [`AngleZ`]
+//! - [`RotationZLookAt`]
+//! - [`AimAt`]
+//! - [`TranslationPath`] and [`TranslationPath2d`]
+//! - [`TwoBoneIk`]
+//! - [`Constrained`]
+//! - [`FaceMotion`]
+//! - [`WithTransformCallback`]
+//! - [`PoseCrossfade`]
+//! - [`PhasedTransform`]
+//! - [`Settle`]
+//! - [`Mirror`]
+//! - [`Relative`]
+//! - [`Blend`] and [`BlendWeight`]
+//! - [`TranslationCurve`], [`RotationCurve`], and [`ScaleCurve`]
 //! - [`SpriteColor`]
+//! - [`SpriteColorHsv`]
+//! - [`SpriteGradient`]
 //! - [`ColorMaterial`]
+//! - [`Wireframe2dColor`]
+//! - [`Wireframe2dConfigColor`]
+//! - [`CameraClearColor`]
+//! - [`TranslationToScreen`]
+//! - [`VisibilityBlink`]
+//! - [`AudioSinkVolume`]
+//! - [`SpatialAudioSinkVolume`]
+//! - [`SpatialListenerGap`]
+//! - [`ReflectField`]
+//! - [`Light2dIntensity`] and [`Light2dColor`] for [`Light2d`] components
+//! - [`ClosureDt`]
+//!
+//! # Extrapolation
+//!
+//! Progress of a tween is clamped between 0–1 but the sampled value is not.
+//! Eases that overshoot, such as [`EaseKind::BackOut`] or
+//! [`EaseKind::ElasticOut`], produce values below 0 or above 1 and these
+//! values are passed to interpolators as is.
+//!
+//! Interpolators using `lerp`, `slerp`, or `mix` extrapolate past their
+//! endpoints: [`Translation`], [`TranslationZ`], [`TranslationSnapped`],
+//! [`TranslationMaxSpeed`] within its cap, [`TranslationAdd`], [`Rotation`],
+//! [`Scale`], [`AngleZ`], and the color interpolators such as [`SpriteColor`].
+//! [`TranslationPath`] extrapolates along its first and last segments and
+//! [`TranslationArc`] continues along its parabola. Extrapolated colors may
+//! have channels outside of their valid range, which are clamped when
+//! rendered, and extrapolated audio volumes may become negative.
+//!
+//! Other interpolators stay within their endpoints:
+//! - [`TranslationCurve`], [`RotationCurve`], [`ScaleCurve`], and
+//!   [`SpriteGradient`] clamp the value between 0–1.
+//! - [`Mirror`] switches at 1, so an overshoot only switches earlier.
+//! - [`PhasedTransform`] clamps the value within each of its phases.
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+//! use bevy_tween::interpolate::{translation, Translation};
+//! use bevy_tween::interpolation::sample_interpolations_system;
+//! use bevy_tween::prelude::*;
+//! use bevy_tween::tween::apply_component_tween_system;
+//!
+//! let mut world = World::new();
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(
+//!     (
+//!         sample_interpolations_system::<EaseKind>,
+//!         apply_component_tween_system::<Translation>,
+//!     )
+//!         .chain(),
+//! );
+//!
+//! let end = Vec3::new(10., 0., 0.);
+//! let target = world.spawn(Transform::default()).id();
+//! world.spawn((
+//!     TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+//!     EaseKind::BackOut,
+//!     target.into_target().with(translation(Vec3::ZERO, end)),
+//!     TimeSpanProgress {
+//!         now_percentage: 0.6,
+//!         now: 0.6,
+//!         previous_percentage: 0.,
+//!         previous: 0.,
+//!     },
+//! ));
+//! schedule.run(&mut world);
+//!
+//! // Overshot past the end
+//! let transform = world.get::<Transform>(target).unwrap();
+//! assert!(transform.translation.x > end.x);
+//! ```
+//!
+//! [`EaseKind::BackOut`]: crate::interpolation::EaseKind::BackOut
+//! [`EaseKind::ElasticOut`]: crate::interpolation::EaseKind::ElasticOut
 //!
 //! # Your own [`Interpolator`]
 //!
@@ -69,18 +166,47 @@
 //! [`resource_tween_system`]: crate::tween::resource_tween_system
 //! [`asset_tween_system`]: crate::tween::asset_tween_system
 
+#[cfg(feature = "bevy_audio")]
+mod audio;
 mod blanket_impl;
+mod blend;
+mod curve;
+mod ik;
+mod light_2d;
+mod path;
+mod reflect;
+mod relative;
+#[cfg(feature = "bevy_render")]
+mod render;
 #[cfg(feature = "bevy_sprite")]
 mod sprite;
+#[cfg(feature = "bevy_text")]
+mod text;
 mod transform;
 #[cfg(feature = "bevy_ui")]
 mod ui;
 
+pub use blend::*;
+pub use curve::*;
+pub use ik::*;
+pub use light_2d::*;
+pub use path::*;
+pub use reflect::*;
+pub use relative::*;
 pub use transform::*;
 
+#[cfg(feature = "bevy_audio")]
+pub use audio::*;
+
+#[cfg(feature = "bevy_render")]
+pub use render::*;
+
 #[cfg(feature = "bevy_sprite")]
 pub use sprite::*;
 
+#[cfg(feature = "bevy_text")]
+pub use text::*;
+
 #[cfg(feature = "bevy_ui")]
 pub use ui::*;
 
@@ -101,6 +227,132 @@ where
     Box::new(f)
 }
 
+type InterpolatorClosureDt<I> =
+    Box<dyn Fn(&mut I, f32, f32) + Send + Sync + 'static>;
+
+/// Dynamic [`Interpolator`] calling a closure with the item, the tweened
+/// value, and the time the tween advanced since the last frame in seconds, for
+/// example, to integrate a velocity inside an otherwise time-normalized tween.
+///
+/// The delta time is recorded by [`closure_dt_system`] from the tween's
+/// [`TimeSpanProgress`](bevy_time_runner::TimeSpanProgress), so it follows the
+/// time runner rather than the frame: it's `0.` while the animation is
+/// paused or the tween isn't progressing, and it's scaled by the time runner's
+/// time scale.
+///
+/// # Delta time
+///
+/// The delta time is negative while the animation plays backward and can be
+/// any size when the animation is seeked or jumps, for example, with
+/// [`reset_to_start`](crate::combinator::reset_to_start) or when repeating.
+/// Closures integrating over time should decide what to do with these, for
+/// example, ignore negative deltas with `dt.max(0.)` or clamp large ones with
+/// `dt.min(max_step)`.
+///
+/// [`closure_dt_system`] and [`component_tween_system`](tween::component_tween_system)
+/// of `ClosureDt<C>` are registered for [`Transform`] by
+/// [`DefaultDynInterpolatorsPlugin`]. Register both for other components.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+///
+/// use bevy_tween::interpolate::closure_dt;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let deltas = Arc::new(Mutex::new(Vec::new()));
+/// let recorded = deltas.clone();
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// app.world_mut()
+///     .commands()
+///     .entity(entity)
+///     .animation()
+///     .insert_tween_here(
+///         Duration::from_secs(1),
+///         EaseKind::QuadraticIn,
+///         // Accelerate up to 10 units per second
+///         entity.into_target().with(closure_dt(
+///             move |transform: &mut Transform, value, dt| {
+///                 recorded.lock().unwrap().push(dt);
+///                 transform.translation.x += value * 10. * dt.max(0.);
+///             },
+///         )),
+///     );
+/// app.world_mut().flush();
+///
+/// let mut previous = 0.;
+/// for _ in 0..10 {
+///     app.update();
+///     let x = app.world().get::<Transform>(entity).unwrap().translation.x;
+///     assert!(x >= previous);
+///     previous = x;
+/// }
+/// assert!(previous > 0.);
+/// let deltas = deltas.lock().unwrap();
+/// assert!(deltas.iter().any(|dt| *dt > 0.));
+/// assert!(deltas.iter().all(|dt| *dt >= 0.));
+/// ```
+pub struct ClosureDt<I> {
+    closure: InterpolatorClosureDt<I>,
+    /// Time the tween advanced since the last frame in seconds
+    pub delta: f32,
+}
+
+impl<I> ClosureDt<I> {
+    /// Set the time the tween advanced since the last frame
+    pub fn resolve(&mut self, delta: f32) {
+        self.delta = delta;
+    }
+}
+
+impl<I> Interpolator for ClosureDt<I>
+where
+    I: 'static,
+{
+    type Item = I;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        (self.closure)(item, value, self.delta)
+    }
+}
+
+/// Constructor for [`ClosureDt`]
+pub fn closure_dt<I, F>(f: F) -> ClosureDt<I>
+where
+    I: 'static,
+    F: Fn(&mut I, f32, f32) + Send + Sync + 'static,
+{
+    ClosureDt {
+        closure: Box::new(f),
+        delta: 0.,
+    }
+}
+
+/// Record the time [`ClosureDt`] tweens advanced since the last frame.
+#[allow(clippy::type_complexity)]
+pub fn closure_dt_system<C>(
+    mut q_tween: Query<
+        (
+            &mut tween::ComponentTween<ClosureDt<C>>,
+            Option<Ref<bevy_time_runner::TimeSpanProgress>>,
+        ),
+        Without<tween::SkipTween>,
+    >,
+) where
+    C: Component,
+{
+    for (mut tween, progress) in &mut q_tween {
+        let delta = match progress {
+            Some(progress) if progress.is_changed() => {
+                progress.now - progress.previous
+            }
+            _ => 0.,
+        };
+        tween.interpolator.resolve(delta);
+    }
+}
+
 /// [`Interpolator`] is used to specify how to interpolate an [`Self::Item`] by the
 /// implementor.
 ///
@@ -108,11 +360,47 @@ where
 /// and being object-safe for dynamic interpolator.
 ///
 /// See [module-level documentation](self) for more info.
+///
+/// # Wrappers
+///
+/// [`Box`], [`Arc`](std::sync::Arc), and `&'static` references of an
+/// interpolator are interpolators delegating to it, so generic code can wrap
+/// interpolators without extra boilerplate. [`Option`] of an interpolator is
+/// an interpolator as well which does nothing when `None`, leaving the item
+/// untouched.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{translation, Translation};
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = translation(Vec3::ZERO, Vec3::X);
+/// let boxed = Box::new(interpolator.clone());
+/// let shared = Arc::new(interpolator.clone());
+/// let some = Some(interpolator);
+/// let none: Option<Translation> = None;
+///
+/// let mut transform = Transform::default();
+/// boxed.interpolate(&mut transform, 0.25);
+/// assert_eq!(transform.translation, Vec3::X * 0.25);
+/// shared.interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::X * 0.5);
+/// some.interpolate(&mut transform, 1.);
+/// assert_eq!(transform.translation, Vec3::X);
+///
+/// // `None` leaves the item untouched
+/// none.interpolate(&mut transform, 0.);
+/// assert_eq!(transform.translation, Vec3::X);
+/// ```
 pub trait Interpolator: Send + Sync + 'static {
     /// Type to be interpolated.
     type Item;
     /// Interpolate an item using `value` which is typically between 0–1.
     /// The value should be already sampled from an [`Interpolation`]
+    /// and may be outside of 0–1 with overshooting eases.
+    /// See [extrapolation](self#extrapolation).
     ///
     /// [`Interpolation`]: crate::interpolation::Interpolation
     fn interpolate(&self, item: &mut Self::Item, value: f32);
@@ -199,11 +487,33 @@ pub trait Interpolator: Send + Sync + 'static {
 ///
 /// Register type and systems for the following interpolators:
 /// - [`Translation`]
+/// - [`TranslationSnapped`]
+/// - [`TranslationMaxSpeed`]
+/// - [`TranslationZ`]
+/// - [`TranslationWrapped`]
+/// - [`TranslationArc`]
+/// - [`TranslationAdd`]
 /// - [`Rotation`]
 /// - [`Scale`]
+/// - [`ScaleFromCurrent`]
 /// - [`AngleZ`]
-/// - [`SpriteColor`] and [`ColorMaterial`] if `"bevy_sprite"` feature is enabled.
-/// - [`BackgroundColor`] and [`BorderColor`] if `"bevy_ui"` feature is enabled.
+/// - [`RotationZLookAt`]
+/// - [`AimAt`]
+/// - [`TranslationPath`]
+/// - [`TranslationPath2d`]
+/// - [`TwoBoneIk`]
+/// - [`PoseCrossfade`]
+/// - [`PhasedTransform`]
+/// - [`Settle`]
+/// - [`Mirror`]
+/// - [`Relative`] of [`Translation`], [`Scale`], [`Rotation`], and [`AngleZ`]
+/// - [`Blend`] and [`BlendWeight`] of [`Translation`], [`Scale`], and [`Rotation`]
+/// - [`TranslationCurve`], [`RotationCurve`], and [`ScaleCurve`]
+/// - [`SpriteColor`], [`SpriteColorHsv`], [`SpriteGradient`], [`ColorMaterial`], and [`Wireframe2dColor`] if `"bevy_sprite"` feature is enabled.
+/// - [`BackgroundColor`], [`BorderColor`], [`ZIndex`], [`GlobalZIndex`], [`NodeWidth`], and [`NodeHeight`] if `"bevy_ui"` feature is enabled.
+/// - [`TextColor`] and [`TextFontSize`] if `"bevy_text"` feature is enabled.
+/// - [`CameraClearColor`], [`TranslationToScreen`], and [`VisibilityBlink`] if `"bevy_render"` feature is enabled.
+/// - [`AudioSinkVolume`], [`SpatialAudioSinkVolume`], and [`SpatialListenerGap`] if `"bevy_audio"` feature is enabled.
 pub struct DefaultInterpolatorsPlugin;
 impl Plugin for DefaultInterpolatorsPlugin {
     /// # Panics
@@ -212,44 +522,203 @@ impl Plugin for DefaultInterpolatorsPlugin {
     ///
     /// [`TweenAppResource`]: crate::TweenAppResource
     fn build(&self, app: &mut App) {
+        let schedule = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist")
+            .schedule;
+        app.add_systems(
+            schedule,
+            (
+                aim_at_system,
+                translation_max_speed_system,
+                relative_repeat_system::<Translation>,
+                relative_repeat_system::<Scale>,
+                relative_repeat_system::<Rotation>,
+                relative_repeat_system::<AngleZ>,
+                additive_translation_reset_system,
+                single_target_check_system::<ScaleFromCurrent>,
+                single_target_check_system::<RotationZLookAt>,
+            )
+                .in_set(crate::TweenSystemSet::UpdateInterpolationValue),
+        )
+        .add_systems(
+            schedule,
+            (
+                additive_translation_system,
+                tween_blend_system::<Translation>,
+                tween_blend_system::<Scale>,
+                tween_blend_system::<Rotation>,
+            )
+                .after(crate::TweenSystemSet::ApplyTween)
+                .before(crate::TweenSystemSet::PostApply),
+        );
+
         app.add_tween_systems((
             tween::component_tween_system::<Translation>(),
+            tween::component_tween_system::<TranslationSnapped>(),
+            tween::component_tween_system::<TranslationMaxSpeed>(),
+            tween::component_tween_system::<TranslationZ>(),
+            tween::component_tween_system::<TranslationWrapped>(),
+            tween::component_tween_system::<TranslationArc>(),
+            tween::component_tween_system::<TranslationAdd>(),
             tween::component_tween_system::<Rotation>(),
             tween::component_tween_system::<Scale>(),
+            tween::component_tween_system::<ScaleFromCurrent>(),
             tween::component_tween_system::<AngleZ>(),
+            tween::component_tween_system::<RotationZLookAt>(),
+            tween::component_tween_system::<AimAt>(),
+            tween::component_tween_system::<TranslationPath>(),
+            tween::component_tween_system::<TranslationPath2d>(),
+            tween::component_tween_system::<TwoBoneIk>(),
+            tween::component_tween_system::<PoseCrossfade>(),
+            tween::component_tween_system::<PhasedTransform>(),
+            tween::component_tween_system::<Settle>(),
+            tween::component_tween_system::<Mirror>(),
         ))
         .register_type::<tween::ComponentTween<Translation>>()
+        .register_type::<tween::ComponentTween<TranslationSnapped>>()
+        .register_type::<tween::ComponentTween<TranslationMaxSpeed>>()
+        .register_type::<tween::ComponentTween<TranslationZ>>()
+        .register_type::<tween::ComponentTween<TranslationWrapped>>()
+        .register_type::<tween::ComponentTween<TranslationArc>>()
+        .register_type::<tween::ComponentTween<TranslationAdd>>()
+        .register_type::<AdditiveTranslation>()
         .register_type::<tween::ComponentTween<Rotation>>()
         .register_type::<tween::ComponentTween<Scale>>()
-        .register_type::<tween::ComponentTween<AngleZ>>();
+        .register_type::<tween::ComponentTween<ScaleFromCurrent>>()
+        .register_type::<tween::ComponentTween<AngleZ>>()
+        .register_type::<tween::ComponentTween<RotationZLookAt>>()
+        .register_type::<tween::ComponentTween<AimAt>>()
+        .register_type::<tween::ComponentTween<TranslationPath>>()
+        .register_type::<tween::ComponentTween<TranslationPath2d>>()
+        .register_type::<tween::ComponentTween<TwoBoneIk>>()
+        .register_type::<tween::ComponentTween<PoseCrossfade>>()
+        .register_type::<tween::ComponentTween<PhasedTransform>>()
+        .register_type::<tween::ComponentTween<Settle>>()
+        .register_type::<tween::ComponentTween<Mirror>>();
+
+        app.add_tween_systems((
+            tween::component_tween_system::<Relative<Translation>>(),
+            tween::component_tween_system::<Relative<Scale>>(),
+            tween::component_tween_system::<Relative<Rotation>>(),
+            tween::component_tween_system::<Relative<AngleZ>>(),
+        ))
+        .register_type::<tween::ComponentTween<Relative<Translation>>>()
+        .register_type::<tween::ComponentTween<Relative<Scale>>>()
+        .register_type::<tween::ComponentTween<Relative<Rotation>>>()
+        .register_type::<tween::ComponentTween<Relative<AngleZ>>>();
+
+        app.add_tween_systems((
+            tween::component_tween_system::<Blend<Translation>>(),
+            tween::component_tween_system::<Blend<Scale>>(),
+            tween::component_tween_system::<Blend<Rotation>>(),
+            tween::component_tween_system::<BlendWeight<Translation>>(),
+            tween::component_tween_system::<BlendWeight<Scale>>(),
+            tween::component_tween_system::<BlendWeight<Rotation>>(),
+        ))
+        .register_type::<tween::ComponentTween<Blend<Translation>>>()
+        .register_type::<tween::ComponentTween<Blend<Scale>>>()
+        .register_type::<tween::ComponentTween<Blend<Rotation>>>()
+        .register_type::<tween::ComponentTween<BlendWeight<Translation>>>()
+        .register_type::<tween::ComponentTween<BlendWeight<Scale>>>()
+        .register_type::<tween::ComponentTween<BlendWeight<Rotation>>>()
+        .register_type::<TweenBlend<Translation>>()
+        .register_type::<TweenBlend<Scale>>()
+        .register_type::<TweenBlend<Rotation>>();
+
+        app.add_tween_systems((
+            tween::component_tween_system::<TranslationCurve>(),
+            tween::component_tween_system::<RotationCurve>(),
+            tween::component_tween_system::<ScaleCurve>(),
+        ));
 
         #[cfg(feature = "bevy_sprite")]
-        app.add_tween_systems(tween::component_tween_system::<SpriteColor>())
-            .register_type::<tween::ComponentTween<SpriteColor>>();
+        app.add_systems(
+            schedule,
+            sprite_gradient_image_system
+                .in_set(crate::TweenSystemSet::UpdateInterpolationValue),
+        )
+        .add_tween_systems((
+            tween::component_tween_system::<SpriteColor>(),
+            tween::component_tween_system::<SpriteColorHsv>(),
+            tween::component_tween_system::<SpriteGradient>(),
+            tween::component_tween_system::<Wireframe2dColor>(),
+        ))
+        .register_type::<tween::ComponentTween<SpriteColor>>()
+        .register_type::<tween::ComponentTween<SpriteColorHsv>>()
+        .register_type::<tween::ComponentTween<SpriteGradient>>()
+        .register_type::<tween::ComponentTween<Wireframe2dColor>>();
 
         #[cfg(feature = "bevy_ui")]
         app.add_tween_systems((
             tween::component_tween_system::<ui::BackgroundColor>(),
             tween::component_tween_system::<ui::BorderColor>(),
+            tween::component_tween_system::<ui::ZIndex>(),
+            tween::component_tween_system::<ui::GlobalZIndex>(),
+            tween::component_tween_system::<ui::NodeWidth>(),
+            tween::component_tween_system::<ui::NodeHeight>(),
         ))
         .register_type::<tween::ComponentTween<ui::BackgroundColor>>()
-        .register_type::<tween::ComponentTween<ui::BorderColor>>();
+        .register_type::<tween::ComponentTween<ui::BorderColor>>()
+        .register_type::<tween::ComponentTween<ui::ZIndex>>()
+        .register_type::<tween::ComponentTween<ui::GlobalZIndex>>()
+        .register_type::<tween::ComponentTween<ui::NodeWidth>>()
+        .register_type::<tween::ComponentTween<ui::NodeHeight>>();
+
+        #[cfg(feature = "bevy_text")]
+        app.add_tween_systems((
+            tween::component_tween_system::<text::TextColor>(),
+            tween::component_tween_system::<text::TextFontSize>(),
+        ))
+        .register_type::<tween::ComponentTween<text::TextColor>>()
+        .register_type::<tween::ComponentTween<text::TextFontSize>>();
 
         #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset",))]
         app.add_tween_systems(
             tween::asset_tween_system::<sprite::ColorMaterial>(),
         )
         .register_type::<tween::AssetTween<sprite::ColorMaterial>>();
+
+        #[cfg(feature = "bevy_render")]
+        {
+            app.add_systems(
+                schedule,
+                (translation_to_screen_system, visibility_blink_system)
+                    .in_set(crate::TweenSystemSet::UpdateInterpolationValue),
+            )
+            .add_tween_systems((
+                tween::component_tween_system::<CameraClearColor>(),
+                tween::component_tween_system::<TranslationToScreen>(),
+                tween::component_tween_system::<VisibilityBlink>(),
+            ))
+            .register_type::<tween::ComponentTween<CameraClearColor>>()
+            .register_type::<tween::ComponentTween<TranslationToScreen>>()
+            .register_type::<tween::ComponentTween<VisibilityBlink>>();
+        }
+
+        #[cfg(feature = "bevy_audio")]
+        app.add_tween_systems((
+            tween::component_tween_system::<AudioSinkVolume>(),
+            tween::component_tween_system::<SpatialAudioSinkVolume>(),
+            tween::component_tween_system::<SpatialListenerGap>(),
+        ))
+        .register_type::<tween::ComponentTween<AudioSinkVolume>>()
+        .register_type::<tween::ComponentTween<SpatialAudioSinkVolume>>()
+        .register_type::<tween::ComponentTween<SpatialListenerGap>>();
     }
 }
 
 /// Default dynamic interpolators
 ///
 /// Register systems for the following:
-/// - [`Transform`] component.
+/// - [`Transform`] component, including [`ClosureDt`].
 /// - [`Sprite`] component if `"bevy_sprite"` feature is enabled.
 /// - [`ColorMaterial`] asset if `"bevy_sprite"` feature is enabled.
-/// - [`BackgroundColor`] and [`BorderColor`] components if `"bevy_ui"` feature is enabled.
+/// - [`BackgroundColor`], [`BorderColor`], [`ZIndex`], [`GlobalZIndex`], and [`Node`] components if `"bevy_ui"` feature is enabled.
+/// - [`TextColor`] and [`TextFont`] components if `"bevy_text"` feature is enabled.
+/// - [`Camera`] component if `"bevy_render"` feature is enabled.
+/// - [`AudioSink`], [`SpatialAudioSink`], and [`SpatialListener`] components if `"bevy_audio"` feature is enabled.
 ///
 /// [`ColorMaterial`]: bevy::sprite::ColorMaterial
 pub struct DefaultDynInterpolatorsPlugin;
@@ -260,9 +729,20 @@ impl Plugin for DefaultDynInterpolatorsPlugin {
     ///
     /// [`TweenAppResource`]: crate::TweenAppResource
     fn build(&self, app: &mut App) {
-        app.add_tween_systems(tween::component_tween_system::<
-            BoxedInterpolator<Transform>,
-        >());
+        let schedule = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist")
+            .schedule;
+        app.add_systems(
+            schedule,
+            closure_dt_system::<Transform>
+                .in_set(crate::TweenSystemSet::UpdateInterpolationValue),
+        );
+        app.add_tween_systems((
+            tween::component_tween_system::<BoxedInterpolator<Transform>>(),
+            tween::component_tween_system::<ClosureDt<Transform>>(),
+        ));
 
         #[cfg(feature = "bevy_sprite")]
         app.add_tween_systems(tween::component_tween_system::<
@@ -277,11 +757,52 @@ impl Plugin for DefaultDynInterpolatorsPlugin {
             tween::component_tween_system::<
                 BoxedInterpolator<bevy::prelude::BorderColor>,
             >(),
+            tween::component_tween_system::<
+                BoxedInterpolator<bevy::prelude::ZIndex>,
+            >(),
+            tween::component_tween_system::<
+                BoxedInterpolator<bevy::prelude::GlobalZIndex>,
+            >(),
+            tween::component_tween_system::<BoxedInterpolator<Node>>(),
+        ));
+
+        #[cfg(feature = "bevy_text")]
+        app.add_tween_systems((
+            tween::component_tween_system::<
+                BoxedInterpolator<bevy::prelude::TextColor>,
+            >(),
+            tween::component_tween_system::<BoxedInterpolator<TextFont>>(),
         ));
 
         #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset",))]
         app.add_tween_systems(tween::asset_tween_system::<
             BoxedInterpolator<bevy::sprite::ColorMaterial>,
         >());
+
+        #[cfg(feature = "bevy_render")]
+        app.add_tween_systems(tween::component_tween_system::<
+            BoxedInterpolator<Camera>,
+        >());
+
+        #[cfg(feature = "bevy_audio")]
+        app.add_tween_systems((
+            tween::component_tween_system::<BoxedInterpolator<AudioSink>>(),
+            tween::component_tween_system::<
+                BoxedInterpolator<SpatialAudioSink>,
+            >(),
+            tween::component_tween_system::<
+                BoxedInterpolator<SpatialListener>,
+            >(),
+        ));
+    }
+}
+
+/// `start` before `value` reaches `threshold` and `end` from then on, for
+/// interpolators changing at an instant instead of interpolating.
+fn step<T>(start: T, end: T, threshold: f32, value: f32) -> T {
+    if value < threshold {
+        start
+    } else {
+        end
     }
 }
@@ -0,0 +1,137 @@
+use crate::interpolate::Interpolator;
+use bevy::prelude::*;
+
+/// [`Interpolator`] for [`AudioSink`]'s volume
+///
+/// See [`AudioSinkPlayback::set_volume`] for how volume is applied.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct AudioSinkVolume {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for AudioSinkVolume {
+    type Item = AudioSink;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_volume(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`AudioSinkVolume`]
+pub fn audio_sink_volume(start: f32, end: f32) -> AudioSinkVolume {
+    AudioSinkVolume { start, end }
+}
+
+/// Constructor for [`AudioSinkVolume`] that's relative to previous value using currying.
+pub fn audio_sink_volume_to(to: f32) -> impl Fn(&mut f32) -> AudioSinkVolume {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        audio_sink_volume(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`SpatialAudioSink`]'s volume
+///
+/// The emitter's position follows its [`Transform`] so use
+/// [`Translation`](super::Translation) on the emitter entity to move the sound
+/// around. This interpolator only changes the volume.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct SpatialAudioSinkVolume {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for SpatialAudioSinkVolume {
+    type Item = SpatialAudioSink;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_volume(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`SpatialAudioSinkVolume`]
+pub fn spatial_audio_sink_volume(
+    start: f32,
+    end: f32,
+) -> SpatialAudioSinkVolume {
+    SpatialAudioSinkVolume { start, end }
+}
+
+/// Constructor for [`SpatialAudioSinkVolume`] that's relative to previous value using currying.
+pub fn spatial_audio_sink_volume_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> SpatialAudioSinkVolume {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        spatial_audio_sink_volume(start, end)
+    }
+}
+
+/// [`Interpolator`] for the distance between [`SpatialListener`]'s ears.
+///
+/// Ears are placed on the X axis like [`SpatialListener::new`]. A wider gap
+/// exaggerates the stereo separation of spatial sounds.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::spatial_listener_gap;
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = spatial_listener_gap(2., 6.);
+/// let mut listener = SpatialListener::default();
+///
+/// interpolator.interpolate(&mut listener, 0.);
+/// assert_eq!(listener.left_ear_offset, Vec3::new(-1., 0., 0.));
+/// assert_eq!(listener.right_ear_offset, Vec3::new(1., 0., 0.));
+///
+/// interpolator.interpolate(&mut listener, 0.5);
+/// assert_eq!(listener.left_ear_offset, Vec3::new(-2., 0., 0.));
+/// assert_eq!(listener.right_ear_offset, Vec3::new(2., 0., 0.));
+///
+/// interpolator.interpolate(&mut listener, 1.);
+/// assert_eq!(listener.left_ear_offset, Vec3::new(-3., 0., 0.));
+/// assert_eq!(listener.right_ear_offset, Vec3::new(3., 0., 0.));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct SpatialListenerGap {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for SpatialListenerGap {
+    type Item = SpatialListener;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        *item = SpatialListener::new(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`SpatialListenerGap`]
+pub fn spatial_listener_gap(start: f32, end: f32) -> SpatialListenerGap {
+    SpatialListenerGap { start, end }
+}
+
+/// Constructor for [`SpatialListenerGap`] that's relative to previous value using currying.
+pub fn spatial_listener_gap_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> SpatialListenerGap {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        spatial_listener_gap(start, end)
+    }
+}
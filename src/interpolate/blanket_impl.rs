@@ -34,6 +34,21 @@ where
     }
 }
 
+/// Interpolate the inner interpolator if `Some`, otherwise do nothing and
+/// leave the item untouched.
+impl<I> Interpolator for Option<I>
+where
+    I: Interpolator,
+{
+    type Item = I::Item;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Some(interpolator) = self {
+            interpolator.interpolate(item, value)
+        }
+    }
+}
+
 impl<I: 'static> Interpolator for dyn Fn(&mut I, f32) + Send + Sync + 'static {
     type Item = I;
 
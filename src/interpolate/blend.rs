@@ -0,0 +1,349 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::interpolate::{Interpolator, Rotation, Scale, Translation};
+use bevy::prelude::*;
+
+/// [`Interpolator`] whose values can be blended with another interpolator of
+/// the same type, see [`Blend`].
+pub trait BlendInterpolator: Interpolator {
+    /// Value sampled from the interpolator
+    type Value: Debug + Clone + PartialEq + Send + Sync + 'static;
+
+    /// Sample the value without applying it to an item
+    fn sample_value(&self, value: f32) -> Self::Value;
+
+    /// Combine two values where `weight` of `0.` is `from` and `1.` is `to`
+    fn blend_values(
+        from: &Self::Value,
+        to: &Self::Value,
+        weight: f32,
+    ) -> Self::Value;
+
+    /// Apply a value to the item
+    fn apply_value(value: &Self::Value, item: &mut Self::Item);
+}
+
+impl BlendInterpolator for Translation {
+    type Value = Vec3;
+
+    fn sample_value(&self, value: f32) -> Self::Value {
+        self.start.lerp(self.end, value)
+    }
+
+    fn blend_values(
+        from: &Self::Value,
+        to: &Self::Value,
+        weight: f32,
+    ) -> Self::Value {
+        from.lerp(*to, weight)
+    }
+
+    fn apply_value(value: &Self::Value, item: &mut Self::Item) {
+        item.translation = *value;
+    }
+}
+
+impl BlendInterpolator for Scale {
+    type Value = Vec3;
+
+    fn sample_value(&self, value: f32) -> Self::Value {
+        self.start.lerp(self.end, value)
+    }
+
+    fn blend_values(
+        from: &Self::Value,
+        to: &Self::Value,
+        weight: f32,
+    ) -> Self::Value {
+        from.lerp(*to, weight)
+    }
+
+    fn apply_value(value: &Self::Value, item: &mut Self::Item) {
+        item.scale = *value;
+    }
+}
+
+impl BlendInterpolator for Rotation {
+    type Value = Quat;
+
+    fn sample_value(&self, value: f32) -> Self::Value {
+        self.start.slerp(self.end, value)
+    }
+
+    fn blend_values(
+        from: &Self::Value,
+        to: &Self::Value,
+        weight: f32,
+    ) -> Self::Value {
+        from.slerp(*to, weight)
+    }
+
+    fn apply_value(value: &Self::Value, item: &mut Self::Item) {
+        item.rotation = *value;
+    }
+}
+
+/// Which input of [`TweenBlend`] a [`Blend`] writes to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum BlendInput {
+    /// Input applied with the weight of `0.`
+    #[default]
+    From,
+    /// Input applied with the weight of `1.`
+    To,
+}
+
+/// [`Interpolator`] writing a [`BlendInterpolator`]'s value to an input of
+/// the target's [`TweenBlend`] instead of the item, for example, to crossfade
+/// from an idle tween to a run tween rather than snapping.
+///
+/// Tweens of both inputs can be in different animations. [`tween_blend_system`]
+/// applies the combination of both inputs weighted by [`TweenBlend::weight`]
+/// after every tween was applied, in
+/// [`TweenSystemSet::ApplyTween`](crate::TweenSystemSet::ApplyTween). The
+/// weight can be tweened with [`BlendWeight`].
+///
+/// An input keeps the last value written to it, so an input whose tween has
+/// completed stays at its final value while the other input keeps changing,
+/// for example, when blending a repeating animation with a one-shot one. If
+/// only one input was written, it's applied as is.
+///
+/// The target has to have [`TweenBlend<I>`]. Custom [`BlendInterpolator`]s
+/// have to register [`component_tween_system`](crate::tween::component_tween_system)
+/// for `Blend<I>` and `BlendWeight<I>`, and [`tween_blend_system`] for `I`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{parallel, tween};
+/// use bevy_tween::interpolate::{
+///     blend_from, blend_to, blend_weight, translation, Translation, TweenBlend,
+/// };
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let entity = app
+///     .world_mut()
+///     .spawn((Transform::default(), TweenBlend::<Translation>::default()))
+///     .id();
+/// let target = entity.into_target();
+///
+/// // Repeating idle
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .repeat(Repeat::Infinitely)
+///     .insert(tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         target.with(blend_from(translation(Vec3::ZERO, Vec3::Y))),
+///     ));
+/// // One-shot run crossfading in
+/// app.world_mut().commands().animation().insert(parallel((
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         target.with(blend_to(translation(
+///             Vec3::new(2., 0., 0.),
+///             Vec3::new(2., 1., 0.),
+///         ))),
+///     ),
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         target.with(blend_weight::<Translation>(0., 1.)),
+///     ),
+/// )));
+/// app.world_mut().flush();
+///
+/// let mut blended_midpoint = false;
+/// for _ in 0..20 {
+///     app.update();
+///     let translation = app.world().get::<Transform>(entity).unwrap().translation;
+///     let blend = app.world().get::<TweenBlend<Translation>>(entity).unwrap();
+///     if blend.weight == 0.5 {
+///         assert!(translation.abs_diff_eq(Vec3::new(1., 0.5, 0.), 1e-5));
+///         blended_midpoint = true;
+///     }
+/// }
+/// assert!(blended_midpoint);
+///
+/// // The run completed and is fully blended in while idle keeps repeating
+/// let translation = app.world().get::<Transform>(entity).unwrap().translation;
+/// assert!(translation.abs_diff_eq(Vec3::new(2., 1., 0.), 1e-5));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct Blend<I>
+where
+    I: BlendInterpolator,
+{
+    /// Interpolator sampling the input's value
+    pub interpolator: I,
+    /// Input to write to
+    pub input: BlendInput,
+}
+
+impl<I> Interpolator for Blend<I>
+where
+    I: BlendInterpolator,
+{
+    type Item = TweenBlend<I>;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let value = self.interpolator.sample_value(value);
+        match self.input {
+            BlendInput::From => item.from = Some(value),
+            BlendInput::To => item.to = Some(value),
+        }
+    }
+}
+
+/// Constructor for [`Blend`] writing to [`BlendInput::From`]
+pub fn blend_from<I>(interpolator: I) -> Blend<I>
+where
+    I: BlendInterpolator,
+{
+    Blend {
+        interpolator,
+        input: BlendInput::From,
+    }
+}
+
+/// Constructor for [`Blend`] writing to [`BlendInput::To`]
+pub fn blend_to<I>(interpolator: I) -> Blend<I>
+where
+    I: BlendInterpolator,
+{
+    Blend {
+        interpolator,
+        input: BlendInput::To,
+    }
+}
+
+/// [`Interpolator`] for [`TweenBlend::weight`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct BlendWeight<I>
+where
+    I: BlendInterpolator,
+{
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+    #[reflect(ignore)]
+    marker: PhantomData<I>,
+}
+
+impl<I> Interpolator for BlendWeight<I>
+where
+    I: BlendInterpolator,
+{
+    type Item = TweenBlend<I>;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.weight = self.start + (self.end - self.start) * value;
+    }
+}
+
+/// Constructor for [`BlendWeight`]
+pub fn blend_weight<I>(start: f32, end: f32) -> BlendWeight<I>
+where
+    I: BlendInterpolator,
+{
+    BlendWeight {
+        start,
+        end,
+        marker: PhantomData,
+    }
+}
+
+/// Constructor for [`BlendWeight`] that's relative to previous value using currying.
+pub fn blend_weight_to<I>(to: f32) -> impl Fn(&mut f32) -> BlendWeight<I>
+where
+    I: BlendInterpolator,
+{
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        blend_weight(start, end)
+    }
+}
+
+/// Inputs and weight of the [`Blend`] tweens of `I` targeting an entity.
+/// See [`Blend`] for how they're applied.
+#[derive(Debug, Component, Reflect)]
+pub struct TweenBlend<I>
+where
+    I: BlendInterpolator,
+{
+    /// Weight of [`BlendInput::To`] clamped to 0–1, `0.` applies only
+    /// [`BlendInput::From`]
+    pub weight: f32,
+    #[reflect(ignore)]
+    from: Option<I::Value>,
+    #[reflect(ignore)]
+    to: Option<I::Value>,
+}
+
+impl<I> Default for TweenBlend<I>
+where
+    I: BlendInterpolator,
+{
+    fn default() -> Self {
+        TweenBlend::new(0.)
+    }
+}
+
+impl<I> TweenBlend<I>
+where
+    I: BlendInterpolator,
+{
+    /// Create a new [`TweenBlend`] without inputs
+    pub fn new(weight: f32) -> TweenBlend<I> {
+        TweenBlend {
+            weight,
+            from: None,
+            to: None,
+        }
+    }
+
+    /// Last value written to the input or `None` if not written yet
+    pub fn input(&self, input: BlendInput) -> Option<&I::Value> {
+        match input {
+            BlendInput::From => self.from.as_ref(),
+            BlendInput::To => self.to.as_ref(),
+        }
+    }
+
+    /// Forget the values written to both inputs
+    pub fn clear_inputs(&mut self) {
+        self.from = None;
+        self.to = None;
+    }
+
+    /// Weighted combination of the inputs or `None` if no input was written
+    pub fn blended(&self) -> Option<I::Value> {
+        match (&self.from, &self.to) {
+            (Some(from), Some(to)) => {
+                Some(I::blend_values(from, to, self.weight.clamp(0., 1.)))
+            }
+            (Some(value), None) | (None, Some(value)) => Some(value.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Apply the blended value of changed [`TweenBlend`]s to the item.
+pub fn tween_blend_system<I>(
+    mut q_blend: Query<(&TweenBlend<I>, &mut I::Item), Changed<TweenBlend<I>>>,
+) where
+    I: BlendInterpolator,
+    I::Item: Component,
+{
+    for (blend, mut item) in &mut q_blend {
+        if let Some(value) = blend.blended() {
+            I::apply_value(&value, &mut item);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::interpolate::Interpolator;
+use crate::interpolation::curve_parameter;
+use bevy::prelude::*;
+
+/// [`Interpolator`] for [`Transform`]'s translation sampled from any Bevy
+/// [`Curve<Vec3>`](Curve).
+///
+/// The tween's value is mapped to the curve's domain if it's bounded and
+/// samples outside of the domain are clamped. See
+/// [`EaseCurve`](crate::interpolation::EaseCurve) to use a curve as the
+/// easing function instead.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+/// use bevy_tween::interpolate::{translation_curve, TranslationCurve};
+/// use bevy_tween::interpolation::{sample_interpolations_system, EaseCurve};
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::apply_component_tween_system;
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(
+///     (
+///         sample_interpolations_system::<EaseCurve>,
+///         apply_component_tween_system::<TranslationCurve>,
+///     )
+///         .chain(),
+/// );
+///
+/// let target = world.spawn(Transform::default()).id();
+/// let path = EasingCurve::new(Vec3::ZERO, Vec3::new(4., 0., 0.), EaseFunction::Linear);
+/// world.spawn((
+///     TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+///     EaseCurve::new(EasingCurve::new(0., 1., EaseFunction::QuadraticIn)),
+///     target.into_target().with(translation_curve(path)),
+///     TimeSpanProgress {
+///         now_percentage: 0.5,
+///         now: 0.5,
+///         previous_percentage: 0.,
+///         previous: 0.,
+///     },
+/// ));
+/// schedule.run(&mut world);
+///
+/// let transform = world.get::<Transform>(target).unwrap();
+/// assert_eq!(transform.translation, Vec3::new(1., 0., 0.));
+/// ```
+pub struct TranslationCurve(pub Box<dyn Curve<Vec3> + Send + Sync + 'static>);
+
+impl Interpolator for TranslationCurve {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.translation =
+            self.0.sample_clamped(curve_parameter(&*self.0, value));
+    }
+}
+
+/// Constructor for [`TranslationCurve`]
+pub fn translation_curve<C>(curve: C) -> TranslationCurve
+where
+    C: Curve<Vec3> + Send + Sync + 'static,
+{
+    TranslationCurve(Box::new(curve))
+}
+
+/// [`Interpolator`] for [`Transform`]'s rotation sampled from any Bevy
+/// [`Curve<Quat>`](Curve).
+///
+/// See [`TranslationCurve`] for how the curve is sampled.
+pub struct RotationCurve(pub Box<dyn Curve<Quat> + Send + Sync + 'static>);
+
+impl Interpolator for RotationCurve {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.rotation = self.0.sample_clamped(curve_parameter(&*self.0, value));
+    }
+}
+
+/// Constructor for [`RotationCurve`]
+pub fn rotation_curve<C>(curve: C) -> RotationCurve
+where
+    C: Curve<Quat> + Send + Sync + 'static,
+{
+    RotationCurve(Box::new(curve))
+}
+
+/// [`Interpolator`] for [`Transform`]'s scale sampled from any Bevy
+/// [`Curve<Vec3>`](Curve).
+///
+/// See [`TranslationCurve`] for how the curve is sampled.
+pub struct ScaleCurve(pub Box<dyn Curve<Vec3> + Send + Sync + 'static>);
+
+impl Interpolator for ScaleCurve {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.scale = self.0.sample_clamped(curve_parameter(&*self.0, value));
+    }
+}
+
+/// Constructor for [`ScaleCurve`]
+pub fn scale_curve<C>(curve: C) -> ScaleCurve
+where
+    C: Curve<Vec3> + Send + Sync + 'static,
+{
+    ScaleCurve(Box::new(curve))
+}
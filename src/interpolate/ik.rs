@@ -0,0 +1,146 @@
+use crate::interpolate::Interpolator;
+use bevy::prelude::*;
+
+/// Bone of a two-bone chain rotated by [`TwoBoneIk`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum TwoBoneIkBone {
+    /// The bone attached to the origin, for example, the upper arm
+    #[default]
+    Upper,
+    /// The bone attached to the end of the upper bone, for example, the
+    /// forearm
+    Lower,
+}
+
+/// [`Interpolator`] for [`Transform`]'s rotation at Z axis of a 2D two-bone
+/// chain reaching toward an eased target position using inverse kinematics.
+///
+/// The target moves linearly from `start` to `end` by the tweened value and
+/// the chain is solved for it every time this interpolator is applied. One
+/// tween rotates one bone so a chain needs two tweens sharing the same
+/// settings, one for each [`TwoBoneIkBone`], usually in
+/// [`parallel`](crate::combinator::parallel).
+///
+/// # Bone setup
+///
+/// - The upper bone's entity is positioned at `origin` and its local X axis
+///   points along the bone.
+/// - The lower bone's entity is a child of the upper bone positioned at
+///   `(upper_length, 0.)` and its local X axis points along the bone.
+/// - The end effector is at `(lower_length, 0.)` in the lower bone's space.
+///
+/// `origin`, `start`, and `end` are in the space of the upper bone's parent.
+/// Targets out of reach stretch the chain toward them and targets too close to
+/// the origin fold the chain as much as possible. `bend_clockwise` chooses
+/// which side the joint bends to.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{two_bone_ik, TwoBoneIkBone};
+/// use bevy_tween::prelude::*;
+///
+/// let target = Vec2::new(1.2, 0.8);
+/// let upper_ik = two_bone_ik(Vec2::ZERO, 1., 1., Vec2::new(2., 0.), target);
+/// let lower_ik = upper_ik.clone().with_bone(TwoBoneIkBone::Lower);
+///
+/// let mut upper = Transform::default();
+/// let mut lower = Transform::from_xyz(1., 0., 0.);
+/// upper_ik.interpolate(&mut upper, 1.);
+/// lower_ik.interpolate(&mut lower, 1.);
+///
+/// let effector = upper * lower * Vec3::new(1., 0., 0.);
+/// assert!(effector.truncate().abs_diff_eq(target, 1e-5));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TwoBoneIk {
+    /// Position of the upper bone
+    pub origin: Vec2,
+    /// Length of the upper bone
+    pub upper_length: f32,
+    /// Length of the lower bone
+    pub lower_length: f32,
+    #[allow(missing_docs)]
+    pub start: Vec2,
+    #[allow(missing_docs)]
+    pub end: Vec2,
+    /// Bend the joint clockwise instead of counterclockwise
+    pub bend_clockwise: bool,
+    /// Bone rotated by this interpolator
+    pub bone: TwoBoneIkBone,
+}
+
+impl TwoBoneIk {
+    /// Rotate the provided bone instead
+    pub fn with_bone(mut self, bone: TwoBoneIkBone) -> Self {
+        self.bone = bone;
+        self
+    }
+
+    /// Bend the joint clockwise instead of counterclockwise
+    pub fn with_bend_clockwise(mut self, bend_clockwise: bool) -> Self {
+        self.bend_clockwise = bend_clockwise;
+        self
+    }
+
+    /// Solve the chain for `target` returning the upper bone's angle and the
+    /// lower bone's angle relative to the upper bone.
+    pub fn solve(&self, target: Vec2) -> (f32, f32) {
+        let l1 = self.upper_length;
+        let l2 = self.lower_length;
+        let to_target = target - self.origin;
+        let min_reach = (l1 - l2).abs();
+        let max_reach = l1 + l2;
+        let distance = to_target.length().clamp(min_reach, max_reach);
+        let direction = to_target.y.atan2(to_target.x);
+        if l1 <= 0. || l2 <= 0. || distance <= 0. {
+            return (direction, 0.);
+        }
+        let shoulder = ((l1 * l1 + distance * distance - l2 * l2)
+            / (2. * l1 * distance))
+            .clamp(-1., 1.)
+            .acos();
+        let elbow = ((l1 * l1 + l2 * l2 - distance * distance)
+            / (2. * l1 * l2))
+            .clamp(-1., 1.)
+            .acos();
+        let sign = if self.bend_clockwise { -1. } else { 1. };
+        (
+            direction - sign * shoulder,
+            sign * (std::f32::consts::PI - elbow),
+        )
+    }
+}
+
+impl Interpolator for TwoBoneIk {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let (upper, lower) = self.solve(self.start.lerp(self.end, value));
+        let angle = match self.bone {
+            TwoBoneIkBone::Upper => upper,
+            TwoBoneIkBone::Lower => lower,
+        };
+        item.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+/// Constructor for [`TwoBoneIk`] rotating the upper bone
+pub fn two_bone_ik(
+    origin: Vec2,
+    upper_length: f32,
+    lower_length: f32,
+    start: Vec2,
+    end: Vec2,
+) -> TwoBoneIk {
+    TwoBoneIk {
+        origin,
+        upper_length,
+        lower_length,
+        start,
+        end,
+        bend_clockwise: false,
+        bone: TwoBoneIkBone::Upper,
+    }
+}
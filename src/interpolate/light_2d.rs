@@ -0,0 +1,205 @@
+use std::marker::PhantomData;
+
+use crate::interpolate::Interpolator;
+use bevy::prelude::*;
+
+/// Adapter for 2D light components of third-party crates, since Bevy doesn't
+/// have 2D lighting.
+///
+/// Implement this for a light component to tween it with
+/// [`Light2dIntensity`] and [`Light2dColor`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::Light2d;
+///
+/// // A 2D light component from another crate
+/// #[derive(Component, Reflect)]
+/// struct PointLight2d {
+///     intensity: f32,
+///     color: Color,
+/// }
+///
+/// impl Light2d for PointLight2d {
+///     fn intensity_mut(&mut self) -> &mut f32 {
+///         &mut self.intensity
+///     }
+///
+///     fn color_mut(&mut self) -> &mut Color {
+///         &mut self.color
+///     }
+/// }
+/// ```
+pub trait Light2d: Component {
+    /// Intensity of the light
+    fn intensity_mut(&mut self) -> &mut f32;
+
+    /// Color of the light
+    fn color_mut(&mut self) -> &mut Color;
+}
+
+/// [`Interpolator`] for the intensity of a [`Light2d`].
+///
+/// Since this interpolator is generic, you have to
+/// [register the system](crate::tween#registering-systems) for each light
+/// component you use.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{
+///     light_2d_color, light_2d_intensity, Light2d, Light2dColor,
+///     Light2dIntensity,
+/// };
+/// use bevy_tween::prelude::*;
+///
+/// #[derive(Component, Reflect)]
+/// struct PointLight2d {
+///     intensity: f32,
+///     color: Color,
+/// }
+///
+/// impl Light2d for PointLight2d {
+///     fn intensity_mut(&mut self) -> &mut f32 {
+///         &mut self.intensity
+///     }
+///
+///     fn color_mut(&mut self) -> &mut Color {
+///         &mut self.color
+///     }
+/// }
+///
+/// let mut light = PointLight2d {
+///     intensity: 0.,
+///     color: Color::BLACK,
+/// };
+/// let start = Color::srgb(1., 0.5, 0.);
+/// let end = Color::srgb(0., 0.5, 1.);
+/// light_2d_intensity::<PointLight2d>(0., 4.).interpolate(&mut light, 0.25);
+/// light_2d_color::<PointLight2d>(start, end).interpolate(&mut light, 0.5);
+/// assert_eq!(light.intensity, 1.);
+/// assert_eq!(light.color, start.mix(&end, 0.5));
+///
+/// // Register the systems for this light component
+/// fn point_light_2d_plugin(app: &mut App) {
+///     app.add_tween_systems((
+///         bevy_tween::component_tween_system::<Light2dIntensity<PointLight2d>>(),
+///         bevy_tween::component_tween_system::<Light2dColor<PointLight2d>>(),
+///     ));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct Light2dIntensity<L> {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> L>,
+}
+
+impl<L> Light2dIntensity<L> {
+    /// Create a new [`Light2dIntensity`]
+    pub fn new(start: f32, end: f32) -> Self {
+        Light2dIntensity {
+            start,
+            end,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<L> Interpolator for Light2dIntensity<L>
+where
+    L: Light2d,
+{
+    type Item = L;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        *item.intensity_mut() = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`Light2dIntensity`]
+pub fn light_2d_intensity<L>(start: f32, end: f32) -> Light2dIntensity<L>
+where
+    L: Light2d,
+{
+    Light2dIntensity::new(start, end)
+}
+
+/// Constructor for [`Light2dIntensity`] that's relative to previous value using currying.
+pub fn light_2d_intensity_to<L>(
+    to: f32,
+) -> impl Fn(&mut f32) -> Light2dIntensity<L>
+where
+    L: Light2d,
+{
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        light_2d_intensity(start, end)
+    }
+}
+
+/// [`Interpolator`] for the color of a [`Light2d`].
+///
+/// Since this interpolator is generic, you have to
+/// [register the system](crate::tween#registering-systems) for each light
+/// component you use. See [`Light2dIntensity`] for an example.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct Light2dColor<L> {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> L>,
+}
+
+impl<L> Light2dColor<L> {
+    /// Create a new [`Light2dColor`]
+    pub fn new(start: Color, end: Color) -> Self {
+        Light2dColor {
+            start,
+            end,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<L> Interpolator for Light2dColor<L>
+where
+    L: Light2d,
+{
+    type Item = L;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        *item.color_mut() = self.start.mix(&self.end, value);
+    }
+}
+
+/// Constructor for [`Light2dColor`]
+pub fn light_2d_color<L>(start: Color, end: Color) -> Light2dColor<L>
+where
+    L: Light2d,
+{
+    Light2dColor::new(start, end)
+}
+
+/// Constructor for [`Light2dColor`] that's relative to previous value using currying.
+pub fn light_2d_color_to<L>(to: Color) -> impl Fn(&mut Color) -> Light2dColor<L>
+where
+    L: Light2d,
+{
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        light_2d_color(start, end)
+    }
+}
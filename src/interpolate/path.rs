@@ -0,0 +1,306 @@
+use crate::interpolate::Interpolator;
+use bevy::math::cubic_splines::{
+    CubicCardinalSpline, CubicCurve, CubicGenerator,
+};
+use bevy::math::VectorSpace;
+use bevy::prelude::*;
+
+/// Step in the curve's parameter used to estimate the tangent and curvature
+/// by finite differences.
+const FINITE_DIFFERENCE_STEP: f32 = 1e-3;
+
+/// [`Interpolator`] for [`Transform`]'s translation along a [`CubicCurve`].
+///
+/// The whole curve is traversed from `0.` to `1.` of the interpolation
+/// regardless of how many segments it has.
+///
+/// With [`PathFacing`] set, rotation will also be set to face the path's
+/// tangent with an optional banking proportional to the curvature.
+///
+/// # Tangent estimation
+///
+/// The tangent and curvature are estimated by finite differences: the curve
+/// is sampled slightly behind and slightly ahead of the current position
+/// (clamped to the curve's ends), the tangent is the direction between the two
+/// samples and the curvature is the signed turning angle around
+/// [`PathFacing::up`] divided by the distance travelled between them.
+/// When the samples coincide, for example on a degenerate curve, rotation is
+/// left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::math::cubic_splines::{CubicCardinalSpline, CubicGenerator};
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{translation_path, PathFacing};
+/// use bevy_tween::prelude::*;
+///
+/// let curve = CubicCardinalSpline::new_catmull_rom([
+///     Vec3::ZERO,
+///     Vec3::new(5., 0., -5.),
+///     Vec3::new(10., 0., 0.),
+/// ])
+/// .to_curve()
+/// .unwrap();
+/// let interpolator =
+///     translation_path(curve.clone()).with_facing(PathFacing::new(Vec3::Y));
+///
+/// let mut transform = Transform::default();
+/// interpolator.interpolate(&mut transform, 0.25);
+///
+/// let t = 0.25 * curve.segments().len() as f32;
+/// assert!(transform.translation.distance(curve.position(t)) < 1e-5);
+/// let tangent = curve.velocity(t).normalize();
+/// assert!(transform.forward().dot(tangent) > 0.999);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct TranslationPath {
+    /// Path to follow
+    pub curve: CubicCurve<Vec3>,
+    /// Rotate to face the path's tangent if set
+    pub facing: Option<PathFacing>,
+}
+
+impl TranslationPath {
+    /// Create a new [`TranslationPath`] that only sets translation
+    pub fn new(curve: CubicCurve<Vec3>) -> Self {
+        TranslationPath {
+            curve,
+            facing: None,
+        }
+    }
+
+    /// Also rotate to face the path's tangent
+    pub fn with_facing(mut self, facing: PathFacing) -> Self {
+        self.facing = Some(facing);
+        self
+    }
+}
+
+impl Interpolator for TranslationPath {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let end = self.curve.segments().len() as f32;
+        let t = value * end;
+        item.translation = self.curve.position(t);
+
+        let Some(facing) = &self.facing else {
+            return;
+        };
+        let behind = self.curve.position((t - FINITE_DIFFERENCE_STEP).max(0.));
+        let ahead = self.curve.position((t + FINITE_DIFFERENCE_STEP).min(end));
+        let Ok(tangent) = Dir3::new(ahead - behind) else {
+            return;
+        };
+        item.look_to(tangent, facing.up);
+
+        if facing.banking == 0. {
+            return;
+        }
+        let to_current = item.translation - behind;
+        let to_ahead = ahead - item.translation;
+        let distance = to_current.length() + to_ahead.length();
+        let (Some(before), Some(after)) =
+            (to_current.try_normalize(), to_ahead.try_normalize())
+        else {
+            return;
+        };
+        let turn = before.cross(after).dot(*facing.up).clamp(-1., 1.).asin();
+        let curvature = turn / (distance * 0.5);
+        let bank = (facing.banking * curvature)
+            .clamp(-std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2);
+        item.rotate_local_z(bank);
+    }
+}
+
+/// Rotation settings for [`TranslationPath`]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct PathFacing {
+    /// Up direction used to orient toward the tangent and to measure curvature
+    pub up: Dir3,
+    /// Roll in radians per unit of curvature (`1 / radius`) around the
+    /// tangent. Positive values lean into the turn. The roll is clamped to
+    /// a quarter turn.
+    pub banking: f32,
+}
+
+impl PathFacing {
+    /// Face the tangent without banking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `up` is zero, infinite, or NaN.
+    pub fn new(up: Vec3) -> Self {
+        PathFacing {
+            up: Dir3::new(up).expect("up should be a valid direction"),
+            banking: 0.,
+        }
+    }
+
+    /// Set [`Self::banking`]
+    pub fn with_banking(mut self, banking: f32) -> Self {
+        self.banking = banking;
+        self
+    }
+}
+
+impl Default for PathFacing {
+    fn default() -> Self {
+        PathFacing {
+            up: Dir3::Y,
+            banking: 0.,
+        }
+    }
+}
+
+/// Constructor for [`TranslationPath`]
+pub fn translation_path(curve: CubicCurve<Vec3>) -> TranslationPath {
+    TranslationPath::new(curve)
+}
+
+/// [`Interpolator`] for [`Transform`]'s `x` and `y` translation along a 2D
+/// [`CubicCurve`], keeping `z` for layering.
+///
+/// The whole curve is traversed from `0.` to `1.` of the interpolation
+/// regardless of how many segments it has, like [`TranslationPath`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::translation_catmull_rom_2d;
+/// use bevy_tween::prelude::*;
+///
+/// let path = translation_catmull_rom_2d(0.5, [Vec2::ZERO, Vec2::new(4., 2.)])
+///     .unwrap();
+///
+/// let mut transform = Transform::from_xyz(0., 0., 5.);
+/// path.interpolate(&mut transform, 0.5);
+/// assert!(transform.translation.distance(Vec3::new(2., 1., 5.)) < 1e-5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct TranslationPath2d {
+    /// Path to follow
+    pub curve: CubicCurve<Vec2>,
+}
+
+impl TranslationPath2d {
+    /// Create a new [`TranslationPath2d`]
+    pub fn new(curve: CubicCurve<Vec2>) -> Self {
+        TranslationPath2d { curve }
+    }
+}
+
+impl Interpolator for TranslationPath2d {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let t = value * self.curve.segments().len() as f32;
+        let position = self.curve.position(t);
+        item.translation.x = position.x;
+        item.translation.y = position.y;
+    }
+}
+
+/// Create a Catmull-Rom spline through every point in `points` for any
+/// [`VectorSpace`] such as [`Vec2`], [`Vec3`], or [`LinearRgba`].
+/// Returns `None` if `points` is empty.
+///
+/// `tension` is the tension of a [`CubicCardinalSpline`] where `0.5` is a
+/// Catmull-Rom spline, lower values are looser and higher values are tighter
+/// around the points. The ends are clamped: the curve starts at the first
+/// point and ends at the last point by mirroring their neighbours.
+///
+/// Degenerate curves are supported:
+/// - Two points are a straight line between them, which is the same as
+///   [`VectorSpace::lerp`] with a tension of `0.5`.
+/// - A single point stays at that point.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::catmull_rom_curve;
+///
+/// let curve = catmull_rom_curve(0.5, [Vec2::ZERO, Vec2::X]).unwrap();
+/// assert!(curve.position(0.3).distance(Vec2::new(0.3, 0.)) < 1e-5);
+/// assert!(catmull_rom_curve::<Vec2>(0.5, []).is_none());
+/// ```
+pub fn catmull_rom_curve<V: VectorSpace>(
+    tension: f32,
+    points: impl IntoIterator<Item = V>,
+) -> Option<CubicCurve<V>> {
+    let mut points: Vec<V> = points.into_iter().collect();
+    let first = *points.first()?;
+    if points.len() == 1 {
+        points.push(first);
+    }
+    CubicCardinalSpline::new(tension, points).to_curve().ok()
+}
+
+/// Constructor for [`TranslationPath`] following a Catmull-Rom spline
+/// through every point in `points`, for example, waypoints.
+/// Returns `None` if `points` is empty.
+///
+/// See [`catmull_rom_curve`] for `tension` and how the ends and degenerate
+/// paths are handled. Two points move the same as
+/// [`Translation`](super::Translation) with a tension of `0.5` and a single
+/// point stays at that point.
+///
+/// Use [`translation_catmull_rom_2d`] for 2D points.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{translation, translation_catmull_rom};
+/// use bevy_tween::prelude::*;
+///
+/// let sample = |interpolator: &dyn Interpolator<Item = Transform>, value| {
+///     let mut transform = Transform::default();
+///     interpolator.interpolate(&mut transform, value);
+///     transform.translation
+/// };
+///
+/// let path = translation_catmull_rom(
+///     0.5,
+///     [
+///         Vec3::new(0., 0., 0.),
+///         Vec3::new(1., 1., 0.),
+///         Vec3::new(2., 1., 0.),
+///         Vec3::new(3., 0., 0.),
+///     ],
+/// )
+/// .unwrap();
+/// assert!(sample(&path, 0.).distance(Vec3::ZERO) < 1e-5);
+/// assert!(sample(&path, 0.5).distance(Vec3::new(1.5, 1.125, 0.)) < 1e-5);
+/// assert!(sample(&path, 1.).distance(Vec3::new(3., 0., 0.)) < 1e-5);
+///
+/// let line = translation_catmull_rom(0.5, [Vec3::ZERO, Vec3::X]).unwrap();
+/// let constant = translation_catmull_rom(0.5, [Vec3::Y]).unwrap();
+/// for value in [0., 0.3, 0.5, 1.] {
+///     let expected = sample(&translation(Vec3::ZERO, Vec3::X), value);
+///     assert!(sample(&line, value).distance(expected) < 1e-5);
+///     assert!(sample(&constant, value).distance(Vec3::Y) < 1e-5);
+///
+/// assert!(translation_catmull_rom(0.5, []).is_none());
+/// ```
+pub fn translation_catmull_rom(
+    tension: f32,
+    points: impl IntoIterator<Item = Vec3>,
+) -> Option<TranslationPath> {
+    catmull_rom_curve(tension, points).map(TranslationPath::new)
+}
+
+/// Constructor for [`TranslationPath2d`] following a Catmull-Rom spline
+/// through every point in `points`. Returns `None` if `points` is empty.
+///
+/// See [`catmull_rom_curve`] for `tension` and how the ends and degenerate
+/// paths are handled.
+pub fn translation_catmull_rom_2d(
+    tension: f32,
+    points: impl IntoIterator<Item = Vec2>,
+) -> Option<TranslationPath2d> {
+    catmull_rom_curve(tension, points).map(TranslationPath2d::new)
+}
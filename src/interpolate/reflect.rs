@@ -0,0 +1,178 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::sync::{Mutex, OnceLock};
+
+use crate::interpolate::Interpolator;
+use bevy::math::VectorSpace;
+use bevy::prelude::*;
+use bevy::reflect::GetPath;
+use bevy::utils::HashSet;
+
+/// [`Interpolator`] for a field of any [`Reflect`] type accessed by a
+/// reflection path such as `"intensity"` or `"uniforms.color"`.
+///
+/// This is mainly used for tweening uniforms of custom
+/// [`Material2d`](bevy::sprite::Material2d) assets without writing a new
+/// interpolator for every field. The field type `V` must be a
+/// [`VectorSpace`] like [`f32`], [`Vec2`], [`Vec4`], or [`LinearRgba`].
+///
+/// The tweened type must derive [`Reflect`] and the field must be reachable
+/// by the path. If the path doesn't resolve or the field type doesn't match
+/// `V`, an error will be logged once for each tweened type and path and the
+/// item will be left untouched.
+///
+/// Since this interpolator is generic, you have to
+/// [register the system](crate::tween#registering-systems) for each material
+/// and field type you use.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{reflect_field, ReflectField};
+/// use bevy_tween::prelude::*;
+///
+/// #[derive(Reflect)]
+/// struct Glow {
+///     intensity: f32,
+/// }
+///
+/// let interpolator: ReflectField<Glow, f32> =
+///     reflect_field("intensity", 0., 2.);
+///
+/// let mut glow = Glow { intensity: 0. };
+/// interpolator.interpolate(&mut glow, 0.5);
+/// assert_eq!(glow.intensity, 1.);
+/// ```
+///
+/// Tweening a uniform of a material asset. A real material would also derive
+/// `AsBindGroup` and implement `Material2d`.
+///
+/// ```
+/// # #[cfg(feature = "bevy_asset")]
+/// # {
+/// use bevy_tween::combinator::tween;
+/// use bevy_tween::interpolate::{reflect_field, ReflectField};
+///
+/// #[derive(Asset, Reflect)]
+/// struct GlowMaterial {
+///     intensity: f32,
+/// }
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_plugins(AssetPlugin::default())
+///     .init_asset::<GlowMaterial>();
+/// // Register the system for this material and field type
+/// app.add_tween_systems(bevy_tween::asset_tween_system::<
+///     ReflectField<GlowMaterial, f32>,
+/// >());
+///
+/// let material = app
+///     .world_mut()
+///     .resource_mut::<Assets<GlowMaterial>>()
+///     .add(GlowMaterial { intensity: 0. });
+/// app.world_mut().commands().animation().insert(tween(
+///     Duration::from_secs(1),
+///     EaseKind::Linear,
+///     material
+///         .clone()
+///         .into_target()
+///         .with(reflect_field("intensity", 0., 2.)),
+/// ));
+/// app.world_mut().flush();
+/// for _ in 0..5 {
+///     app.update();
+/// }
+///
+/// let intensity = |app: &App| {
+///     let materials = app.world().resource::<Assets<GlowMaterial>>();
+///     materials.get(&material).unwrap().intensity
+/// };
+/// assert!(intensity(&app) > 0. && intensity(&app) < 2.);
+/// for _ in 0..10 {
+///     app.update();
+/// }
+/// assert_eq!(intensity(&app), 2.);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct ReflectField<T, V> {
+    /// Reflection path of the field.
+    pub path: String,
+    #[allow(missing_docs)]
+    pub start: V,
+    #[allow(missing_docs)]
+    pub end: V,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, V> ReflectField<T, V> {
+    /// Create a new [`ReflectField`]
+    pub fn new(path: impl Into<String>, start: V, end: V) -> Self {
+        ReflectField {
+            path: path.into(),
+            start,
+            end,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Interpolator for ReflectField<T, V>
+where
+    T: Reflect,
+    V: VectorSpace + Reflect + Send + Sync + 'static,
+{
+    type Item = T;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        match item.path_mut::<V>(self.path.as_str()) {
+            Ok(field) => {
+                *field = VectorSpace::lerp(self.start, self.end, value)
+            }
+            Err(e) => {
+                static REPORTED: OnceLock<Mutex<HashSet<(TypeId, String)>>> =
+                    OnceLock::new();
+                let newly_reported = REPORTED
+                    .get_or_init(Default::default)
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .insert((TypeId::of::<T>(), self.path.clone()));
+                if newly_reported {
+                    error!(
+                        "ReflectField cannot access `{}` of {}: {e}",
+                        self.path,
+                        std::any::type_name::<T>(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Constructor for [`ReflectField`]
+pub fn reflect_field<T, V>(
+    path: impl Into<String>,
+    start: V,
+    end: V,
+) -> ReflectField<T, V> {
+    ReflectField::new(path, start, end)
+}
+
+/// Constructor for [`ReflectField`] that's relative to previous value using currying.
+pub fn reflect_field_to<T, V>(
+    path: impl Into<String>,
+    to: V,
+) -> impl Fn(&mut V) -> ReflectField<T, V>
+where
+    V: Copy,
+{
+    let path = path.into();
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        reflect_field(path.clone(), start, end)
+    }
+}
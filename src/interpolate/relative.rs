@@ -0,0 +1,229 @@
+use std::fmt::Debug;
+use std::sync::OnceLock;
+
+use crate::interpolate::{AngleZ, Interpolator, Rotation, Scale, Translation};
+use crate::tween;
+use bevy::prelude::*;
+use bevy_time_runner::TimeRunnerEnded;
+
+/// [`Interpolator`] that can be applied as a change relative to a base value
+/// captured from the item, see [`Relative`].
+///
+/// The interpolator's own start and end only describe the change, for
+/// example, [`Translation`] moves the base by `end - start`.
+pub trait RelativeInterpolator: Interpolator {
+    /// Value captured from the item as the base
+    type Base: Debug + Clone + PartialEq + Send + Sync + 'static;
+
+    /// Capture the base from the item's current value
+    fn capture(&self, item: &Self::Item) -> Self::Base;
+
+    /// Interpolate the item starting from `base`
+    fn interpolate_relative(
+        &self,
+        base: &Self::Base,
+        item: &mut Self::Item,
+        value: f32,
+    );
+}
+
+impl RelativeInterpolator for Translation {
+    type Base = Vec3;
+
+    fn capture(&self, item: &Self::Item) -> Self::Base {
+        item.translation
+    }
+
+    fn interpolate_relative(
+        &self,
+        base: &Self::Base,
+        item: &mut Self::Item,
+        value: f32,
+    ) {
+        item.translation = *base + (self.end - self.start) * value;
+    }
+}
+
+impl RelativeInterpolator for Scale {
+    type Base = Vec3;
+
+    fn capture(&self, item: &Self::Item) -> Self::Base {
+        item.scale
+    }
+
+    fn interpolate_relative(
+        &self,
+        base: &Self::Base,
+        item: &mut Self::Item,
+        value: f32,
+    ) {
+        item.scale = *base + (self.end - self.start) * value;
+    }
+}
+
+impl RelativeInterpolator for Rotation {
+    type Base = Quat;
+
+    fn capture(&self, item: &Self::Item) -> Self::Base {
+        item.rotation
+    }
+
+    fn interpolate_relative(
+        &self,
+        base: &Self::Base,
+        item: &mut Self::Item,
+        value: f32,
+    ) {
+        let change = self.start.slerp(self.end, value) * self.start.inverse();
+        item.rotation = change * *base;
+    }
+}
+
+impl RelativeInterpolator for AngleZ {
+    type Base = Quat;
+
+    fn capture(&self, item: &Self::Item) -> Self::Base {
+        item.rotation
+    }
+
+    fn interpolate_relative(
+        &self,
+        base: &Self::Base,
+        item: &mut Self::Item,
+        value: f32,
+    ) {
+        let change = Quat::from_rotation_z((self.end - self.start) * value);
+        item.rotation = change * *base;
+    }
+}
+
+/// [`Interpolator`] applying a [`RelativeInterpolator`]'s change on top of a
+/// base captured from the item at playback time, for example, to move an
+/// entity by an offset from wherever it is when the animation starts rather
+/// than from where it was when the animation was built.
+///
+/// The base is captured the first time this interpolator is applied, which is
+/// the first run of [`TweenSystemSet::ApplyTween`](crate::TweenSystemSet::ApplyTween)
+/// where the tween is active. Targeting multiple entities is not supported
+/// since only one base is captured.
+///
+/// # Repeat
+///
+/// By default, the base is captured again every time the animation repeats
+/// by [`relative_repeat_system`] so every repeat continues from where the
+/// previous one left off. For [`RepeatStyle::PingPong`], the second play goes
+/// backward and would move the base further instead of returning to it.
+/// Disable [`Self::resample_on_repeat`] to keep the first captured base
+/// instead.
+///
+/// Custom [`RelativeInterpolator`]s have to register
+/// [`component_tween_system`](tween::component_tween_system) for
+/// `Relative<I>` and [`relative_repeat_system`] for `I`.
+///
+/// Use the [`relative`](crate::combinator::relative) combinator to create
+/// relative tweens.
+///
+/// [`RepeatStyle::PingPong`]: bevy_time_runner::RepeatStyle::PingPong
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{translation, Relative};
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = Relative::new(translation(Vec3::ZERO, Vec3::X));
+///
+/// let mut transform = Transform::from_xyz(5., 0., 0.);
+/// interpolator.interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(5.5, 0., 0.));
+/// assert_eq!(interpolator.base(), Some(&Vec3::new(5., 0., 0.)));
+///
+/// // Base is only captured once
+/// interpolator.interpolate(&mut transform, 1.);
+/// assert_eq!(transform.translation, Vec3::new(6., 0., 0.));
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct Relative<I>
+where
+    I: RelativeInterpolator,
+{
+    /// Interpolator describing the change
+    pub interpolator: I,
+    /// Capture the base again every time the animation repeats
+    pub resample_on_repeat: bool,
+    #[reflect(ignore)]
+    base: OnceLock<I::Base>,
+}
+
+impl<I> Relative<I>
+where
+    I: RelativeInterpolator,
+{
+    /// Create a new [`Relative`] that captures the base again on repeat
+    pub fn new(interpolator: I) -> Self {
+        Relative {
+            interpolator,
+            resample_on_repeat: true,
+            base: OnceLock::new(),
+        }
+    }
+
+    /// Set whether to capture the base again every time the animation repeats
+    pub fn with_resample_on_repeat(mut self, resample_on_repeat: bool) -> Self {
+        self.resample_on_repeat = resample_on_repeat;
+        self
+    }
+
+    /// Captured base or `None` if this interpolator has not been applied yet
+    pub fn base(&self) -> Option<&I::Base> {
+        self.base.get()
+    }
+
+    /// Forget the captured base so it's captured again on the next apply
+    pub fn reset_base(&mut self) {
+        self.base = OnceLock::new();
+    }
+}
+
+impl<I> Interpolator for Relative<I>
+where
+    I: RelativeInterpolator,
+{
+    type Item = I::Item;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let base = self.base.get_or_init(|| self.interpolator.capture(item));
+        self.interpolator.interpolate_relative(base, item, value);
+    }
+}
+
+/// Forget the captured base of [`Relative`] tweens whose time runner has
+/// repeated, if [`Relative::resample_on_repeat`] is enabled.
+///
+/// The base is then captured from the item's value at the next apply which
+/// may include a part of the previous repeat that wasn't applied yet.
+#[allow(clippy::type_complexity)]
+pub fn relative_repeat_system<I>(
+    mut ended: EventReader<TimeRunnerEnded>,
+    mut q_tween: Query<(
+        Entity,
+        &mut tween::ComponentTween<Relative<I>>,
+        Option<&Parent>,
+    )>,
+) where
+    I: RelativeInterpolator,
+{
+    for ended in ended.read() {
+        if ended.is_completed() {
+            continue;
+        }
+        for (entity, mut tween, parent) in &mut q_tween {
+            let in_runner = entity == ended.time_runner
+                || parent.is_some_and(|p| p.get() == ended.time_runner);
+            if in_runner && tween.interpolator.resample_on_repeat {
+                tween.interpolator.reset_base();
+            }
+        }
+    }
+}
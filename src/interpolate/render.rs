@@ -0,0 +1,355 @@
+use crate::interpolate::Interpolator;
+use crate::tween;
+use bevy::prelude::*;
+use bevy::render::camera::ViewportConversionError;
+use bevy_time_runner::TimeSpanProgress;
+use std::time::Duration;
+
+/// [`Interpolator`] for [`Camera`]'s [`clear_color`](Camera::clear_color).
+///
+/// Both endpoints are custom colors so the camera's
+/// [`ClearColorConfig`] is always set to [`ClearColorConfig::Custom`] while
+/// tweening, overriding [`ClearColorConfig::Default`] or
+/// [`ClearColorConfig::None`]. This allows each camera to have its own
+/// background fade unlike the global [`ClearColor`] resource.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::camera_clear_color;
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = camera_clear_color(Color::BLACK, Color::WHITE);
+///
+/// let mut camera = Camera::default();
+/// interpolator.interpolate(&mut camera, 0.5);
+/// let ClearColorConfig::Custom(color) = camera.clear_color else {
+///     panic!("clear color should be custom");
+/// };
+/// assert_eq!(color, Color::BLACK.mix(&Color::WHITE, 0.5));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct CameraClearColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+}
+
+impl Interpolator for CameraClearColor {
+    type Item = Camera;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.clear_color =
+            ClearColorConfig::Custom(self.start.mix(&self.end, value));
+    }
+}
+
+/// Constructor for [`CameraClearColor`]
+pub fn camera_clear_color(start: Color, end: Color) -> CameraClearColor {
+    CameraClearColor { start, end }
+}
+
+/// Constructor for [`CameraClearColor`] that's relative to previous value using currying.
+pub fn camera_clear_color_to(
+    to: Color,
+) -> impl Fn(&mut Color) -> CameraClearColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        camera_clear_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation toward a screen position
+/// of a camera, for example, flying an item to an inventory slot.
+///
+/// `screen_position` is in logical pixels of the camera's viewport with the
+/// origin at the top-left like [`Camera::viewport_to_world`]. The end in world
+/// space is resolved by [`translation_to_screen_system`] every frame the tween
+/// is active using the current [`Camera`] and [`GlobalTransform`] of `camera`,
+/// so camera movement and viewport changes are followed. The end lies on the
+/// plane through `start` facing the camera's forward, keeping the same depth
+/// from the camera.
+///
+/// The translation is left untouched until the end is resolved, which requires
+/// the camera's viewport size to be computed by Bevy's camera system.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::render::camera::{camera_system, ManualTextureViews};
+/// use bevy::window::{
+///     PrimaryWindow, WindowCreated, WindowResized, WindowScaleFactorChanged,
+/// };
+/// use bevy_tween::interpolate::TranslationToScreen;
+/// use bevy_tween::prelude::*;
+///
+/// let mut world = World::new();
+/// world.init_resource::<Events<WindowCreated>>();
+/// world.init_resource::<Events<WindowResized>>();
+/// world.init_resource::<Events<WindowScaleFactorChanged>>();
+/// world.init_resource::<Events<AssetEvent<Image>>>();
+/// world.init_resource::<Assets<Image>>();
+/// world.init_resource::<ManualTextureViews>();
+/// world.spawn((
+///     Window {
+///         resolution: (800., 600.).into(),
+///         ..default()
+///     },
+///     PrimaryWindow,
+/// ));
+/// let camera = world
+///     .spawn((
+///         Camera::default(),
+///         OrthographicProjection::default_2d(),
+///         GlobalTransform::default(),
+///     ))
+///     .id();
+///
+/// // Compute the camera's viewport
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(camera_system::<OrthographicProjection>);
+/// schedule.run(&mut world);
+///
+/// let screen_position = Vec2::new(600., 150.);
+/// let mut interpolator =
+///     TranslationToScreen::new(Vec3::ZERO, screen_position, camera);
+/// let (camera, camera_transform) = world
+///     .query::<(&Camera, &GlobalTransform)>()
+///     .single(&world);
+/// interpolator.resolve(camera, camera_transform).unwrap();
+///
+/// let mut transform = Transform::default();
+/// interpolator.interpolate(&mut transform, 1.);
+/// assert!(transform
+///     .translation
+///     .abs_diff_eq(Vec3::new(200., 150., 0.), 1e-3));
+/// let projected = camera
+///     .world_to_viewport(camera_transform, transform.translation)
+///     .unwrap();
+/// assert!(projected.abs_diff_eq(screen_position, 1e-3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct TranslationToScreen {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    /// Target position in the camera's viewport
+    pub screen_position: Vec2,
+    /// Camera entity used to project `screen_position`
+    pub camera: Entity,
+    /// Resolved end in world space
+    pub end: Option<Vec3>,
+}
+
+impl TranslationToScreen {
+    /// Create a new [`TranslationToScreen`] with an unresolved end
+    pub fn new(start: Vec3, screen_position: Vec2, camera: Entity) -> Self {
+        TranslationToScreen {
+            start,
+            screen_position,
+            camera,
+            end: None,
+        }
+    }
+
+    /// Resolve [`Self::end`] from the camera.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Camera::viewport_to_world`] or
+    /// [`ViewportConversionError::InvalidData`] if the plane through `start`
+    /// is not in front of the camera. [`Self::end`] is not changed on error.
+    pub fn resolve(
+        &mut self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+    ) -> Result<(), ViewportConversionError> {
+        let ray =
+            camera.viewport_to_world(camera_transform, self.screen_position)?;
+        let distance = ray
+            .intersect_plane(
+                self.start,
+                InfinitePlane3d::new(camera_transform.forward()),
+            )
+            .ok_or(ViewportConversionError::InvalidData)?;
+        self.end = Some(ray.get_point(distance));
+        Ok(())
+    }
+}
+
+impl Interpolator for TranslationToScreen {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Some(end) = self.end {
+            item.translation = self.start.lerp(end, value);
+        }
+    }
+}
+
+/// Constructor for [`TranslationToScreen`] that's relative to previous value using currying.
+///
+/// The end is resolved from the camera while the tween is active and is
+/// unknown while building so the state is left unchanged.
+pub fn translation_to_screen(
+    screen_position: Vec2,
+    camera: Entity,
+) -> impl Fn(&mut Vec3) -> TranslationToScreen {
+    move |state| TranslationToScreen::new(*state, screen_position, camera)
+}
+
+/// Resolve the end of active [`TranslationToScreen`] tweens from their
+/// cameras.
+#[allow(clippy::type_complexity)]
+pub fn translation_to_screen_system(
+    mut q_tween: Query<
+        &mut tween::ComponentTween<TranslationToScreen>,
+        (With<TimeSpanProgress>, Without<tween::SkipTween>),
+    >,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+) {
+    for mut tween in &mut q_tween {
+        let Ok((camera, camera_transform)) =
+            q_camera.get(tween.interpolator.camera)
+        else {
+            continue;
+        };
+        // Keep the previous end until the camera can be projected again
+        let _ = tween.interpolator.resolve(camera, camera_transform);
+    }
+}
+
+/// [`Interpolator`] for [`Visibility`] that blinks strictly periodically by
+/// the tween's elapsed time.
+///
+/// Every frame the tween is active, [`visibility_blink_system`] records
+/// [`TimeSpanProgress::now`], the seconds elapsed since the start of the
+/// tween's time span. The entity is [`Visibility::Inherited`] for the first
+/// `on_ratio` of every `period` of the elapsed time and
+/// [`Visibility::Hidden`] for the rest. The tweened value is not used so
+/// easing has no effect and the visibility is left as is when the tween ends.
+///
+/// Blinking can also be done by repeating a short tween with
+/// [`Repeat`](bevy_time_runner::Repeat) but then the whole animation repeats
+/// with it and the blink rate is tied to the time runner's repeat
+/// configuration. This interpolator instead blinks at a fixed rate within a
+/// single long tween of any length, alongside other tweens in the same
+/// animation, and the pattern only depends on the elapsed time so it is the
+/// same regardless of frame rate.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::visibility_blink;
+/// use bevy_tween::prelude::*;
+///
+/// let mut interpolator = visibility_blink(Duration::from_secs_f32(0.2));
+/// let mut visibility = Visibility::default();
+///
+/// let pattern = [
+///     (0.05, Visibility::Inherited),
+///     (0.15, Visibility::Hidden),
+///     (0.25, Visibility::Inherited),
+///     (0.35, Visibility::Hidden),
+///     (1.05, Visibility::Inherited),
+///     (1.15, Visibility::Hidden),
+/// ];
+/// for (elapsed, expected) in pattern {
+///     interpolator.resolve(elapsed);
+///     interpolator.interpolate(&mut visibility, 0.);
+///     assert_eq!(visibility, expected);
+/// }
+///
+/// // Visible for a quarter of every period
+/// let mut interpolator = interpolator.with_on_ratio(0.25);
+/// interpolator.resolve(0.04);
+/// assert!(interpolator.is_on());
+/// interpolator.resolve(0.06);
+/// assert!(!interpolator.is_on());
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct VisibilityBlink {
+    /// Seconds of one on and off cycle
+    pub period: f32,
+    /// Fraction of the period to be visible, from `0.` to `1.`
+    pub on_ratio: f32,
+    /// Seconds elapsed since the start of the tween's time span recorded by
+    /// [`visibility_blink_system`]
+    pub elapsed: f32,
+}
+
+impl VisibilityBlink {
+    /// Create a new [`VisibilityBlink`] visible for half of every `period`
+    pub fn new(period: Duration) -> VisibilityBlink {
+        VisibilityBlink {
+            period: period.as_secs_f32(),
+            on_ratio: 0.5,
+            elapsed: 0.,
+        }
+    }
+
+    /// Be visible for the provided fraction of every period instead
+    pub fn with_on_ratio(mut self, on_ratio: f32) -> Self {
+        self.on_ratio = on_ratio;
+        self
+    }
+
+    /// Record the seconds elapsed since the start of the tween's time span
+    pub fn resolve(&mut self, elapsed: f32) {
+        self.elapsed = elapsed;
+    }
+
+    /// Returns `true` if the entity should be visible at the recorded elapsed
+    /// time
+    pub fn is_on(&self) -> bool {
+        if self.period <= 0. {
+            return true;
+        }
+        self.elapsed.rem_euclid(self.period) < self.period * self.on_ratio
+    }
+}
+
+impl Default for VisibilityBlink {
+    fn default() -> Self {
+        VisibilityBlink::new(Duration::from_secs_f32(0.5))
+    }
+}
+
+impl Interpolator for VisibilityBlink {
+    type Item = Visibility;
+
+    fn interpolate(&self, item: &mut Self::Item, _value: f32) {
+        *item = if self.is_on() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Constructor for [`VisibilityBlink`] visible for half of every `period`
+pub fn visibility_blink(period: Duration) -> VisibilityBlink {
+    VisibilityBlink::new(period)
+}
+
+/// Record the elapsed time of active [`VisibilityBlink`] tweens from their
+/// [`TimeSpanProgress`].
+#[allow(clippy::type_complexity)]
+pub fn visibility_blink_system(
+    mut q_tween: Query<
+        (
+            &mut tween::ComponentTween<VisibilityBlink>,
+            &TimeSpanProgress,
+        ),
+        Without<tween::SkipTween>,
+    >,
+) {
+    for (mut tween, progress) in &mut q_tween {
+        tween.interpolator.resolve(progress.now);
+    }
+}
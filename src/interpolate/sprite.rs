@@ -1,5 +1,7 @@
 use crate::interpolate::Interpolator;
+use crate::tween;
 use bevy::prelude::*;
+use bevy::render::render_resource::TextureDimension;
 
 // type ReflectInterpolatorSprite = ReflectInterpolator<Sprite>;
 
@@ -36,6 +38,246 @@ pub fn sprite_color_to(to: Color) -> impl Fn(&mut Color) -> SpriteColor {
     }
 }
 
+/// [`Interpolator`] for [`Sprite`]'s color mixed in [`Hsva`] space instead of
+/// the linear RGB mix of [`SpriteColor`], for example, so red to green passes
+/// through yellow instead of a muddy brown.
+///
+/// Hue is interpolated along the shorter arc so it wraps around `0.`/`360.`
+/// when that's closer. Achromatic endpoints such as gray, white, or black
+/// have no meaningful hue so they take the hue of the other endpoint, keeping
+/// the hue stable while the saturation or value fades.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{sprite_color, sprite_color_hsv};
+/// use bevy_tween::prelude::*;
+///
+/// let red = Color::srgb(1., 0., 0.);
+/// let green = Color::srgb(0., 1., 0.);
+///
+/// let mut sprite = Sprite::default();
+/// sprite_color_hsv(red, green).interpolate(&mut sprite, 0.5);
+/// let hsv = Hsva::from(sprite.color);
+/// assert!((hsv.hue - 60.).abs() < 1e-3, "yellow");
+/// assert!((hsv.saturation - 1.).abs() < 1e-3 && (hsv.value - 1.).abs() < 1e-3);
+///
+/// // Mixing in linear RGB is darker
+/// sprite_color(red, green).interpolate(&mut sprite, 0.5);
+/// assert!(Hsva::from(sprite.color).value < 1.);
+///
+/// // Short path across 0/360
+/// let magenta = Color::hsv(330., 1., 1.);
+/// let orange = Color::hsv(30., 1., 1.);
+/// sprite_color_hsv(magenta, orange).interpolate(&mut sprite, 0.5);
+/// let hue = Hsva::from(sprite.color).hue;
+/// assert!(hue < 1e-3 || hue > 360. - 1e-3);
+///
+/// // Hue stays while fading to gray
+/// let gray = Color::hsv(0., 0., 0.5);
+/// let blue = Color::hsv(240., 1., 1.);
+/// sprite_color_hsv(blue, gray).interpolate(&mut sprite, 0.5);
+/// assert!((Hsva::from(sprite.color).hue - 240.).abs() < 1e-2);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct SpriteColorHsv {
+    #[allow(missing_docs)]
+    pub start: Hsva,
+    #[allow(missing_docs)]
+    pub end: Hsva,
+}
+
+impl Interpolator for SpriteColorHsv {
+    type Item = Sprite;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.color = self.start.mix(&self.end, value).into();
+    }
+}
+
+/// Constructor for [`SpriteColorHsv`]
+pub fn sprite_color_hsv(start: Color, end: Color) -> SpriteColorHsv {
+    let mut start = Hsva::from(start);
+    let mut end = Hsva::from(end);
+    let achromatic = |c: &Hsva| c.saturation <= 0. || c.value <= 0.;
+    match (achromatic(&start), achromatic(&end)) {
+        (true, false) => start.hue = end.hue,
+        (false, true) => end.hue = start.hue,
+        _ => {}
+    }
+    SpriteColorHsv { start, end }
+}
+
+/// Constructor for [`SpriteColorHsv`] that's relative to previous value using currying.
+pub fn sprite_color_hsv_to(to: Color) -> impl Fn(&mut Color) -> SpriteColorHsv {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        sprite_color_hsv(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`Sprite`]'s color picked by sampling a gradient
+/// instead of mixing two endpoints.
+///
+/// The gradient is a list of [`Self::colors`] evenly spaced from `0.` to `1.`,
+/// adjacent colors are mixed the same way as [`SpriteColor`]. Values outside
+/// of `0.` to `1.` are clamped to the ends of the gradient.
+///
+/// # Gradient images
+///
+/// The gradient can also be read from the first row of an [`Image`] with
+/// [`sprite_gradient_image`]. [`sprite_gradient_image_system`] reads the
+/// image into [`Self::colors`] once it's loaded, the sprite isn't changed
+/// until then.
+///
+/// Reading happens on the CPU so the image must be kept in the main world
+/// with [`RenderAssetUsages::MAIN_WORLD`] and use an uncompressed format
+/// supported by [`Image::get_color_at`]. Prefer precomputed colors with
+/// [`sprite_gradient`] when possible.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{sprite_color, sprite_gradient};
+/// use bevy_tween::prelude::*;
+///
+/// let start = Color::srgb(1., 0., 0.);
+/// let end = Color::srgb(0., 0., 1.);
+/// let gradient = sprite_gradient(vec![start, end]);
+/// let lerp = sprite_color(start, end);
+///
+/// for value in [0., 0.25, 0.5, 1.] {
+///     let mut a = Sprite::default();
+///     let mut b = Sprite::default();
+///     gradient.interpolate(&mut a, value);
+///     lerp.interpolate(&mut b, value);
+///     assert_eq!(a.color, b.color);
+/// }
+/// ```
+///
+/// Reading a gradient image:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::render::render_asset::RenderAssetUsages;
+/// use bevy::render::render_resource::{
+///     Extent3d, TextureDimension, TextureFormat,
+/// };
+/// use bevy_tween::interpolate::SpriteGradient;
+///
+/// let image = Image::new(
+///     Extent3d {
+///         width: 2,
+///         height: 1,
+///         depth_or_array_layers: 1,
+///     },
+///     TextureDimension::D2,
+///     vec![255, 0, 0, 255, 0, 0, 255, 255],
+///     TextureFormat::Rgba8UnormSrgb,
+///     RenderAssetUsages::MAIN_WORLD,
+/// );
+/// let colors = SpriteGradient::read_image(&image).unwrap();
+/// assert_eq!(colors, [Color::srgb(1., 0., 0.), Color::srgb(0., 0., 1.)]);
+/// ```
+///
+/// [`RenderAssetUsages::MAIN_WORLD`]: bevy::render::render_asset::RenderAssetUsages::MAIN_WORLD
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct SpriteGradient {
+    /// Colors of the gradient evenly spaced from `0.` to `1.`
+    pub colors: Vec<Color>,
+    /// Image to read [`Self::colors`] from once it's loaded
+    pub image: Option<Handle<Image>>,
+}
+
+impl SpriteGradient {
+    /// Sample the gradient at `value` or `None` if there's no colors
+    pub fn sample(&self, value: f32) -> Option<Color> {
+        match self.colors.len() {
+            0 => None,
+            1 => Some(self.colors[0]),
+            len => {
+                let position = value.clamp(0., 1.) * (len - 1) as f32;
+                let i = (position as usize).min(len - 2);
+                Some(
+                    self.colors[i]
+                        .mix(&self.colors[i + 1], position - i as f32),
+                )
+            }
+        }
+    }
+
+    /// Read colors from the first row of `image`
+    pub fn read_image(
+        image: &Image,
+    ) -> Result<Vec<Color>, bevy::image::TextureAccessError> {
+        (0..image.width())
+            .map(|x| match image.texture_descriptor.dimension {
+                TextureDimension::D1 => image.get_color_at_1d(x),
+                _ => image.get_color_at(x, 0),
+            })
+            .collect()
+    }
+}
+
+impl Interpolator for SpriteGradient {
+    type Item = Sprite;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Some(color) = self.sample(value) {
+            item.color = color;
+        }
+    }
+}
+
+/// Constructor for [`SpriteGradient`]
+pub fn sprite_gradient(colors: Vec<Color>) -> SpriteGradient {
+    SpriteGradient {
+        colors,
+        image: None,
+    }
+}
+
+/// Constructor for [`SpriteGradient`] reading the gradient from `image`.
+/// See [gradient images](SpriteGradient#gradient-images).
+pub fn sprite_gradient_image(image: Handle<Image>) -> SpriteGradient {
+    SpriteGradient {
+        colors: Vec::new(),
+        image: Some(image),
+    }
+}
+
+/// Read the gradient images of [`SpriteGradient`] tweens once they're loaded.
+///
+/// Images that cannot be read are logged and dropped.
+pub fn sprite_gradient_image_system(
+    mut q_tween: Query<&mut tween::ComponentTween<SpriteGradient>>,
+    images: Option<Res<Assets<Image>>>,
+) {
+    let Some(images) = images else {
+        return;
+    };
+    for mut tween in &mut q_tween {
+        let Some(handle) = &tween.interpolator.image else {
+            continue;
+        };
+        let Some(image) = images.get(handle) else {
+            continue;
+        };
+        match SpriteGradient::read_image(image) {
+            Ok(colors) => tween.interpolator.colors = colors,
+            Err(e) => error!(
+                "SpriteGradient cannot read gradient image {}: {e}",
+                handle.id()
+            ),
+        }
+        tween.interpolator.image = None;
+    }
+}
+
 // type ReflectInterpolatorColorMaterial =
 //     ReflectInterpolator<bevy::sprite::ColorMaterial>;
 
@@ -71,3 +313,104 @@ pub fn color_material_to(to: Color) -> impl Fn(&mut Color) -> ColorMaterial {
         color_material(start, end)
     }
 }
+
+/// [`Interpolator`] for [`Wireframe2dColor`](bevy::sprite::Wireframe2dColor).
+/// Useful for pulsing the wireframe of selected entities while debugging.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct Wireframe2dColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+}
+
+impl Interpolator for Wireframe2dColor {
+    type Item = bevy::sprite::Wireframe2dColor;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.color = self.start.mix(&self.end, value);
+    }
+}
+
+/// Constructor for [`Wireframe2dColor`](crate::interpolate::Wireframe2dColor)
+pub fn wireframe_2d_color(start: Color, end: Color) -> Wireframe2dColor {
+    Wireframe2dColor { start, end }
+}
+
+/// Constructor for [`Wireframe2dColor`](crate::interpolate::Wireframe2dColor) that's relative to previous value using currying.
+pub fn wireframe_2d_color_to(
+    to: Color,
+) -> impl Fn(&mut Color) -> Wireframe2dColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        wireframe_2d_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`Wireframe2dConfig`]'s
+/// [`default_color`](Wireframe2dConfig::default_color).
+///
+/// The resource only exists with [`Wireframe2dPlugin`](bevy::sprite::Wireframe2dPlugin)
+/// so this isn't registered by
+/// [`DefaultInterpolatorsPlugin`](crate::interpolate::DefaultInterpolatorsPlugin).
+/// Register the system yourself if you need it.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::sprite::Wireframe2dConfig;
+/// use bevy_tween::interpolate::{wireframe_2d_config_color, Wireframe2dConfigColor};
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = wireframe_2d_config_color(Color::WHITE, Color::BLACK);
+///
+/// let mut config = Wireframe2dConfig::default();
+/// interpolator.interpolate(&mut config, 0.5);
+/// assert_eq!(config.default_color, Color::WHITE.mix(&Color::BLACK, 0.5));
+///
+/// fn wireframe_plugin(app: &mut App) {
+///     app.add_tween_systems(bevy_tween::resource_tween_system::<
+///         Wireframe2dConfigColor,
+///     >());
+/// }
+/// ```
+///
+/// [`Wireframe2dConfig`]: bevy::sprite::Wireframe2dConfig
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct Wireframe2dConfigColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+}
+
+impl Interpolator for Wireframe2dConfigColor {
+    type Item = bevy::sprite::Wireframe2dConfig;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.default_color = self.start.mix(&self.end, value);
+    }
+}
+
+/// Constructor for [`Wireframe2dConfigColor`]
+pub fn wireframe_2d_config_color(
+    start: Color,
+    end: Color,
+) -> Wireframe2dConfigColor {
+    Wireframe2dConfigColor { start, end }
+}
+
+/// Constructor for [`Wireframe2dConfigColor`] that's relative to previous value using currying.
+pub fn wireframe_2d_config_color_to(
+    to: Color,
+) -> impl Fn(&mut Color) -> Wireframe2dConfigColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        wireframe_2d_config_color(start, end)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::prelude::Interpolator;
+use bevy::prelude::*;
+
+/// [`Interpolator`] for Bevy's [`TextColor`](bevy::prelude::TextColor).
+///
+/// Every span of a text is its own entity with its own
+/// [`TextColor`](bevy::prelude::TextColor), target the [`TextSpan`] entity to
+/// tween a single span.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TextColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+}
+
+impl Interpolator for TextColor {
+    type Item = bevy::prelude::TextColor;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.0 = self.start.mix(&self.end, value)
+    }
+}
+
+/// Constructor for [`TextColor`](crate::interpolate::TextColor)
+pub fn text_color(start: Color, end: Color) -> TextColor {
+    TextColor { start, end }
+}
+
+/// Constructor for [`TextColor`](crate::interpolate::TextColor) that's relative to previous value using currying.
+pub fn text_color_to(to: Color) -> impl Fn(&mut Color) -> TextColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        text_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`TextFont`]'s font size.
+///
+/// Every span of a text is its own entity with its own [`TextFont`], target
+/// the [`TextSpan`] entity to tween a single span.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::tween;
+/// use bevy_tween::interpolate::text_font_size;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let text = app
+///     .world_mut()
+///     .spawn((Text::new("Hello"), TextFont::from_font_size(20.)))
+///     .with_child((TextSpan::new(" world"), TextFont::from_font_size(20.)))
+///     .id();
+/// let span = app.world().get::<Children>(text).unwrap()[0];
+/// app.world_mut().commands().animation().insert(tween(
+///     Duration::from_secs(1),
+///     EaseKind::Linear,
+///     span.into_target().with(text_font_size(20., 40.)),
+/// ));
+/// app.world_mut().flush();
+/// for _ in 0..15 {
+///     app.update();
+/// }
+///
+/// assert_eq!(app.world().get::<TextFont>(span).unwrap().font_size, 40.);
+/// assert_eq!(app.world().get::<TextFont>(text).unwrap().font_size, 20.);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TextFontSize {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for TextFontSize {
+    type Item = TextFont;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.font_size = self.start.lerp(self.end, value)
+    }
+}
+
+/// Constructor for [`TextFontSize`]
+pub fn text_font_size(start: f32, end: f32) -> TextFontSize {
+    TextFontSize { start, end }
+}
+
+/// Constructor for [`TextFontSize`] that's relative to previous value using currying.
+pub fn text_font_size_to(to: f32) -> impl Fn(&mut f32) -> TextFontSize {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        text_font_size(start, end)
+    }
+}
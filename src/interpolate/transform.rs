@@ -1,9 +1,48 @@
 // type ReflectInterpolatorTransform = ReflectInterpolator<Transform>;
 
+use std::sync::OnceLock;
+
 use crate::interpolate::Interpolator;
+use crate::tween;
 use bevy::prelude::*;
+use bevy_time_runner::TimeSpanProgress;
 
 /// [`Interpolator`] for [`Transform`]'s translation.
+///
+/// # Easing and distance
+///
+/// The translation moves along a straight line by the same distance for every
+/// unit of value, so easing the time is the same as easing the travelled
+/// distance: the distance covered is always `ease(t) * start.distance(end)`
+/// and there's no separate distance-eased mode for straight moves.
+///
+/// This doesn't hold for [`TranslationPath`](super::TranslationPath) where
+/// the curve's parameter does not advance by the same distance everywhere
+/// along the curve.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::prelude::*;
+///
+/// let start = Vec3::new(-100., 20., 0.);
+/// let end = Vec3::new(400., -80., 50.);
+/// let interpolator = translation(start, end);
+/// let ease = EaseKind::CubicInOut;
+///
+/// for i in 0..=10 {
+///     let t = i as f32 / 10.;
+///     // Time-eased
+///     let mut transform = Transform::default();
+///     interpolator.interpolate(&mut transform, ease.sample(t));
+///     // Distance-eased
+///     let distance = ease.sample(t) * start.distance(end);
+///     let expected = start + (end - start).normalize() * distance;
+///     assert!(transform.translation.distance(expected) < 1e-3);
+/// }
+/// ```
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
 // #[reflect(InterpolatorTransform)]
 pub struct Translation {
@@ -45,6 +84,624 @@ pub fn translation_by(by: Vec3) -> impl Fn(&mut Vec3) -> Translation {
     }
 }
 
+impl Translation {
+    /// Snap the end of this translation to `grid`, see [`TranslationSnapped`]
+    pub fn snapped(self, grid: Vec3) -> TranslationSnapped {
+        translation_snapped(self.start, self.end, grid)
+    }
+
+    /// Cap the speed of this translation to `max_speed`, see
+    /// [`TranslationMaxSpeed`]
+    pub fn max_speed(self, max_speed: f32) -> TranslationMaxSpeed {
+        translation_max_speed(self.start, self.end, max_speed)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation that lands exactly on a
+/// grid, for example, moves in a tile-based game.
+///
+/// Translation is interpolated the same as [`Translation`] but once the
+/// tweened value reaches `1.` or more, the applied translation is rounded to
+/// the nearest multiple of `grid` on each axis. Snapping only applies at
+/// `t >= 1` so the motion in between is left as is. Note that eases
+/// overshooting past `1.` before the end, such as [`EaseKind::BackOut`], are
+/// snapped while overshooting. Axes with a grid resolution of zero or less are
+/// not snapped.
+///
+/// The relative constructors [`translation_snapped_to`] and
+/// [`translation_snapped_by`] also snap the state so chained moves start from
+/// the grid and don't accumulate sub-pixel drift.
+///
+/// [`EaseKind::BackOut`]: crate::interpolation::EaseKind::BackOut
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::translation_snapped;
+/// use bevy_tween::interpolation::Interpolation;
+/// use bevy_tween::prelude::*;
+///
+/// let grid = Vec3::new(16., 16., 0.);
+/// let interpolator =
+///     translation_snapped(Vec3::ZERO, Vec3::new(47.9, 16.2, 0.3), grid);
+/// let mut transform = Transform::default();
+///
+/// interpolator.interpolate(&mut transform, EaseKind::QuadraticInOut.sample(0.5));
+/// assert_ne!(transform.translation.x % 16., 0.);
+///
+/// interpolator.interpolate(&mut transform, EaseKind::BackOut.sample(1.));
+/// assert_eq!(transform.translation, Vec3::new(48., 16., 0.3));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TranslationSnapped {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    /// Grid resolution of each axis
+    pub grid: Vec3,
+}
+
+impl TranslationSnapped {
+    fn snap(value: Vec3, grid: Vec3) -> Vec3 {
+        let snapped = (value / grid).round() * grid;
+        Vec3::select(grid.cmpgt(Vec3::ZERO), snapped, value)
+    }
+}
+
+impl Interpolator for TranslationSnapped {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let translation = self.start.lerp(self.end, value);
+        item.translation = if value >= 1. {
+            TranslationSnapped::snap(translation, self.grid)
+        } else {
+            translation
+        };
+    }
+}
+
+/// Constructor for [`TranslationSnapped`]
+pub fn translation_snapped(
+    start: Vec3,
+    end: Vec3,
+    grid: Vec3,
+) -> TranslationSnapped {
+    TranslationSnapped { start, end, grid }
+}
+
+/// Constructor for [`TranslationSnapped`] that's relative to previous value using currying.
+pub fn translation_snapped_to(
+    to: Vec3,
+    grid: Vec3,
+) -> impl Fn(&mut Vec3) -> TranslationSnapped {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = TranslationSnapped::snap(to, grid);
+        translation_snapped(start, end, grid)
+    }
+}
+
+/// Constructor for [`TranslationSnapped`] that's relative to previous value using currying.
+pub fn translation_snapped_by(
+    by: Vec3,
+    grid: Vec3,
+) -> impl Fn(&mut Vec3) -> TranslationSnapped {
+    move |state| {
+        let start = *state;
+        let end = *state + by;
+        *state = TranslationSnapped::snap(end, grid);
+        translation_snapped(start, end, grid)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation moving toward the eased
+/// position by at most `max_speed` units per second, so large jumps don't
+/// occur with eases that move quickly or with long frames.
+///
+/// Every frame, the translation moves from where it currently is toward the
+/// position interpolated the same as [`Translation`], but the distance moved
+/// is capped by `max_speed` times the frame's delta time. The delta time is
+/// recorded by [`translation_max_speed_system`].
+///
+/// # Reaching the end
+///
+/// When capped, the translation lags behind the eased position and may not
+/// reach `end` by the time the tween ends. To make sure it does, either:
+/// - Give the tween a duration of at least `start.distance(end) / max_speed`
+///   plus the time spent lagging, for example, by adding
+///   [`forward`](crate::combinator::forward) with a tween of a constant `end`.
+/// - Keep applying the tween after the animation completes with
+///   [`RetainFinalValue`](tween::RetainFinalValue) so it keeps moving toward
+///   `end` at the capped speed.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::interpolate::translation_max_speed;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let max_speed = 50.;
+/// let end = Vec3::new(100., 0., 0.);
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// app.world_mut()
+///     .commands()
+///     .entity(entity)
+///     .animation()
+///     .insert_tween_here(
+///         Duration::from_secs(1),
+///         EaseKind::ExponentialIn,
+///         entity
+///             .into_target()
+///             .with(translation_max_speed(Vec3::ZERO, end, max_speed)),
+///     );
+/// app.world_mut().flush();
+///
+/// let mut previous = Vec3::ZERO;
+/// for _ in 0..15 {
+///     app.update();
+///     let translation = app.world().get::<Transform>(entity).unwrap().translation;
+///     assert!(translation.distance(previous) <= max_speed * 0.1 + 1e-4);
+///     previous = translation;
+/// }
+/// // Capped behind the end
+/// assert!(previous.x < end.x);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TranslationMaxSpeed {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    /// Maximum speed in units per second
+    pub max_speed: f32,
+    /// Delta time in seconds of the current frame
+    pub delta: f32,
+}
+
+impl TranslationMaxSpeed {
+    /// Set the delta time of the current frame
+    pub fn resolve(&mut self, delta: f32) {
+        self.delta = delta;
+    }
+}
+
+impl Interpolator for TranslationMaxSpeed {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let target = self.start.lerp(self.end, value);
+        let step = (target - item.translation)
+            .clamp_length_max(self.max_speed * self.delta);
+        item.translation += step;
+    }
+}
+
+/// Constructor for [`TranslationMaxSpeed`]
+pub fn translation_max_speed(
+    start: Vec3,
+    end: Vec3,
+    max_speed: f32,
+) -> TranslationMaxSpeed {
+    TranslationMaxSpeed {
+        start,
+        end,
+        max_speed,
+        delta: 0.,
+    }
+}
+
+/// Constructor for [`TranslationMaxSpeed`] that's relative to previous value using currying.
+pub fn translation_max_speed_to(
+    to: Vec3,
+    max_speed: f32,
+) -> impl Fn(&mut Vec3) -> TranslationMaxSpeed {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        translation_max_speed(start, end, max_speed)
+    }
+}
+
+/// Constructor for [`TranslationMaxSpeed`] that's relative to previous value using currying.
+pub fn translation_max_speed_by(
+    by: Vec3,
+    max_speed: f32,
+) -> impl Fn(&mut Vec3) -> TranslationMaxSpeed {
+    move |state| {
+        let start = *state;
+        let end = *state + by;
+        *state += by;
+        translation_max_speed(start, end, max_speed)
+    }
+}
+
+/// Record the delta time for [`TranslationMaxSpeed`] tweens.
+pub fn translation_max_speed_system(
+    time: Res<Time>,
+    mut q_tween: Query<
+        &mut tween::ComponentTween<TranslationMaxSpeed>,
+        Without<tween::SkipTween>,
+    >,
+) {
+    let delta = time.delta_secs();
+    for mut tween in &mut q_tween {
+        tween.interpolator.resolve(delta);
+    }
+}
+
+/// [`Interpolator`] adding an offset to [`Transform`]'s translation on top of
+/// other tweens, for example, an idle bob plus a knockback on the same entity.
+///
+/// Unlike [`Translation`], which overwrites the translation so the last
+/// applied tween wins, every [`TranslationAdd`] targeting an entity adds
+/// `start.lerp(end, value)` to its [`AdditiveTranslation`] and the sum is
+/// written to the translation once per frame. The target entity needs the
+/// [`AdditiveTranslation`] component.
+///
+/// # Ordering
+///
+/// Offsets are summed in [`TweenSystemSet::ApplyTween`] so the order of the
+/// additive tweens doesn't matter:
+/// 1. [`additive_translation_reset_system`] removes the offset written in the
+///    previous frame from the translation in
+///    [`TweenSystemSet::UpdateInterpolationValue`].
+/// 2. Non-additive tweens set the translation and [`TranslationAdd`] tweens
+///    add to the offset in [`TweenSystemSet::ApplyTween`].
+/// 3. [`additive_translation_system`] adds the summed offset to the
+///    translation after [`TweenSystemSet::ApplyTween`].
+///
+/// Since the offset is summed again every frame, it doesn't accumulate over
+/// time and a tween stops contributing once it's no longer active. Use
+/// [`RetainFinalValue`](tween::RetainFinalValue) to keep the final offset of a
+/// completed tween.
+///
+/// [`TweenSystemSet::ApplyTween`]: crate::TweenSystemSet::ApplyTween
+/// [`TweenSystemSet::UpdateInterpolationValue`]: crate::TweenSystemSet::UpdateInterpolationValue
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{parallel, tween};
+/// use bevy_tween::interpolate::{translation_add, AdditiveTranslation};
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let entity = app
+///     .world_mut()
+///     .spawn((Transform::from_xyz(1., 0., 0.), AdditiveTranslation::default()))
+///     .id();
+/// let target = entity.into_target();
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .retain_final_value(true)
+///     .insert(parallel((
+///         tween(
+///             Duration::from_secs(1),
+///             EaseKind::Linear,
+///             target.with(translation_add(Vec3::ZERO, Vec3::new(2., 0., 0.))),
+///         ),
+///         tween(
+///             Duration::from_secs(1),
+///             EaseKind::Linear,
+///             target.with(translation_add(Vec3::ZERO, Vec3::new(0., 3., 0.))),
+///         ),
+///     )));
+/// app.world_mut().flush();
+///
+/// for _ in 0..20 {
+///     app.update();
+///     let translation = app.world().get::<Transform>(entity).unwrap().translation;
+///     // Both offsets progress together on top of the original translation
+///     assert!(((translation.x - 1.) / 2. - translation.y / 3.).abs() < 1e-4);
+/// }
+///
+/// // Summed offset without drifting over time
+/// let translation = app.world().get::<Transform>(entity).unwrap().translation;
+/// assert!(translation.distance(Vec3::new(3., 3., 0.)) < 1e-4);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TranslationAdd {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+}
+
+impl Interpolator for TranslationAdd {
+    type Item = AdditiveTranslation;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.offset += self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`TranslationAdd`]
+pub fn translation_add(start: Vec3, end: Vec3) -> TranslationAdd {
+    TranslationAdd { start, end }
+}
+
+/// Constructor for [`TranslationAdd`] that's relative to previous value using currying.
+pub fn translation_add_to(to: Vec3) -> impl Fn(&mut Vec3) -> TranslationAdd {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        translation_add(start, end)
+    }
+}
+
+/// Sum of the [`TranslationAdd`] offsets of an entity in the current frame.
+/// See [`TranslationAdd`] for how offsets are applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AdditiveTranslation {
+    offset: Vec3,
+    applied: Vec3,
+}
+
+impl AdditiveTranslation {
+    /// Offset summed so far in the current frame
+    pub fn offset(&self) -> Vec3 {
+        self.offset
+    }
+
+    /// Offset currently added to the translation
+    pub fn applied(&self) -> Vec3 {
+        self.applied
+    }
+}
+
+/// Remove the offset of [`AdditiveTranslation`] written in the previous frame
+/// from the translation and start summing again.
+pub fn additive_translation_reset_system(
+    mut q_additive: Query<(&mut AdditiveTranslation, &mut Transform)>,
+) {
+    for (mut additive, mut transform) in &mut q_additive {
+        if additive.applied != Vec3::ZERO {
+            transform.translation -= additive.applied;
+        }
+        additive.offset = Vec3::ZERO;
+        additive.applied = Vec3::ZERO;
+    }
+}
+
+/// Add the summed offset of [`AdditiveTranslation`] to the translation.
+pub fn additive_translation_system(
+    mut q_additive: Query<(&mut AdditiveTranslation, &mut Transform)>,
+) {
+    for (mut additive, mut transform) in &mut q_additive {
+        if additive.offset != Vec3::ZERO {
+            transform.translation += additive.offset;
+        }
+        additive.applied = additive.offset;
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation at Z axis only.
+/// Usually used for changing draw order in 2D without disturbing X and Y.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::translation_z;
+/// use bevy_tween::prelude::*;
+///
+/// let mut transform = Transform::from_xyz(3., 4., 0.);
+/// translation_z(0., 10.).interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(3., 4., 5.));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TranslationZ {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+impl Interpolator for TranslationZ {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.translation.z = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`TranslationZ`]
+pub fn translation_z(start: f32, end: f32) -> TranslationZ {
+    TranslationZ { start, end }
+}
+
+/// Constructor for [`TranslationZ`] that's relative to previous value using currying.
+pub fn translation_z_to(to: f32) -> impl Fn(&mut f32) -> TranslationZ {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        translation_z(start, end)
+    }
+}
+
+/// Constructor for [`TranslationZ`] that's relative to previous value using currying.
+pub fn translation_z_by(by: f32) -> impl Fn(&mut f32) -> TranslationZ {
+    move |state| {
+        let start = *state;
+        let end = *state + by;
+        *state += by;
+        translation_z(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation along a parabolic arc, for
+/// example, for jumps and projectiles.
+///
+/// The translation moves in a straight line from `start` to `end` while a
+/// vertical offset along Y rises from zero to `height` at the middle and falls
+/// back to zero at the end. A negative `height` dips instead, and the arc is
+/// kept even if `start` is equal to `end`. Use a linear ease to keep the
+/// horizontal speed constant like a thrown object.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::translation_arc;
+/// use bevy_tween::prelude::*;
+///
+/// let start = Vec3::new(0., 0., 0.);
+/// let end = Vec3::new(4., 2., 0.);
+/// let mut transform = Transform::default();
+///
+/// translation_arc(start, end, 3.).interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(2., 1. + 3., 0.));
+///
+/// // Dip
+/// translation_arc(start, end, -3.).interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(2., 1. - 3., 0.));
+///
+/// // Jump in place
+/// translation_arc(end, end, 3.).interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, end + Vec3::Y * 3.);
+/// translation_arc(end, end, 3.).interpolate(&mut transform, 1.);
+/// assert_eq!(transform.translation, end);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TranslationArc {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    /// Vertical offset at the middle of the arc
+    pub height: f32,
+}
+impl Interpolator for TranslationArc {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let arc = 4. * self.height * value * (1. - value);
+        item.translation = self.start.lerp(self.end, value) + Vec3::Y * arc;
+    }
+}
+
+/// Constructor for [`TranslationArc`]
+pub fn translation_arc(start: Vec3, end: Vec3, height: f32) -> TranslationArc {
+    TranslationArc { start, end, height }
+}
+
+/// Constructor for [`TranslationArc`] that's relative to previous value using currying.
+pub fn translation_arc_to(
+    to: Vec3,
+    height: f32,
+) -> impl Fn(&mut Vec3) -> TranslationArc {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        translation_arc(start, end, height)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation in a wrap-around
+/// (toroidal) space.
+///
+/// The X and Y axes wrap within `bounds`: moving past `bounds.max` reappears
+/// at `bounds.min` and the other way around, like in an asteroids-style world.
+/// The translation moves along the shortest wrapped path from `start` to
+/// `end`, which may cross an edge, and the result is always wrapped back
+/// into `bounds` within `[min, max)`. The Z axis is not wrapped and is
+/// linearly interpolated.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::translation_wrapped;
+/// use bevy_tween::prelude::*;
+///
+/// let bounds = Rect::new(-10., -10., 10., 10.);
+/// let interpolator =
+///     translation_wrapped(bounds, Vec3::new(8., 0., 0.), Vec3::new(-8., 0., 0.));
+///
+/// let mut transform = Transform::default();
+/// interpolator.interpolate(&mut transform, 0.75);
+/// assert!((transform.translation.x - -9.).abs() < 1e-5);
+///
+/// // Moves across the edge in small steps instead of through the middle
+/// let mut previous = 8.;
+/// for i in 0..=100 {
+///     interpolator.interpolate(&mut transform, i as f32 / 100.);
+///     let x = transform.translation.x;
+///     assert!((-10. ..10.).contains(&x));
+///     let step = (x - previous).rem_euclid(20.);
+///     assert!(step.min(20. - step) < 0.2 + 1e-4);
+///     previous = x;
+/// }
+/// assert!((previous - -8.).abs() < 1e-5);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TranslationWrapped {
+    /// Wrapping bounds of the X and Y axes
+    pub bounds: Rect,
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+}
+impl Interpolator for TranslationWrapped {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let min = self.bounds.min;
+        let size = self.bounds.size();
+        let start = self.start.truncate();
+        let mut delta = self.end.truncate() - start;
+        if size.x > 0. {
+            delta.x -= size.x * (delta.x / size.x).round();
+        }
+        if size.y > 0. {
+            delta.y -= size.y * (delta.y / size.y).round();
+        }
+        let position = start + delta * value - min;
+        let wrapped =
+            min + Vec2::new(wrap(position.x, size.x), wrap(position.y, size.y));
+        item.translation = wrapped.extend(self.start.z.lerp(self.end.z, value));
+    }
+}
+
+fn wrap(value: f32, size: f32) -> f32 {
+    if size > 0. {
+        value.rem_euclid(size)
+    } else {
+        value
+    }
+}
+
+/// Constructor for [`TranslationWrapped`]
+pub fn translation_wrapped(
+    bounds: Rect,
+    start: Vec3,
+    end: Vec3,
+) -> TranslationWrapped {
+    TranslationWrapped { bounds, start, end }
+}
+
+/// Constructor for [`TranslationWrapped`] that's relative to previous value using currying.
+pub fn translation_wrapped_to(
+    bounds: Rect,
+    to: Vec3,
+) -> impl Fn(&mut Vec3) -> TranslationWrapped {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        translation_wrapped(bounds, start, end)
+    }
+}
+
 /// [`Interpolator`] for [`Transform`]'s rotation using the [`Quat::slerp`] function.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
 // #[reflect(InterpolatorTransform)]
@@ -129,8 +786,80 @@ pub fn scale_by(by: Vec3) -> impl Fn(&mut Vec3) -> Scale {
     }
 }
 
+/// [`Interpolator`] for [`Transform`]'s scale that starts from the live scale.
+///
+/// The start is captured from the item's current scale the first time this
+/// interpolator is applied, which is the first run of
+/// [`TweenSystemSet::ApplyTween`](crate::TweenSystemSet::ApplyTween) where the
+/// tween is active. Anything that changed the scale before then, such as
+/// external scaling or an earlier tween, is picked up. The captured start is
+/// kept afterward including when the tween is repeated.
+///
+/// Targeting multiple entities is not supported since only one start is
+/// captured and every target would start from the first target's scale.
+/// [`single_target_check_system`] logs an error for tweens targeting
+/// [`TargetComponent::Entities`](tween::TargetComponent::Entities) with more
+/// than one entity.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::scale_to_from_current;
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = scale_to_from_current(Vec3::splat(4.));
+///
+/// let mut transform = Transform::from_scale(Vec3::splat(2.));
+/// interpolator.interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.scale, Vec3::splat(3.));
+/// assert_eq!(interpolator.start(), Some(Vec3::splat(2.)));
+///
+/// // Start is only captured once
+/// interpolator.interpolate(&mut transform, 0.);
+/// assert_eq!(transform.scale, Vec3::splat(2.));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct ScaleFromCurrent {
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    #[reflect(ignore)]
+    start: OnceLock<Vec3>,
+}
+impl ScaleFromCurrent {
+    /// Create a new [`ScaleFromCurrent`] to `end`
+    pub fn new(end: Vec3) -> Self {
+        ScaleFromCurrent {
+            end,
+            start: OnceLock::new(),
+        }
+    }
+
+    /// Captured start or `None` if this interpolator has not been applied yet
+    pub fn start(&self) -> Option<Vec3> {
+        self.start.get().copied()
+    }
+}
+impl Interpolator for ScaleFromCurrent {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let start = *self.start.get_or_init(|| item.scale);
+        item.scale = start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`ScaleFromCurrent`]
+pub fn scale_to_from_current(end: Vec3) -> ScaleFromCurrent {
+    ScaleFromCurrent::new(end)
+}
+
 /// [`Interpolator`] for [`Transform`]'s rotation at Z axis.
 /// Usually used for 2D rotation.
+///
+/// Angles in radians are interpolated as is, so `angle_z(0., TAU * 2.)` spins
+/// two full turns and tweening from 350° to 10° turns 340° the long way. Use
+/// [`angle_z_shortest`] or [`rotation_z_shortest`] to turn the shortest way.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
 // #[reflect(InterpolatorTransform)]
 pub struct AngleZ {
@@ -172,3 +901,1016 @@ pub fn angle_z_by(by: f32) -> impl Fn(&mut f32) -> AngleZ {
         angle_z(start, end)
     }
 }
+
+/// Constructor for [`AngleZ`] that turns from `start` to `end` the shortest
+/// way instead of spinning through their raw difference.
+///
+/// The difference is wrapped into `[-π, π)`, so `end` is moved by whole turns
+/// to be within half a turn of `start`. A difference of exactly half a turn
+/// in either direction turns clockwise, by `-π`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::interpolate::angle_z_shortest;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// app.world_mut()
+///     .commands()
+///     .entity(entity)
+///     .animation()
+///     .insert_tween_here(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         entity.into_target().with(angle_z_shortest(
+///             350_f32.to_radians(),
+///             10_f32.to_radians(),
+///         )),
+///     );
+/// app.world_mut().flush();
+///
+/// for _ in 0..12 {
+///     app.update();
+///     let rotation = app.world().get::<Transform>(entity).unwrap().rotation;
+///     // Goes through 0° and never near 180°
+///     assert!(rotation.angle_between(Quat::IDENTITY) <= 10_f32.to_radians() + 1e-4);
+/// }
+/// let rotation = app.world().get::<Transform>(entity).unwrap().rotation;
+/// let end = Quat::from_rotation_z(10_f32.to_radians());
+/// assert!(rotation.angle_between(end) < 1e-4);
+/// ```
+pub fn angle_z_shortest(start: f32, end: f32) -> AngleZ {
+    use std::f32::consts::{PI, TAU};
+    let delta = (end - start + PI).rem_euclid(TAU) - PI;
+    angle_z(start, start + delta)
+}
+
+/// Constructor for [`AngleZ`] that's relative to previous value using currying
+/// and turns the shortest way, see [`angle_z_shortest`].
+pub fn rotation_z_shortest(to: f32) -> impl Fn(&mut f32) -> AngleZ {
+    move |state| {
+        let start = *state;
+        *state = to;
+        angle_z_shortest(start, to)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s rotation at Z axis that turns to face
+/// a point. Usually used for 2D rotation, for example, to turn a top-down
+/// character toward where it's going.
+///
+/// The start is captured from the item's current rotation the first time this
+/// interpolator is applied, like [`ScaleFromCurrent`]. The end faces `target`
+/// with the local X axis and is computed from the item's current translation
+/// on every apply, so it stays facing the target while the item moves. The
+/// rotation is slerped along the shortest arc so it never spins the long way
+/// around. If the item is at the target, the start rotation is kept.
+///
+/// Like [`ScaleFromCurrent`], targeting multiple entities is not supported
+/// since only one start is captured.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::rotation_z_look_at;
+/// use bevy_tween::prelude::*;
+///
+/// let angle = |t: &Transform| {
+///     let forward = t.rotation * Vec3::X;
+///     forward.y.atan2(forward.x)
+/// };
+///
+/// // Facing slightly below -X, the target is slightly above -X
+/// let mut transform = Transform::from_xyz(10., 10., 0.)
+///     .with_rotation(Quat::from_rotation_z(-3.));
+/// let interpolator = rotation_z_look_at(Vec2::new(0., 10.5));
+///
+/// interpolator.interpolate(&mut transform, 0.5);
+/// // Turned through -X instead of the long way through +X
+/// assert!(angle(&transform).abs() > 3.);
+///
+/// interpolator.interpolate(&mut transform, 1.);
+/// let to_target = Vec2::new(0., 10.5) - transform.translation.truncate();
+/// assert!((angle(&transform) - to_target.y.atan2(to_target.x)).abs() < 1e-4);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct RotationZLookAt {
+    /// Point to face
+    pub target: Vec2,
+    #[reflect(ignore)]
+    start: OnceLock<Quat>,
+}
+impl RotationZLookAt {
+    /// Create a new [`RotationZLookAt`] facing `target`
+    pub fn new(target: Vec2) -> Self {
+        RotationZLookAt {
+            target,
+            start: OnceLock::new(),
+        }
+    }
+
+    /// Captured start or `None` if this interpolator has not been applied yet
+    pub fn start(&self) -> Option<Quat> {
+        self.start.get().copied()
+    }
+}
+impl Interpolator for RotationZLookAt {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let start = *self.start.get_or_init(|| item.rotation);
+        let to_target = self.target - item.translation.truncate();
+        if to_target == Vec2::ZERO {
+            item.rotation = start;
+            return;
+        }
+        let end = Quat::from_rotation_z(to_target.y.atan2(to_target.x));
+        item.rotation = start.slerp(end, value);
+    }
+}
+
+/// Constructor for [`RotationZLookAt`]
+pub fn rotation_z_look_at(target: Vec2) -> RotationZLookAt {
+    RotationZLookAt::new(target)
+}
+
+/// Log an error for added tweens of an interpolator that captures a single
+/// start from its target, such as [`ScaleFromCurrent`] and
+/// [`RotationZLookAt`], if they target more than one entity.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{
+///     scale_to_from_current, single_target_check_system, ScaleFromCurrent,
+/// };
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::TargetComponent;
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(single_target_check_system::<ScaleFromCurrent>);
+///
+/// let a = world.spawn(Transform::default()).id();
+/// let b = world.spawn(Transform::default()).id();
+/// // Logs an error since `b` would start from the scale of `a`
+/// world.spawn(
+///     TargetComponent::entities([a, b]).with(scale_to_from_current(Vec3::ONE)),
+/// );
+/// schedule.run(&mut world);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn single_target_check_system<I>(
+    q_tween: Query<
+        (Entity, &tween::ComponentTween<I>),
+        Added<tween::ComponentTween<I>>,
+    >,
+) where
+    I: Send + Sync + 'static,
+{
+    for (entity, tween) in &q_tween {
+        if let tween::TargetComponent::Entities(targets) = &tween.target {
+            if targets.len() > 1 {
+                error!(
+                    "Tween {entity} {} targets {} entities but only supports a single target since it captures one start",
+                    std::any::type_name::<I>(),
+                    targets.len()
+                );
+            }
+        }
+    }
+}
+
+/// [`Interpolator`] wrapper that constrains the translation of the inner
+/// [`Transform`] interpolator to the axes allowed by `mask`.
+///
+/// Axes with `false` in the mask keep the translation they had before the inner
+/// interpolator ran, so the movement is locked to an axis or a plane. For
+/// example, [`BVec3::new(true, false, false)`](BVec3::new) only allows
+/// movement along X. Rotation and scale set by the inner interpolator are left
+/// as is.
+///
+/// Since this interpolator is generic, you have to
+/// [register the system](crate::tween#registering-systems) for each inner
+/// interpolator you use.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{constrained, translation};
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = constrained(
+///     BVec3::new(true, false, true),
+///     translation(Vec3::ZERO, Vec3::new(10., 10., 10.)),
+/// );
+///
+/// let mut transform = Transform::from_xyz(0., 3., 0.);
+/// interpolator.interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(5., 3., 5.));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct Constrained<I> {
+    /// Allowed translation axes
+    pub mask: BVec3,
+    /// Inner interpolator
+    pub inner: I,
+}
+impl<I> Interpolator for Constrained<I>
+where
+    I: Interpolator<Item = Transform>,
+{
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let before = item.translation;
+        self.inner.interpolate(item, value);
+        item.translation = Vec3::select(self.mask, item.translation, before);
+    }
+}
+
+/// Constructor for [`Constrained`]
+pub fn constrained<I>(mask: BVec3, inner: I) -> Constrained<I>
+where
+    I: Interpolator<Item = Transform>,
+{
+    Constrained { mask, inner }
+}
+
+/// [`Interpolator`] wrapper that rotates the [`Transform`] to face the
+/// direction it moved in, for any inner interpolator setting translation.
+///
+/// Unlike [`PathFacing`](super::PathFacing), which uses the tangent of a known
+/// curve, this works for arbitrary motion such as [`Settle`] overshooting
+/// back and forth or a custom interpolator.
+///
+/// # Previous position
+///
+/// Interpolators don't keep state between frames so the previous position is
+/// the translation of the transform right before the inner interpolator runs,
+/// usually the value applied in the previous frame. The direction is from
+/// there to the translation set by the inner interpolator and the transform
+/// is rotated with [`Transform::look_to`] so its forward (`-Z`) faces it.
+/// Rotation is left untouched while the transform doesn't move, so it keeps
+/// facing the last direction of motion. This means:
+/// - In the first frame, the direction is from wherever the transform was
+///   before the tween started.
+/// - Anything else moving the transform between frames is included in the
+///   direction.
+///
+/// Since this interpolator is generic, you have to
+/// [register the system](crate::tween#registering-systems) for each inner
+/// interpolator you use.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::math::cubic_splines::{CubicCardinalSpline, CubicGenerator};
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{face_motion, translation_path};
+/// use bevy_tween::prelude::*;
+///
+/// let curve = CubicCardinalSpline::new_catmull_rom([
+///     Vec3::ZERO,
+///     Vec3::new(5., 0., -5.),
+///     Vec3::new(10., 0., 0.),
+/// ])
+/// .to_curve()
+/// .unwrap();
+/// let interpolator = face_motion(translation_path(curve));
+///
+/// let mut transform = Transform::default();
+/// for i in 1..=20 {
+///     let previous = transform.translation;
+///     interpolator.interpolate(&mut transform, i as f32 / 20.);
+///     let motion = (transform.translation - previous).normalize();
+///     assert!(transform.forward().dot(motion) > 0.999);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct FaceMotion<I> {
+    /// Inner interpolator
+    pub inner: I,
+    /// Up direction used to orient toward the direction of motion
+    pub up: Dir3,
+}
+impl<I> FaceMotion<I> {
+    /// Orient with the provided up direction instead of [`Dir3::Y`]
+    pub fn with_up(mut self, up: Dir3) -> Self {
+        self.up = up;
+        self
+    }
+}
+impl<I> Interpolator for FaceMotion<I>
+where
+    I: Interpolator<Item = Transform>,
+{
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let previous = item.translation;
+        self.inner.interpolate(item, value);
+        let Ok(direction) = Dir3::new(item.translation - previous) else {
+            return;
+        };
+        item.look_to(direction, self.up);
+    }
+}
+
+/// Constructor for [`FaceMotion`] using [`Dir3::Y`] as up
+pub fn face_motion<I>(inner: I) -> FaceMotion<I>
+where
+    I: Interpolator<Item = Transform>,
+{
+    FaceMotion { inner, up: Dir3::Y }
+}
+
+/// Closure called by [`WithTransformCallback`] with the previous and the
+/// current [`Transform`]
+pub type TransformCallback =
+    Box<dyn Fn(&Transform, &mut Transform) + Send + Sync + 'static>;
+
+/// [`Interpolator`] wrapper that calls a closure after the inner [`Transform`]
+/// interpolator is applied, for effects derived from the motion such as tilting
+/// based on velocity.
+///
+/// The closure is called every time this interpolator is applied with the
+/// transform as it was before the inner interpolator ran, usually the value
+/// from the previous frame, and the mutable transform after the inner
+/// interpolator ran. Anything set on the current transform by the closure is
+/// kept.
+///
+/// Since this interpolator is generic, you have to
+/// [register the system](crate::tween#registering-systems) for each inner
+/// interpolator you use.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{translation, with_transform_callback};
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = with_transform_callback(
+///     translation(Vec3::ZERO, Vec3::X * 10.),
+///     |previous, current| {
+///         // Tilt toward the direction of movement
+///         let velocity = current.translation.x - previous.translation.x;
+///         current.rotation = Quat::from_rotation_z(-velocity * 0.1);
+///     },
+/// );
+///
+/// let mut transform = Transform::default();
+/// interpolator.interpolate(&mut transform, 0.);
+/// assert_eq!(transform.rotation, Quat::IDENTITY);
+///
+/// interpolator.interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::X * 5.);
+/// assert!(transform
+///     .rotation
+///     .abs_diff_eq(Quat::from_rotation_z(-0.5), 1e-6));
+/// ```
+pub struct WithTransformCallback<I> {
+    /// Inner interpolator
+    pub inner: I,
+    /// Closure called after the inner interpolator
+    pub callback: TransformCallback,
+}
+impl<I> Interpolator for WithTransformCallback<I>
+where
+    I: Interpolator<Item = Transform>,
+{
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let previous = *item;
+        self.inner.interpolate(item, value);
+        (self.callback)(&previous, item);
+    }
+}
+
+/// Constructor for [`WithTransformCallback`]
+pub fn with_transform_callback<I, F>(
+    inner: I,
+    callback: F,
+) -> WithTransformCallback<I>
+where
+    I: Interpolator<Item = Transform>,
+    F: Fn(&Transform, &mut Transform) + Send + Sync + 'static,
+{
+    WithTransformCallback {
+        inner,
+        callback: Box::new(callback),
+    }
+}
+
+/// [`Interpolator`] crossfading a whole [`Transform`] between two poses.
+///
+/// Translation and scale are linearly interpolated and rotation uses
+/// [`Quat::slerp`]. The tweened value is the crossfade weight from `from` to
+/// `to`.
+///
+/// Use this instead of separate [`Translation`], [`Rotation`], and [`Scale`]
+/// tweens to animate the whole transform with a single tween entity, for
+/// example, with the [`transform`] constructor. See the `pose_crossfade`
+/// example.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::pose_crossfade;
+/// use bevy_tween::prelude::*;
+///
+/// let idle = Transform::from_xyz(0., 0., 0.);
+/// let alert = Transform::from_xyz(0., 2., 0.)
+///     .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2))
+///     .with_scale(Vec3::splat(3.));
+///
+/// let mut transform = Transform::default();
+/// pose_crossfade(idle, alert).interpolate(&mut transform, 0.5);
+///
+/// assert_eq!(transform.translation, Vec3::new(0., 1., 0.));
+/// assert_eq!(transform.scale, Vec3::splat(2.));
+/// assert!(transform
+///     .rotation
+///     .abs_diff_eq(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4), 1e-6));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct PoseCrossfade {
+    #[allow(missing_docs)]
+    pub from: Transform,
+    #[allow(missing_docs)]
+    pub to: Transform,
+}
+impl Interpolator for PoseCrossfade {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.translation =
+            self.from.translation.lerp(self.to.translation, value);
+        item.rotation = self.from.rotation.slerp(self.to.rotation, value);
+        item.scale = self.from.scale.lerp(self.to.scale, value);
+    }
+}
+
+/// Constructor for [`PoseCrossfade`]
+pub fn pose_crossfade(from: Transform, to: Transform) -> PoseCrossfade {
+    PoseCrossfade { from, to }
+}
+
+/// Constructor for [`PoseCrossfade`] that's relative to previous value using currying.
+pub fn pose_crossfade_to(
+    to: Transform,
+) -> impl Fn(&mut Transform) -> PoseCrossfade {
+    move |state| {
+        let from = *state;
+        *state = to;
+        pose_crossfade(from, to)
+    }
+}
+
+/// Constructor for [`PoseCrossfade`] tweening a whole [`Transform`], the
+/// same as [`pose_crossfade`]
+pub fn transform(start: Transform, end: Transform) -> PoseCrossfade {
+    pose_crossfade(start, end)
+}
+
+/// Constructor for [`PoseCrossfade`] that's relative to previous value using
+/// currying, the same as [`pose_crossfade_to`]
+pub fn transform_to(to: Transform) -> impl Fn(&mut Transform) -> PoseCrossfade {
+    pose_crossfade_to(to)
+}
+
+/// [`Interpolator`] for a whole [`Transform`] where translation, rotation,
+/// and scale each change within their own phase of the tween, for example, to
+/// turn toward a direction and then move there in a single tween.
+///
+/// # Phases
+///
+/// Each part has a [`TransformPhase`] window from `start` to `end` in the
+/// tweened value. The part stays at `from` before its window, changes over
+/// the window, and stays at `to` after it. Windows can overlap or leave gaps,
+/// and a zero-length window switches at its start. Every window covers the
+/// whole tween by default, which is the same as [`PoseCrossfade`].
+///
+/// The tween's ease is applied to the whole tween before the value is mapped
+/// to each window so the windows are in eased value, not in time. Use
+/// [`EaseKind::Linear`](crate::interpolation::EaseKind::Linear) to place the
+/// windows in time. Values outside of 0–1 are clamped within each window so
+/// this doesn't extrapolate.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::phased_transform;
+/// use bevy_tween::prelude::*;
+///
+/// let from = Transform::default();
+/// let to = Transform::from_xyz(10., 0., 0.)
+///     .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+/// // Rotate in the first half and move in the second half
+/// let interpolator = phased_transform(from, to)
+///     .with_rotation_phase(0., 0.5)
+///     .with_translation_phase(0.5, 1.);
+///
+/// let mut transform = Transform::default();
+/// interpolator.interpolate(&mut transform, 0.25);
+/// assert!(transform
+///     .rotation
+///     .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4), 1e-6));
+/// assert_eq!(transform.translation, Vec3::ZERO);
+///
+/// // Rotation completes before translation starts
+/// interpolator.interpolate(&mut transform, 0.5);
+/// assert!(transform.rotation.abs_diff_eq(to.rotation, 1e-6));
+/// assert_eq!(transform.translation, Vec3::ZERO);
+///
+/// interpolator.interpolate(&mut transform, 0.75);
+/// assert_eq!(transform.translation, Vec3::new(5., 0., 0.));
+/// assert!(transform.rotation.abs_diff_eq(to.rotation, 1e-6));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct PhasedTransform {
+    #[allow(missing_docs)]
+    pub from: Transform,
+    #[allow(missing_docs)]
+    pub to: Transform,
+    /// Window of the tweened value where translation changes
+    pub translation_phase: TransformPhase,
+    /// Window of the tweened value where rotation changes
+    pub rotation_phase: TransformPhase,
+    /// Window of the tweened value where scale changes
+    pub scale_phase: TransformPhase,
+}
+
+impl PhasedTransform {
+    /// Change translation from `start` to `end` of the tweened value
+    pub fn with_translation_phase(mut self, start: f32, end: f32) -> Self {
+        self.translation_phase = TransformPhase::new(start, end);
+        self
+    }
+
+    /// Change rotation from `start` to `end` of the tweened value
+    pub fn with_rotation_phase(mut self, start: f32, end: f32) -> Self {
+        self.rotation_phase = TransformPhase::new(start, end);
+        self
+    }
+
+    /// Change scale from `start` to `end` of the tweened value
+    pub fn with_scale_phase(mut self, start: f32, end: f32) -> Self {
+        self.scale_phase = TransformPhase::new(start, end);
+        self
+    }
+}
+
+impl Interpolator for PhasedTransform {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.translation = self
+            .from
+            .translation
+            .lerp(self.to.translation, self.translation_phase.local(value));
+        item.rotation = self
+            .from
+            .rotation
+            .slerp(self.to.rotation, self.rotation_phase.local(value));
+        item.scale = self
+            .from
+            .scale
+            .lerp(self.to.scale, self.scale_phase.local(value));
+    }
+}
+
+/// Window of the tweened value used by [`PhasedTransform`]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct TransformPhase {
+    /// Tweened value where the change starts
+    pub start: f32,
+    /// Tweened value where the change ends
+    pub end: f32,
+}
+
+impl TransformPhase {
+    /// Create a new [`TransformPhase`]
+    pub fn new(start: f32, end: f32) -> Self {
+        TransformPhase { start, end }
+    }
+
+    /// Map the tweened value to the progress within this window, clamped
+    /// between 0–1
+    pub fn local(&self, value: f32) -> f32 {
+        if self.end <= self.start {
+            return if value >= self.start { 1. } else { 0. };
+        }
+        ((value - self.start) / (self.end - self.start)).clamp(0., 1.)
+    }
+}
+
+impl Default for TransformPhase {
+    fn default() -> Self {
+        TransformPhase { start: 0., end: 1. }
+    }
+}
+
+/// Constructor for [`PhasedTransform`] with every part changing over the
+/// whole tween
+pub fn phased_transform(from: Transform, to: Transform) -> PhasedTransform {
+    PhasedTransform {
+        from,
+        to,
+        ..Default::default()
+    }
+}
+
+/// Constructor for [`PhasedTransform`] that's relative to previous value using currying.
+pub fn phased_transform_to(
+    to: Transform,
+) -> impl Fn(&mut Transform) -> PhasedTransform {
+    move |state| {
+        let from = *state;
+        *state = to;
+        phased_transform(from, to)
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation that settles into `end`
+/// with diminishing overshoots.
+///
+/// The translation oscillates around `end` `frequency` times over the tween
+/// with an amplitude that decays exponentially by `decay`. Unlike a spring
+/// ease, the oscillation is produced by the interpolator itself so it can be
+/// combined with any [`EaseKind`](crate::interpolation::EaseKind), usually
+/// [`EaseKind::Linear`](crate::interpolation::EaseKind::Linear).
+///
+/// The envelope is normalized so the translation is always exactly `start` at
+/// value `0.` and exactly `end` at value `1.` regardless of `decay` and
+/// `frequency`. A whole number `frequency` results in that many overshoots.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::Settle;
+/// use bevy_tween::prelude::*;
+///
+/// let settle = Settle {
+///     start: Vec3::ZERO,
+///     end: Vec3::X * 10.,
+///     decay: 3.,
+///     frequency: 3.,
+/// };
+///
+/// let mut transform = Transform::default();
+/// settle.interpolate(&mut transform, 0.);
+/// assert_eq!(transform.translation, Vec3::ZERO);
+/// settle.interpolate(&mut transform, 1.);
+/// assert_eq!(transform.translation, Vec3::X * 10.);
+///
+/// // Collect the peak of every overshoot past the end
+/// let mut peaks = vec![];
+/// let mut overshooting = false;
+/// for i in 0..=1000 {
+///     settle.interpolate(&mut transform, i as f32 / 1000.);
+///     let overshoot = transform.translation.x - 10.;
+///     if overshoot > 0. {
+///         if !overshooting {
+///             peaks.push(0.);
+///         }
+///         let peak = peaks.last_mut().unwrap();
+///         *peak = f32::max(*peak, overshoot);
+///     }
+///     overshooting = overshoot > 0.;
+/// }
+///
+/// assert_eq!(peaks.len(), 3);
+/// assert!(peaks.windows(2).all(|w| w[0] > w[1]));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct Settle {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    /// Exponential decay rate of the oscillation's amplitude
+    pub decay: f32,
+    /// Number of oscillations over the tween
+    pub frequency: f32,
+}
+impl Settle {
+    fn envelope(&self, value: f32) -> f32 {
+        if self.decay.abs() <= f32::EPSILON {
+            return 1. - value;
+        }
+        let tail = (-self.decay).exp();
+        ((-self.decay * value).exp() - tail) / (1. - tail)
+    }
+}
+impl Interpolator for Settle {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let oscillation =
+            (std::f32::consts::TAU * self.frequency * value).cos();
+        item.translation = self.end
+            + (self.start - self.end) * self.envelope(value) * oscillation;
+    }
+}
+
+/// Constructor for [`Settle`] that's relative to previous value using currying.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::settle;
+/// use bevy_tween::prelude::*;
+///
+/// let mut state = Vec3::ZERO;
+/// let interpolator = settle(Vec3::Y * 5., 4., 2.)(&mut state);
+/// assert_eq!(interpolator.start, Vec3::ZERO);
+/// assert_eq!(state, Vec3::Y * 5.);
+/// ```
+pub fn settle(
+    target: Vec3,
+    decay: f32,
+    frequency: f32,
+) -> impl Fn(&mut Vec3) -> Settle {
+    move |state| {
+        let start = *state;
+        *state = target;
+        Settle {
+            start,
+            end: target,
+            decay,
+            frequency,
+        }
+    }
+}
+
+/// Axis of a [`Mirror`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum MirrorAxis {
+    /// Mirror across the YZ plane, for example, a 2D character turning around
+    #[default]
+    X,
+    /// Mirror across the XZ plane
+    Y,
+    /// Mirror across the XY plane
+    Z,
+}
+
+/// [`Interpolator`] for [`Transform`]'s scale and rotation that mirrors the
+/// transform across an axis at an instant, for example, a skeletal character
+/// turning around.
+///
+/// The transform is `scale` and `rotation` before the tweened value reaches
+/// `threshold` and mirrored from then on, the same instant-crossing as
+/// [`ZIndex`](crate::interpolate::ZIndex). Translation is left as is so the
+/// mirror plane passes through the entity's own position.
+///
+/// # Mirror math
+///
+/// Mirroring across the plane with the normal `n`, one of the parent's axes,
+/// reflects the transform's linear part `R * S` into `M * R * S` where `M` is
+/// the reflection. A reflection can't be represented by a rotation so it's
+/// split as `(M * R * M) * (M * S)`:
+///
+/// - `M * S` negates the scale along `n`.
+/// - `M * R * M` is the rotation mirrored across the plane which keeps the
+///   quaternion's vector component along `n` and negates the other two,
+///   reversing the rotation's direction in the plane. For example, a rotation
+///   of `θ` around Z becomes `-θ` when mirrored across X.
+///
+/// Children are mirrored along with the transform and mirroring twice gives
+/// back the original transform.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{mirror, MirrorAxis};
+/// use bevy_tween::prelude::*;
+///
+/// let mut state = Transform::from_rotation(Quat::from_rotation_z(0.3))
+///     .with_scale(Vec3::new(2., 1., 1.));
+/// let interpolator = mirror(MirrorAxis::X)(&mut state).with_threshold(0.5);
+///
+/// let mut transform = Transform::from_xyz(5., 0., 0.);
+/// interpolator.interpolate(&mut transform, 0.49);
+/// assert_eq!(transform.scale, Vec3::new(2., 1., 1.));
+///
+/// interpolator.interpolate(&mut transform, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(5., 0., 0.));
+/// assert_eq!(transform.scale, Vec3::new(-2., 1., 1.));
+/// assert!(transform
+///     .rotation
+///     .abs_diff_eq(Quat::from_rotation_z(-0.3), 1e-6));
+///
+/// // A point on the right side of the entity is now on the left side
+/// let point = transform.transform_point(Vec3::X) - transform.translation;
+/// let original = Transform::from_rotation(Quat::from_rotation_z(0.3))
+///     .with_scale(Vec3::new(2., 1., 1.))
+///     .transform_point(Vec3::X);
+/// assert!(point.abs_diff_eq(original * Vec3::new(-1., 1., 1.), 1e-5));
+/// assert_eq!(state.scale, transform.scale);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct Mirror {
+    /// Scale before mirroring
+    pub scale: Vec3,
+    /// Rotation before mirroring
+    pub rotation: Quat,
+    /// Axis to mirror across
+    pub axis: MirrorAxis,
+    /// Value at which the transform is mirrored
+    pub threshold: f32,
+}
+
+impl Mirror {
+    /// Set the value at which the transform is mirrored
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Scale and rotation after mirroring
+    pub fn mirrored(&self) -> (Vec3, Quat) {
+        let (scale_sign, rotation_sign) = match self.axis {
+            MirrorAxis::X => (Vec3::new(-1., 1., 1.), Vec3::new(1., -1., -1.)),
+            MirrorAxis::Y => (Vec3::new(1., -1., 1.), Vec3::new(-1., 1., -1.)),
+            MirrorAxis::Z => (Vec3::new(1., 1., -1.), Vec3::new(-1., -1., 1.)),
+        };
+        let v = self.rotation.xyz() * rotation_sign;
+        (
+            self.scale * scale_sign,
+            Quat::from_xyzw(v.x, v.y, v.z, self.rotation.w),
+        )
+    }
+}
+
+impl Interpolator for Mirror {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let (scale, rotation) = super::step(
+            (self.scale, self.rotation),
+            self.mirrored(),
+            self.threshold,
+            value,
+        );
+        item.scale = scale;
+        item.rotation = rotation;
+    }
+}
+
+/// Constructor for [`Mirror`] that's relative to previous value using
+/// currying. Mirrors at the end of the tween.
+///
+/// The state is set to the mirrored transform.
+pub fn mirror(axis: MirrorAxis) -> impl Fn(&mut Transform) -> Mirror {
+    move |state| {
+        let mirror = Mirror {
+            scale: state.scale,
+            rotation: state.rotation,
+            axis,
+            threshold: 1.,
+        };
+        (state.scale, state.rotation) = mirror.mirrored();
+        mirror
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s rotation at Z axis that keeps turning
+/// toward a moving target entity, for example, a 2D turret.
+///
+/// Every frame the tween is active, [`aim_at_system`] records the `target`'s
+/// [`GlobalTransform`] position and the frame's delta time. The rotation is
+/// then turned toward the target by at most `turn_rate` radians per second
+/// along the shortest direction, with the local X axis as the aiming
+/// direction. Rotation around other axes is discarded. The aiming entity's
+/// [`Transform`] is assumed to be in world space, that is, without a
+/// transformed parent.
+///
+/// This is a continuous behavior without a fixed endpoint so the tweened value
+/// is not used and easing has no effect. Tracking terminates when the tween's
+/// time span ends or when the target no longer exists, leaving the rotation as
+/// is.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::aim_at;
+/// use bevy_tween::prelude::*;
+///
+/// let turn_rate = std::f32::consts::PI;
+/// let dt = 0.1;
+/// let mut interpolator = aim_at(Entity::PLACEHOLDER, turn_rate);
+/// let mut turret = Transform::default();
+///
+/// let angle = |t: &Transform| {
+///     let forward = t.rotation * Vec3::X;
+///     forward.y.atan2(forward.x)
+/// };
+///
+/// // Target straight up, 90 degrees away
+/// interpolator.resolve(Some(Vec2::new(0., 10.)), dt);
+/// let mut previous = angle(&turret);
+/// for _ in 0..10 {
+///     interpolator.interpolate(&mut turret, 0.);
+///     let current = angle(&turret);
+///     assert!((current - previous).abs() <= turn_rate * dt + 1e-5);
+///     previous = current;
+/// }
+/// assert!((angle(&turret) - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+///
+/// // Target moved to the left
+/// interpolator.resolve(Some(Vec2::new(-10., 0.)), dt);
+/// interpolator.interpolate(&mut turret, 0.);
+/// assert!((angle(&turret) - (std::f32::consts::FRAC_PI_2 + turn_rate * dt)).abs() < 1e-5);
+/// for _ in 0..10 {
+///     interpolator.interpolate(&mut turret, 0.);
+/// }
+/// assert!((angle(&turret).abs() - std::f32::consts::PI).abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct AimAt {
+    /// Entity to aim at
+    pub target: Entity,
+    /// Maximum turning speed in radians per second
+    pub turn_rate: f32,
+    /// Last known position of the target in world space
+    pub target_position: Option<Vec2>,
+    /// Delta time in seconds of the current frame
+    pub delta: f32,
+}
+impl AimAt {
+    /// Create a new [`AimAt`]
+    pub fn new(target: Entity, turn_rate: f32) -> AimAt {
+        AimAt {
+            target,
+            turn_rate,
+            target_position: None,
+            delta: 0.,
+        }
+    }
+
+    /// Set the target's position and the delta time of the current frame
+    pub fn resolve(&mut self, target_position: Option<Vec2>, delta: f32) {
+        self.target_position = target_position;
+        self.delta = delta;
+    }
+}
+impl Interpolator for AimAt {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, _value: f32) {
+        let Some(target_position) = self.target_position else {
+            return;
+        };
+        let to_target = target_position - item.translation.truncate();
+        if to_target == Vec2::ZERO {
+            return;
+        }
+        let forward = item.rotation * Vec3::X;
+        let current = forward.y.atan2(forward.x);
+        let desired = to_target.y.atan2(to_target.x);
+        let difference = (desired - current + std::f32::consts::PI)
+            .rem_euclid(std::f32::consts::TAU)
+            - std::f32::consts::PI;
+        let max_step = self.turn_rate * self.delta;
+        item.rotation = Quat::from_rotation_z(
+            current + difference.clamp(-max_step, max_step),
+        );
+    }
+}
+
+/// Constructor for [`AimAt`]
+pub fn aim_at(target: Entity, turn_rate: f32) -> AimAt {
+    AimAt::new(target, turn_rate)
+}
+
+/// Record the target's position and delta time for active [`AimAt`] tweens.
+#[allow(clippy::type_complexity)]
+pub fn aim_at_system(
+    time: Res<Time>,
+    mut q_tween: Query<
+        &mut tween::ComponentTween<AimAt>,
+        (With<TimeSpanProgress>, Without<tween::SkipTween>),
+    >,
+    q_target: Query<&GlobalTransform>,
+) {
+    let delta = time.delta_secs();
+    for mut tween in &mut q_tween {
+        let target_position = q_target
+            .get(tween.interpolator.target)
+            .ok()
+            .map(|target| target.translation().truncate());
+        tween.interpolator.resolve(target_position, delta);
+    }
+}
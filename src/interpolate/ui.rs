@@ -1,3 +1,4 @@
+use crate::interpolate::step;
 use crate::prelude::Interpolator;
 use bevy::prelude::*;
 
@@ -66,3 +67,265 @@ pub fn border_color_to(to: Color) -> impl Fn(&mut Color) -> BorderColor {
         border_color(start, end)
     }
 }
+
+/// [`Interpolator`] for Bevy's [`ZIndex`](bevy::prelude::ZIndex) used in UIs.
+///
+/// Z-index is an integer so it is not interpolated. The index is `start`
+/// before the tweened value reaches `threshold` and `end` from then on, so
+/// a panel can be brought to the front at a specific point of a transition.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::z_index;
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = z_index(0, 10).with_threshold(0.5);
+///
+/// let mut item = ZIndex(0);
+/// interpolator.interpolate(&mut item, 0.49);
+/// assert_eq!(item.0, 0);
+/// interpolator.interpolate(&mut item, 0.5);
+/// assert_eq!(item.0, 10);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct ZIndex {
+    #[allow(missing_docs)]
+    pub start: i32,
+    #[allow(missing_docs)]
+    pub end: i32,
+    /// Value at which the index changes from `start` to `end`
+    pub threshold: f32,
+}
+
+impl ZIndex {
+    /// Set the value at which the index changes from `start` to `end`
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl Interpolator for ZIndex {
+    type Item = bevy::prelude::ZIndex;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.0 = step(self.start, self.end, self.threshold, value);
+    }
+}
+
+/// Constructor for [`ZIndex`](crate::interpolate::ZIndex) that changes the
+/// index at the end of the tween.
+pub fn z_index(start: i32, end: i32) -> ZIndex {
+    ZIndex {
+        start,
+        end,
+        threshold: 1.,
+    }
+}
+
+/// Constructor for [`ZIndex`](crate::interpolate::ZIndex) that's relative to previous value using currying.
+pub fn z_index_to(to: i32) -> impl Fn(&mut i32) -> ZIndex {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        z_index(start, end)
+    }
+}
+
+/// [`Interpolator`] for Bevy's [`GlobalZIndex`](bevy::prelude::GlobalZIndex) used in UIs.
+///
+/// Like [`ZIndex`](crate::interpolate::ZIndex), the index is `start` before
+/// the tweened value reaches `threshold` and `end` from then on.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct GlobalZIndex {
+    #[allow(missing_docs)]
+    pub start: i32,
+    #[allow(missing_docs)]
+    pub end: i32,
+    /// Value at which the index changes from `start` to `end`
+    pub threshold: f32,
+}
+
+impl GlobalZIndex {
+    /// Set the value at which the index changes from `start` to `end`
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl Interpolator for GlobalZIndex {
+    type Item = bevy::prelude::GlobalZIndex;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.0 = step(self.start, self.end, self.threshold, value);
+    }
+}
+
+/// Constructor for [`GlobalZIndex`](crate::interpolate::GlobalZIndex) that
+/// changes the index at the end of the tween.
+pub fn global_z_index(start: i32, end: i32) -> GlobalZIndex {
+    GlobalZIndex {
+        start,
+        end,
+        threshold: 1.,
+    }
+}
+
+/// Constructor for [`GlobalZIndex`](crate::interpolate::GlobalZIndex) that's relative to previous value using currying.
+pub fn global_z_index_to(to: i32) -> impl Fn(&mut i32) -> GlobalZIndex {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        global_z_index(start, end)
+    }
+}
+
+/// Interpolate between two [`Val`]s of the same unit or `None` if the units
+/// differ or can't be interpolated such as [`Val::Auto`].
+fn lerp_val(start: Val, end: Val, value: f32) -> Option<Val> {
+    let lerp = |start: f32, end: f32| start.lerp(end, value);
+    match (start, end) {
+        (Val::Px(start), Val::Px(end)) => Some(Val::Px(lerp(start, end))),
+        (Val::Percent(start), Val::Percent(end)) => {
+            Some(Val::Percent(lerp(start, end)))
+        }
+        (Val::Vw(start), Val::Vw(end)) => Some(Val::Vw(lerp(start, end))),
+        (Val::Vh(start), Val::Vh(end)) => Some(Val::Vh(lerp(start, end))),
+        (Val::VMin(start), Val::VMin(end)) => Some(Val::VMin(lerp(start, end))),
+        (Val::VMax(start), Val::VMax(end)) => Some(Val::VMax(lerp(start, end))),
+        _ => None,
+    }
+}
+
+/// Step to `end` at the end of the tween if [`Val`]s can't be interpolated
+fn lerp_val_or_step(start: Val, end: Val, value: f32) -> Val {
+    lerp_val(start, end, value).unwrap_or_else(|| step(start, end, 1., value))
+}
+
+fn assert_same_unit(constructor: &str, start: Val, end: Val) {
+    assert!(
+        lerp_val(start, end, 0.).is_some(),
+        "`{constructor}` can't interpolate from {start:?} to {end:?}. \
+        Both values must have the same unit other than `Val::Auto`, \
+        resolve them to the same unit first."
+    );
+}
+
+/// [`Interpolator`] for the width of Bevy's [`Node`] used in UIs.
+///
+/// # Units
+///
+/// Only [`Val`]s of the same unit are interpolated, for example,
+/// [`Val::Px`] to [`Val::Px`] or [`Val::Percent`] to [`Val::Percent`].
+/// Converting between units depends on the parent's size which isn't known
+/// to the interpolator, so [`node_width`] and [`node_width_to`] panic on
+/// different units. If different units are set directly, the width stays at
+/// `start` and changes to `end` at the end of the tween.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::node_width;
+/// use bevy_tween::prelude::*;
+///
+/// let interpolator = node_width(Val::Px(0.), Val::Px(300.));
+///
+/// let mut node = Node::default();
+/// interpolator.interpolate(&mut node, 0.5);
+/// assert_eq!(node.width, Val::Px(150.));
+/// ```
+///
+/// ```should_panic
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::node_width;
+///
+/// node_width(Val::Px(0.), Val::Percent(50.));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct NodeWidth {
+    #[allow(missing_docs)]
+    pub start: Val,
+    #[allow(missing_docs)]
+    pub end: Val,
+}
+
+impl Interpolator for NodeWidth {
+    type Item = Node;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.width = lerp_val_or_step(self.start, self.end, value);
+    }
+}
+
+/// Constructor for [`NodeWidth`]
+///
+/// # Panics
+///
+/// Panics if `start` and `end` have [different units](NodeWidth#units).
+pub fn node_width(start: Val, end: Val) -> NodeWidth {
+    assert_same_unit("node_width", start, end);
+    NodeWidth { start, end }
+}
+
+/// Constructor for [`NodeWidth`] that's relative to previous value using currying.
+///
+/// # Panics
+///
+/// Panics if the previous value and `to` have [different units](NodeWidth#units).
+pub fn node_width_to(to: Val) -> impl Fn(&mut Val) -> NodeWidth {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        node_width(start, end)
+    }
+}
+
+/// [`Interpolator`] for the height of Bevy's [`Node`] used in UIs.
+///
+/// Units are handled like [`NodeWidth`], see [units](NodeWidth#units).
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct NodeHeight {
+    #[allow(missing_docs)]
+    pub start: Val,
+    #[allow(missing_docs)]
+    pub end: Val,
+}
+
+impl Interpolator for NodeHeight {
+    type Item = Node;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.height = lerp_val_or_step(self.start, self.end, value);
+    }
+}
+
+/// Constructor for [`NodeHeight`]
+///
+/// # Panics
+///
+/// Panics if `start` and `end` have [different units](NodeWidth#units).
+pub fn node_height(start: Val, end: Val) -> NodeHeight {
+    assert_same_unit("node_height", start, end);
+    NodeHeight { start, end }
+}
+
+/// Constructor for [`NodeHeight`] that's relative to previous value using currying.
+///
+/// # Panics
+///
+/// Panics if the previous value and `to` have [different units](NodeWidth#units).
+pub fn node_height_to(to: Val) -> impl Fn(&mut Val) -> NodeHeight {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        node_height(start, end)
+    }
+}
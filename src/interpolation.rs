@@ -5,14 +5,26 @@
 //! **Built-in interpolations**:
 //! - [`EaseKind`]
 //! - [`EaseClosure`]
+//! - [`EaseCurve`]
+//! - [`DefaultEase`]
 //!
 //! **Systems**:
 //! - [`sample_interpolations_system`]
+//! - [`sample_default_ease_system`]
 
 use bevy::prelude::*;
 
-use crate::{tween::TweenInterpolationValue, TweenSystemSet};
-use bevy_time_runner::TimeSpanProgress;
+use std::time::Duration;
+
+use crate::{
+    diagnostics::TweenCounters,
+    tween::{
+        EaseFirstPlayOnly, PrewarmTween, RetainFinalValue, ReverseTween,
+        TweenInterpolationValue, TweenSpeed,
+    },
+    TweenSystemSet,
+};
+use bevy_time_runner::{TimeSpan, TimeSpanProgress};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,10 +57,14 @@ impl Plugin for EaseKindPlugin {
             .expect("`TweenAppResource` to be is inserted to world");
         app.add_systems(
             app_resource.schedule,
-            sample_interpolations_system::<EaseKind>
+            (
+                sample_interpolations_system::<EaseKind>,
+                sample_default_ease_system,
+            )
                 .in_set(TweenSystemSet::UpdateInterpolationValue),
         )
-        .register_type::<EaseKind>();
+        .register_type::<EaseKind>()
+        .register_type::<DefaultEase>();
     }
 }
 
@@ -58,7 +74,7 @@ impl Plugin for EaseKindPlugin {
 /// This enum is copied directly from [`EaseFunction`] and will be deprecated in future version.
 ///
 /// [unit interval]: `Interval::UNIT`
-#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
 #[reflect(Component)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum EaseKind {
@@ -140,6 +156,159 @@ pub enum EaseKind {
 
     /// `f(omega,t) = 1 - (1 - t)²(2sin(omega * t) / omega + cos(omega * t))`, parametrized by `omega`
     Elastic(f32),
+
+    /// Cubic Bézier from `(0, 0)` to `(1, 1)` with the control points
+    /// `(x1, y1)` and `(x2, y2)`, the same as CSS's
+    /// `cubic-bezier(x1, y1, x2, y2)` so values can be pasted from design tools.
+    ///
+    /// The curve is solved for `x = t` with Newton-Raphson, falling back to
+    /// bisection, then `y` is returned. `x1` and `x2` are clamped to 0–1 so
+    /// the curve stays a function of `t`, while `y1` and `y2` may be outside
+    /// of 0–1 to overshoot.
+    ///
+    /// ```
+    /// use bevy_tween::prelude::*;
+    ///
+    /// // Reference: bisect the curve in `f64`
+    /// fn reference(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64 {
+    ///     let bezier = |a: f64, b: f64, s: f64| {
+    ///         3. * (1. - s).powi(2) * s * a + 3. * (1. - s) * s.powi(2) * b + s.powi(3)
+    ///     };
+    ///     let (mut low, mut high) = (0., 1.);
+    ///     for _ in 0..100 {
+    ///         let mid = (low + high) / 2.;
+    ///         if bezier(x1, x2, mid) < t {
+    ///             low = mid;
+    ///         } else {
+    ///             high = mid;
+    ///         }
+    ///     }
+    ///     bezier(y1, y2, (low + high) / 2.)
+    /// }
+    ///
+    /// for (x1, y1, x2, y2) in [
+    ///     (0.25, 0.1, 0.25, 1.),
+    ///     (0.42, 0., 0.58, 1.),
+    ///     (0.34, 1.56, 0.64, 1.),
+    ///     (0.68, -0.6, 0.32, 1.6),
+    ///     (0., 0., 1., 1.),
+    /// ] {
+    ///     let ease = EaseKind::CubicBezier { x1, y1, x2, y2 };
+    ///     for i in 0..=20 {
+    ///         let t = i as f32 / 20.;
+    ///         let expected =
+    ///             reference(x1 as f64, y1 as f64, x2 as f64, y2 as f64, t as f64);
+    ///         assert!((ease.sample(t) as f64 - expected).abs() < 1e-4);
+    ///     }
+    ///     assert_eq!(ease.sample(0.), 0.);
+    ///     assert_eq!(ease.sample(1.), 1.);
+    /// }
+    /// ```
+    CubicBezier {
+        /// X of the first control point
+        x1: f32,
+        /// Y of the first control point
+        y1: f32,
+        /// X of the second control point
+        x2: f32,
+        /// Y of the second control point
+        y2: f32,
+    },
+
+    /// Smooth value noise of `t` with `octaves` layers of detail, deterministic for the same `seed`.
+    ///
+    /// Blends from `t` to the noise and back by `sin(t * π)` so the output
+    /// always starts at `0`, ends at `1`, and stays between 0–1.
+    ///
+    /// ```
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let noise = EaseKind::Noise { seed: 42, octaves: 3 };
+    /// for i in 0..=100 {
+    ///     let t = i as f32 / 100.;
+    ///     let v = noise.sample(t);
+    ///     assert_eq!(v, EaseKind::Noise { seed: 42, octaves: 3 }.sample(t));
+    ///     assert!((0.0..=1.0).contains(&v));
+    /// }
+    /// assert_eq!(noise.sample(0.), 0.);
+    /// assert_eq!(noise.sample(1.), 1.);
+    /// ```
+    Noise {
+        /// Seed of the noise
+        seed: u64,
+        /// Number of noise layers. Each layer doubles the frequency and halves the amplitude.
+        octaves: u8,
+    },
+    /// Piecewise ease chaining different eases across sub-ranges of `t`.
+    ///
+    /// Each segment is `(end, ease)` where `end` is the point where the
+    /// segment ends. A segment starts at the previous segment's end, or `0` for
+    /// the first one. Within a segment, `t` is mapped to `[0, 1]` for its ease
+    /// and the output is rescaled to the same sub-range so the chain is
+    /// continuous as long as every ease starts at `0` and ends at `1`.
+    ///
+    /// Use [`EaseKind::chain`] to validate the segments.
+    ///
+    /// ```
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let chain = EaseKind::chain([
+    ///     (0.3, EaseKind::QuadraticIn),
+    ///     (0.7, EaseKind::Linear),
+    ///     (1.0, EaseKind::QuadraticOut),
+    /// ])
+    /// .unwrap();
+    ///
+    /// for split in [0.3, 0.7] {
+    ///     let before = chain.sample(split - 1e-4);
+    ///     let at = chain.sample(split);
+    ///     let after = chain.sample(split + 1e-4);
+    ///     assert!((at - split).abs() < 1e-6);
+    ///     assert!((before - at).abs() < 1e-3);
+    ///     assert!((after - at).abs() < 1e-3);
+    /// }
+    /// assert_eq!(chain.sample(0.), 0.);
+    /// assert_eq!(chain.sample(1.), 1.);
+    /// ```
+    Chain(Vec<(f32, EaseKind)>),
+    /// Sum of the sampled eases, clamped to 0–1 if `clamp` is `true`.
+    ///
+    /// Returns `t` if there's no ease. Use [`EaseKind::add`] or
+    /// [`EaseKind::clamped`] to construct.
+    ///
+    /// ```
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let sum = EaseKind::add(EaseKind::Linear, EaseKind::QuadraticIn);
+    /// assert_eq!(sum.sample(0.5), 0.75);
+    /// assert_eq!(sum.sample(1.), 2.);
+    ///
+    /// let clamped = sum.clamped();
+    /// assert_eq!(clamped.sample(0.5), 0.75);
+    /// assert_eq!(clamped.sample(1.), 1.);
+    ///
+    /// let back = EaseKind::BackIn.clamped();
+    /// assert_eq!(back.sample(0.1), 0.);
+    /// ```
+    Add {
+        /// Eases to sum
+        eases: Vec<EaseKind>,
+        /// Clamp the output to 0–1
+        clamp: bool,
+    },
+    /// Product of the sampled eases.
+    ///
+    /// Returns `t` if there's no ease. Use [`EaseKind::mul`] to construct.
+    ///
+    /// ```
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let product = EaseKind::mul(EaseKind::Linear, EaseKind::QuadraticOut);
+    /// assert_eq!(product.sample(0.5), 0.5 * 0.75);
+    /// assert_eq!(product.sample(0.), 0.);
+    /// assert_eq!(product.sample(1.), 1.);
+    /// ```
+    Mul(Vec<EaseKind>),
 }
 
 impl EaseKind {
@@ -183,6 +352,158 @@ impl EaseKind {
                 easing_functions::steps(*num_steps, t)
             }
             EaseKind::Elastic(omega) => easing_functions::elastic(*omega, t),
+            EaseKind::CubicBezier { x1, y1, x2, y2 } => {
+                easing_functions::cubic_bezier(*x1, *y1, *x2, *y2, t)
+            }
+            EaseKind::Noise { seed, octaves } => {
+                easing_functions::noise(*seed, *octaves, t)
+            }
+            EaseKind::Chain(segments) => {
+                let mut start = 0.;
+                for (end, ease) in segments {
+                    if t <= *end {
+                        let width = end - start;
+                        if width <= 0. {
+                            return *end;
+                        }
+                        let local = ((t - start) / width).clamp(0., 1.);
+                        return ease.sample(local).mul_add(width, start);
+                    }
+                    start = *end;
+                }
+                t
+            }
+            EaseKind::Add { eases, clamp } => {
+                if eases.is_empty() {
+                    return t;
+                }
+                let sum = eases.iter().map(|ease| ease.sample(t)).sum::<f32>();
+                if *clamp {
+                    sum.clamp(0., 1.)
+                } else {
+                    sum
+                }
+            }
+            EaseKind::Mul(eases) => {
+                if eases.is_empty() {
+                    return t;
+                }
+                eases.iter().map(|ease| ease.sample(t)).product()
+            }
+        }
+    }
+
+    /// Mix `seed` into the seed of every [`EaseKind::Noise`] in this ease,
+    /// including the ones nested in [`EaseKind::Chain`], [`EaseKind::Add`],
+    /// and [`EaseKind::Mul`].
+    pub(crate) fn reseed_noise(&mut self, seed: u64) {
+        match self {
+            EaseKind::Noise {
+                seed: noise_seed, ..
+            } => *noise_seed ^= seed,
+            EaseKind::Chain(segments) => segments
+                .iter_mut()
+                .for_each(|(_, ease)| ease.reseed_noise(seed)),
+            EaseKind::Add { eases, .. } | EaseKind::Mul(eases) => {
+                eases.iter_mut().for_each(|ease| ease.reseed_noise(seed))
+            }
+            _ => {}
+        }
+    }
+
+    /// Create an [`EaseKind::Add`] summing two eases without clamping.
+    /// Nested sums are flattened.
+    pub fn add(a: EaseKind, b: EaseKind) -> EaseKind {
+        let mut eases = Vec::with_capacity(2);
+        for ease in [a, b] {
+            match ease {
+                EaseKind::Add {
+                    eases: inner,
+                    clamp: false,
+                } => eases.extend(inner),
+                ease => eases.push(ease),
+            }
+        }
+        EaseKind::Add {
+            eases,
+            clamp: false,
+        }
+    }
+
+    /// Create an [`EaseKind::Mul`] multiplying two eases.
+    /// Nested products are flattened.
+    pub fn mul(a: EaseKind, b: EaseKind) -> EaseKind {
+        let mut eases = Vec::with_capacity(2);
+        for ease in [a, b] {
+            match ease {
+                EaseKind::Mul(inner) => eases.extend(inner),
+                ease => eases.push(ease),
+            }
+        }
+        EaseKind::Mul(eases)
+    }
+
+    /// Clamp the output of this ease to 0–1 using [`EaseKind::Add`].
+    pub fn clamped(self) -> EaseKind {
+        match self {
+            EaseKind::Add { eases, .. } => EaseKind::Add { eases, clamp: true },
+            ease => EaseKind::Add {
+                eases: vec![ease],
+                clamp: true,
+            },
+        }
+    }
+
+    /// Create a validated [`EaseKind::Chain`].
+    ///
+    /// Segment ends must be strictly increasing within `(0, 1]` and the last
+    /// one must be `1`.
+    pub fn chain(
+        segments: impl Into<Vec<(f32, EaseKind)>>,
+    ) -> Result<EaseKind, EaseChainError> {
+        let segments = segments.into();
+        let Some((last, _)) = segments.last() else {
+            return Err(EaseChainError::Empty);
+        };
+        let mut start = 0.;
+        for (index, (end, _)) in segments.iter().enumerate() {
+            if !(*end > start && *end <= 1.) {
+                return Err(EaseChainError::NotIncreasing(index));
+            }
+            start = *end;
+        }
+        if *last != 1. {
+            return Err(EaseChainError::NotEndingAtOne);
+        }
+        Ok(EaseKind::Chain(segments))
+    }
+}
+
+/// Error from [`EaseKind::chain`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EaseChainError {
+    /// There's no segment
+    Empty,
+    /// The segment at this index doesn't end after the previous one or ends
+    /// outside of `(0, 1]`
+    NotIncreasing(usize),
+    /// The last segment doesn't end at `1`
+    NotEndingAtOne,
+}
+
+impl core::error::Error for EaseChainError {}
+
+impl core::fmt::Display for EaseChainError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "The chain has no segment"),
+            Self::NotIncreasing(index) => write!(
+                f,
+                "The segment {index} doesn't end after the previous segment within (0, 1]"
+            ),
+            Self::NotEndingAtOne => {
+                write!(f, "The last segment doesn't end at 1")
+            }
         }
     }
 }
@@ -233,6 +554,22 @@ impl From<EaseFunction> for EaseKind {
     }
 }
 
+/// Fallback [`EaseKind`] for a tween without its own interpolation, inserted
+/// by [`with_ease_per_segment`](crate::combinator::with_ease_per_segment).
+///
+/// [`sample_default_ease_system`] only samples this for entities without
+/// [`EaseKind`], [`EaseClosure`], or [`EaseCurve`] so an interpolation on the
+/// tween itself always takes precedence.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct DefaultEase(pub EaseKind);
+
+impl Interpolation for DefaultEase {
+    fn sample(&self, v: f32) -> f32 {
+        self.0.sample(v)
+    }
+}
+
 /// Plugin for [`EaseClosure`]. In case you want to use custom an ease
 /// function. Since most people likely wouldn't use this type, this plugin is
 /// not with [`DefaultTweenPlugins`] to reduce unused system.
@@ -283,37 +620,363 @@ impl Interpolation for EaseClosure {
     }
 }
 
+/// Plugin for [`EaseCurve`]. In case you want to use Bevy's [`Curve`] as an
+/// ease function. This plugin is not with [`DefaultTweenPlugins`] to reduce
+/// unused system.
+///
+/// [`DefaultTweenPlugins`]: crate::DefaultTweenPlugins
+pub struct EaseCurvePlugin;
+impl Plugin for EaseCurvePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseCurve>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        );
+    }
+}
+
+/// Use any Bevy [`Curve<f32>`](Curve) as an easing function.
+///
+/// Progress of the tween is mapped from `0..=1` to the curve's domain if it's
+/// bounded, otherwise it's sampled as is. Samples outside of the domain are
+/// clamped.
+///
+/// Bevy's curves are available since Bevy 0.15 with the `curve` feature of
+/// `bevy_math` which is enabled by default. See also
+/// [`TranslationCurve`](crate::interpolate::TranslationCurve) to use a curve
+/// as the tweened value.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolation::{EaseCurve, Interpolation};
+///
+/// let ease = EaseCurve::new(EasingCurve::new(0., 1., EaseFunction::QuadraticIn));
+/// assert_eq!(ease.sample(0.5), 0.25);
+/// ```
+#[derive(Component)]
+pub struct EaseCurve(pub Box<dyn Curve<f32> + Send + Sync + 'static>);
+
+impl EaseCurve {
+    /// Create new [`EaseCurve`]
+    pub fn new<C: Curve<f32> + Send + Sync + 'static>(curve: C) -> EaseCurve {
+        EaseCurve(Box::new(curve))
+    }
+}
+
+impl Interpolation for EaseCurve {
+    fn sample(&self, v: f32) -> f32 {
+        self.0.sample_clamped(curve_parameter(&*self.0, v))
+    }
+}
+
+/// Map `value` in `0..=1` to the curve's domain if it's bounded, otherwise
+/// use the value as is.
+pub(crate) fn curve_parameter<T>(
+    curve: &(impl Curve<T> + ?Sized),
+    value: f32,
+) -> f32 {
+    let domain = curve.domain();
+    if domain.is_bounded() {
+        domain.start() + value * domain.length()
+    } else {
+        value
+    }
+}
+
 /// This system will automatically sample in each entities with a
 /// [`TimeSpanProgress`] component then insert [`TweenInterpolationValue`].
 /// Remove [`TweenInterpolationValue`] if [`TimeSpanProgress`] is removed.
+/// An existing [`TweenInterpolationValue`] is only marked changed when the
+/// sampled value is different.
+///
+/// Entities with [`PrewarmTween`] that doesn't have [`TimeSpanProgress`] yet
+/// will be sampled at `0.` if their [`TimeSpan`] starts at zero.
+///
+/// Entities with [`ReverseTween`] will be sampled at `1. - progress`.
+///
+/// Entities with [`TweenSpeed`] will be sampled at `progress * speed` clamped
+/// to 0–1, before reversing.
+///
+/// Entities with [`EaseFirstPlayOnly`] that have already played will be
+/// sampled at `1.`.
+///
+/// Entities with [`RetainFinalValue`] keep their [`TweenInterpolationValue`]
+/// when [`TimeSpanProgress`] is removed.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+/// use bevy_tween::interpolation::sample_interpolations_system;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::{EaseFirstPlayOnly, TweenInterpolationValue};
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(sample_interpolations_system::<EaseKind>);
+///
+/// let entity = world
+///     .spawn((
+///         TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+///         EaseKind::QuadraticIn,
+///         EaseFirstPlayOnly::default(),
+///     ))
+///     .id();
+/// let mut progress_to = |world: &mut World, percentage: f32| {
+///     world.entity_mut(entity).insert(TimeSpanProgress {
+///         now_percentage: percentage,
+///         now: percentage,
+///         previous_percentage: 0.,
+///         previous: 0.,
+///     });
+///     schedule.run(world);
+///     world.get::<TweenInterpolationValue>(entity).unwrap().0
+/// };
+///
+/// // First play eases
+/// assert_eq!(progress_to(&mut world, 0.5), 0.25);
+/// assert_eq!(progress_to(&mut world, 1.), 1.);
+/// // Replays are instant
+/// assert_eq!(progress_to(&mut world, 0.), 1.);
+/// assert_eq!(progress_to(&mut world, 0.5), 1.);
+/// ```
 #[allow(clippy::type_complexity)]
 pub fn sample_interpolations_system<I>(
     mut commands: Commands,
-    query: Query<
-        (Entity, &I, &TimeSpanProgress),
+    mut query: Query<
+        (
+            Entity,
+            &I,
+            &TimeSpanProgress,
+            Option<&mut EaseFirstPlayOnly>,
+            Has<ReverseTween>,
+            Option<&TweenSpeed>,
+            Option<&mut TweenInterpolationValue>,
+        ),
         Or<(Changed<I>, Changed<TimeSpanProgress>)>,
     >,
+    q_prewarm: Query<
+        (
+            Entity,
+            &I,
+            &TimeSpan,
+            Has<TimeSpanProgress>,
+            Has<ReverseTween>,
+        ),
+        With<PrewarmTween>,
+    >,
+    q_retain: Query<(), With<RetainFinalValue>>,
     mut removed: RemovedComponents<TimeSpanProgress>,
+    counters: TweenCounters,
 ) where
     I: Interpolation + Component,
 {
-    query.iter().for_each(|(entity, interpolator, progress)| {
-        if progress.now_percentage.is_nan() {
+    let sampled = sample_prewarm(&mut commands, q_prewarm.iter())
+        + sample_progress(&mut commands, query.iter_mut());
+    counters.add_sampled(sampled);
+    removed.read().for_each(|entity| {
+        if q_retain.contains(entity) {
             return;
         }
-        let value = interpolator.sample(progress.now_percentage.clamp(0., 1.));
-
-        commands
-            .entity(entity)
-            .insert(TweenInterpolationValue(value));
-    });
-    removed.read().for_each(|entity| {
         if let Some(mut entity) = commands.get_entity(entity) {
             entity.remove::<TweenInterpolationValue>();
         }
     });
 }
 
+/// Interpolations that take precedence over [`DefaultEase`]
+#[cfg(not(feature = "bevy_lookup_curve"))]
+type WithoutInterpolation =
+    (Without<EaseKind>, Without<EaseClosure>, Without<EaseCurve>);
+/// Interpolations that take precedence over [`DefaultEase`]
+#[cfg(feature = "bevy_lookup_curve")]
+type WithoutInterpolation = (
+    Without<EaseKind>,
+    Without<EaseClosure>,
+    Without<EaseCurve>,
+    Without<self::bevy_lookup_curve::LookupCurveHandle>,
+);
+
+/// [`sample_interpolations_system`] for [`DefaultEase`] skipping tweens that
+/// have their own interpolation.
+///
+/// [`TweenInterpolationValue`] of finished tweens is removed by
+/// [`sample_interpolations_system::<EaseKind>`](sample_interpolations_system)
+/// which is added with this system in [`EaseKindPlugin`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+/// use bevy_tween::interpolation::{
+///     sample_default_ease_system, sample_interpolations_system, DefaultEase,
+///     EaseClosure,
+/// };
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::TweenInterpolationValue;
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((
+///     sample_interpolations_system::<EaseClosure>,
+///     sample_default_ease_system,
+/// ));
+///
+/// let span = || TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap();
+/// let progress = || TimeSpanProgress {
+///     now_percentage: 0.5,
+///     now: 0.5,
+///     previous_percentage: 0.,
+///     previous: 0.,
+/// };
+/// let default_only = world
+///     .spawn((span(), progress(), DefaultEase(EaseKind::QuadraticIn)))
+///     .id();
+/// let with_closure = world
+///     .spawn((
+///         span(),
+///         progress(),
+///         DefaultEase(EaseKind::QuadraticIn),
+///         EaseClosure::new(|_| 1.),
+///     ))
+///     .id();
+/// schedule.run(&mut world);
+///
+/// let value = |entity| world.get::<TweenInterpolationValue>(entity).unwrap().0;
+/// assert_eq!(value(default_only), 0.25);
+/// assert_eq!(value(with_closure), 1.);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn sample_default_ease_system(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &DefaultEase,
+            &TimeSpanProgress,
+            Option<&mut EaseFirstPlayOnly>,
+            Has<ReverseTween>,
+            Option<&TweenSpeed>,
+            Option<&mut TweenInterpolationValue>,
+        ),
+        (
+            Or<(Changed<DefaultEase>, Changed<TimeSpanProgress>)>,
+            WithoutInterpolation,
+        ),
+    >,
+    q_prewarm: Query<
+        (
+            Entity,
+            &DefaultEase,
+            &TimeSpan,
+            Has<TimeSpanProgress>,
+            Has<ReverseTween>,
+        ),
+        (With<PrewarmTween>, WithoutInterpolation),
+    >,
+    counters: TweenCounters,
+) {
+    let sampled = sample_prewarm(&mut commands, q_prewarm.iter())
+        + sample_progress(&mut commands, query.iter_mut());
+    counters.add_sampled(sampled);
+}
+
+/// Sample tweens with [`PrewarmTween`] at their start and return how many
+/// were sampled
+fn sample_prewarm<'a, I: Interpolation + 'a>(
+    commands: &mut Commands,
+    tweens: impl Iterator<Item = (Entity, &'a I, &'a TimeSpan, bool, bool)>,
+) -> usize {
+    let mut sampled = 0;
+    tweens.for_each(|(entity, interpolator, span, has_progress, reverse)| {
+        let mut entity = commands.entity(entity);
+        if !has_progress && span.min().duration() == Duration::ZERO {
+            let percentage = if reverse { 1. } else { 0. };
+            entity.insert(TweenInterpolationValue(
+                interpolator.sample(percentage),
+            ));
+            sampled += 1;
+        }
+        entity.remove::<PrewarmTween>();
+    });
+    sampled
+}
+
+/// Sample tweens at their current progress and return how many were sampled.
+/// [`TweenInterpolationValue`] is only inserted if the tween doesn't have one.
+#[allow(clippy::type_complexity)]
+fn sample_progress<'a, I: Interpolation + 'a>(
+    commands: &mut Commands,
+    tweens: impl Iterator<
+        Item = (
+            Entity,
+            &'a I,
+            &'a TimeSpanProgress,
+            Option<Mut<'a, EaseFirstPlayOnly>>,
+            bool,
+            Option<&'a TweenSpeed>,
+            Option<Mut<'a, TweenInterpolationValue>>,
+        ),
+    >,
+) -> usize {
+    let mut sampled = 0;
+    tweens.for_each(
+        |(
+            entity,
+            interpolator,
+            progress,
+            first_play_only,
+            reverse,
+            speed,
+            interpolation_value,
+        )| {
+            if progress.now_percentage.is_nan() {
+                return;
+            }
+            let speed = speed.map_or(1., |speed| speed.0);
+            let mut percentage =
+                (progress.now_percentage.clamp(0., 1.) * speed).clamp(0., 1.);
+            if reverse {
+                percentage = 1. - percentage;
+            }
+            if let Some(mut first_play_only) = first_play_only {
+                if first_play_only.played {
+                    percentage = 1.;
+                } else if percentage >= 1. {
+                    first_play_only.played = true;
+                }
+            }
+            let value =
+                TweenInterpolationValue(interpolator.sample(percentage));
+
+            match interpolation_value {
+                Some(mut interpolation_value) => {
+                    interpolation_value.set_if_neq(value);
+                }
+                None => {
+                    commands.entity(entity).insert(value);
+                }
+            }
+            sampled += 1;
+        },
+    );
+    sampled
+}
+
 mod easing_functions {
     use bevy::math::prelude::*;
     use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, PI};
@@ -535,4 +1198,120 @@ mod easing_functions {
         1.0 - (1.0 - t).squared()
             * (2.0 * ops::sin(omega * t) / omega + ops::cos(omega * t))
     }
+
+    #[inline]
+    pub(crate) fn cubic_bezier(
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        t: f32,
+    ) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        let x1 = x1.clamp(0.0, 1.0);
+        let x2 = x2.clamp(0.0, 1.0);
+        let s = solve_cubic_bezier(x1, x2, t);
+        if s <= 0.0 {
+            return 0.0;
+        }
+        if s >= 1.0 {
+            return 1.0;
+        }
+        cubic_bezier_component(y1, y2, s)
+    }
+
+    /// Component of a cubic Bézier from `0` to `1` with the control values
+    /// `a` and `b` at `s`
+    #[inline]
+    fn cubic_bezier_component(a: f32, b: f32, s: f32) -> f32 {
+        let inv = 1.0 - s;
+        3.0 * inv.squared() * s * a + 3.0 * inv * s.squared() * b + s.cubed()
+    }
+
+    /// Find `s` where the component with the control values `a` and `b` is
+    /// `x`. The component must be non-decreasing.
+    #[inline]
+    fn solve_cubic_bezier(a: f32, b: f32, x: f32) -> f32 {
+        const EPSILON: f32 = 1e-6;
+        // Newton-Raphson converges quickly unless the slope is flat
+        let mut s = x;
+        for _ in 0..8 {
+            let error = cubic_bezier_component(a, b, s) - x;
+            if error.abs() < EPSILON {
+                return s;
+            }
+            let inv = 1.0 - s;
+            let slope = 3.0 * inv.squared() * a
+                + 6.0 * inv * s * (b - a)
+                + 3.0 * s.squared() * (1.0 - b);
+            if slope.abs() < EPSILON {
+                break;
+            }
+            s -= error / slope;
+            if !(0.0..=1.0).contains(&s) {
+                break;
+            }
+        }
+        // Bisection always converges since the component is non-decreasing
+        let mut low = 0.0;
+        let mut high = 1.0;
+        s = x;
+        for _ in 0..32 {
+            let error = cubic_bezier_component(a, b, s) - x;
+            if error.abs() < EPSILON {
+                break;
+            }
+            if error < 0.0 {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = (low + high) * 0.5;
+        }
+        s
+    }
+
+    /// Number of lattice cells over the unit interval for the first octave
+    const NOISE_BASE_FREQUENCY: f32 = 4.0;
+
+    #[inline]
+    pub(crate) fn noise(seed: u64, octaves: u8, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        let mut sum = 0.0;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = NOISE_BASE_FREQUENCY;
+        for octave in 0..octaves.max(1) {
+            let octave_seed = seed.wrapping_add(octave as u64);
+            sum += value_noise(octave_seed, t * frequency) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= 0.5;
+            frequency *= 2.0;
+        }
+        let noise = sum / total_amplitude;
+        let envelope = ops::sin(t * PI).max(0.0);
+        t.lerp(noise, envelope).clamp(0.0, 1.0)
+    }
+
+    /// Smoothly interpolated lattice values between 0–1
+    #[inline]
+    fn value_noise(seed: u64, x: f32) -> f32 {
+        let cell = x.floor();
+        let fract = x - cell;
+        let a = lattice_value(seed, cell as i64);
+        let b = lattice_value(seed, cell as i64 + 1);
+        let smooth = fract.squared() * (3.0 - 2.0 * fract);
+        a.lerp(b, smooth)
+    }
+
+    /// splitmix64 hash of the seed and the lattice index mapped to 0–1
+    #[inline]
+    fn lattice_value(seed: u64, index: i64) -> f32 {
+        let mut z = seed
+            .wrapping_add((index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 40) as f32 / (1u64 << 24) as f32
+    }
 }
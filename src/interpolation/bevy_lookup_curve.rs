@@ -53,6 +53,7 @@ pub fn sample_lookup_curve_system(
             &LookupCurveHandle,
             Option<&mut LookupCurveCache>,
             &TimeSpanProgress,
+            Option<&mut TweenInterpolationValue>,
         ),
         Or<(Changed<LookupCurveHandle>, Changed<TimeSpanProgress>)>,
     >,
@@ -61,9 +62,8 @@ pub fn sample_lookup_curve_system(
     mut last_handle_error: Local<HashSet<AssetId<LookupCurve>>>,
 ) {
     let mut handle_error = HashSet::new();
-    query
-        .iter_mut()
-        .for_each(|(entity, curve, cache, progress)| {
+    query.iter_mut().for_each(
+        |(entity, curve, cache, progress, interpolation_value)| {
             if progress.now_percentage.is_nan() {
                 return;
             }
@@ -90,10 +90,17 @@ pub fn sample_lookup_curve_system(
                 None => curve.lookup(progress.now_percentage.clamp(0., 1.)),
             };
 
-            commands
-                .entity(entity)
-                .insert(TweenInterpolationValue(value));
-        });
+            let value = TweenInterpolationValue(value);
+            match interpolation_value {
+                Some(mut interpolation_value) => {
+                    interpolation_value.set_if_neq(value);
+                }
+                None => {
+                    commands.entity(entity).insert(value);
+                }
+            }
+        },
+    );
 
     removed.read().for_each(|entity| {
         if let Some(mut entity) = commands.get_entity(entity) {
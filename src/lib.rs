@@ -381,7 +381,24 @@ pub mod interpolation;
 pub mod tween;
 pub mod tween_event;
 
+#[cfg(all(feature = "serde", feature = "bevy_asset"))]
+pub mod clip;
+
+pub mod beat;
+pub mod clock;
 pub mod combinator;
+pub mod control;
+pub mod cursor;
+#[cfg(feature = "debug_overlay")]
+pub mod debug_overlay;
+pub mod diagnostics;
+pub mod handle;
+pub mod repeat;
+
+#[cfg(feature = "bevy_audio")]
+pub mod sound;
+#[cfg(feature = "bevy_sprite")]
+pub mod trail;
 
 /// Commonly used items
 pub mod prelude {
@@ -394,6 +411,8 @@ pub mod prelude {
 
     pub use crate::combinator::{AnimationBuilderExt, TransformTargetStateExt};
 
+    pub use crate::repeat::{RepeatExt, TimeRunnerRepeatExt};
+
     pub use crate::tween::IntoTarget;
     pub use crate::tween_event::{TweenEvent, TweenEventData};
 
@@ -472,13 +491,20 @@ impl Default for TweenAppResource {
 /// - In schedule configured by [`TweenAppResource`]:
 ///   1. [`UpdateInterpolationValue`],
 ///   2. [`ApplyTween`],
+///   3. [`TweenEvent`], or before [`ApplyTween`] depending on
+///      [`TweenCorePlugin::event_order`]
+///   4. [`PostApply`]
 ///
 ///   [`UpdateInterpolationValue`]: [`TweenSystemSet::UpdateInterpolationValue`]
 ///   [`ApplyTween`]: [`TweenSystemSet::ApplyTween`]
+///   [`TweenEvent`]: [`TweenSystemSet::TweenEvent`]
+///   [`PostApply`]: [`TweenSystemSet::PostApply`]
 #[derive(Default)]
 pub struct TweenCorePlugin {
     /// See [`TweenAppResource`]
     pub app_resource: TweenAppResource,
+    /// See [`TweenEventOrder`]
+    pub event_order: TweenEventOrder,
 }
 
 impl Plugin for TweenCorePlugin {
@@ -497,9 +523,80 @@ impl Plugin for TweenCorePlugin {
                 .chain()
                 .after(bevy_time_runner::TimeRunnerSet::Progress),
         )
+        .configure_sets(
+            self.app_resource.schedule,
+            TweenSystemSet::TweenEvent
+                .after(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .configure_sets(
+            self.app_resource.schedule,
+            TweenSystemSet::PostApply
+                .after(TweenSystemSet::ApplyTween)
+                .after(TweenSystemSet::TweenEvent),
+        );
+        match self.event_order {
+            TweenEventOrder::AfterApply => app.configure_sets(
+                self.app_resource.schedule,
+                TweenSystemSet::TweenEvent.after(TweenSystemSet::ApplyTween),
+            ),
+            TweenEventOrder::BeforeApply => app.configure_sets(
+                self.app_resource.schedule,
+                TweenSystemSet::TweenEvent.before(TweenSystemSet::ApplyTween),
+            ),
+        };
+        app.add_systems(
+            self.app_resource.schedule,
+            (
+                tween::target_grace_period_system
+                    .in_set(TweenSystemSet::UpdateInterpolationValue),
+                tween::first_cycle_only_system
+                    .after(bevy_time_runner::TimeRunnerSet::Progress)
+                    .before(TweenSystemSet::UpdateInterpolationValue),
+                tween_event::repeatable_event_system
+                    .after(bevy_time_runner::TimeRunnerSet::Progress)
+                    .before(TweenSystemSet::UpdateInterpolationValue),
+                tween::interpolation_value_history_system
+                    .in_set(TweenSystemSet::ApplyTween),
+                handle::animation_handle_system
+                    .after(TweenSystemSet::ApplyTween),
+                handle::animation_ended_trigger_system
+                    .after(TweenSystemSet::ApplyTween),
+                handle::on_animation_complete_system
+                    .after(TweenSystemSet::ApplyTween),
+                control::animation_control_system
+                    .before(clock::tween_clock_scale_system)
+                    .before(bevy_time_runner::TimeRunnerSet::TickTimer),
+                clock::tween_clock_scale_system
+                    .before(bevy_time_runner::TimeRunnerSet::TickTimer),
+                clock::tween_clock_restore_system
+                    .after(bevy_time_runner::TimeRunnerSet::TickTimer)
+                    .before(bevy_time_runner::TimeRunnerSet::Progress),
+            ),
+        )
         .insert_resource(self.app_resource.clone())
+        .init_resource::<clock::TweenClock>()
+        .init_resource::<clock::TweenClockScaled>()
+        .init_resource::<tween::TweenApplyMode>()
+        .init_resource::<combinator::AnimationRegistry>()
+        .register_type::<clock::TweenClock>()
+        .register_type::<control::AnimationControl>()
         .register_type::<tween::AnimationTarget>()
-        .register_type::<tween::TweenInterpolationValue>();
+        .register_type::<tween::PrewarmTween>()
+        .register_type::<tween::EaseFirstPlayOnly>()
+        .register_type::<tween::RetainFinalValue>()
+        .register_type::<tween::TweenApplyMode>()
+        .register_type::<tween::ReverseTween>()
+        .register_type::<tween::TweenSpeed>()
+        .register_type::<tween::FirstCycleOnly>()
+        .register_type::<tween::TargetGracePeriod>()
+        .register_type::<tween::AnimationSeed>()
+        .register_type::<tween::TimelineMarkers>()
+        .register_type::<tween::TweenInterpolationValue>()
+        .register_type::<tween::TweenInterpolationValueHistory>()
+        .register_type::<tween_event::AnyTweenEvent>()
+        .register_type::<tween_event::RepeatableEvent>()
+        .register_type::<tween_event::TweenEventData>()
+        .register_type::<tween_event::TweenEventData<&'static str>>();
     }
 
     fn cleanup(&self, app: &mut App) {
@@ -521,10 +618,128 @@ pub enum TweenSystemSet {
     /// - [`tween::component_tween_system`]
     /// - [`tween::resource_tween_system`]
     /// - [`tween::asset_tween_system`]
-    ///
-    /// Events is not necessary related to tweening but their code is still working in the same area.
-    /// - [`tween::tween_event_system`]
     ApplyTween,
+    /// This set is for systems that fire tween events such as
+    /// [`tween_event::tween_event_system`].
+    ///
+    /// Events is not necessary related to tweening but their code is still
+    /// working in the same area. By default, this set runs after
+    /// [`TweenSystemSet::ApplyTween`] so any event reader running after this
+    /// set sees the values applied in the same frame.
+    /// See [`TweenEventOrder`].
+    TweenEvent,
+    /// This set is for user systems that react to fully applied values such
+    /// as cleanup or validation. Runs after [`TweenSystemSet::ApplyTween`] and
+    /// [`TweenSystemSet::TweenEvent`]. No system from this crate is added in
+    /// this set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_tween::combinator::tween;
+    /// use bevy_tween::interpolate::translation;
+    /// use bevy_tween::tween::TweenInterpolationValue;
+    /// use bevy_tween::TweenSystemSet;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Checked(usize);
+    ///
+    /// fn validate(
+    ///     q_value: Query<&TweenInterpolationValue>,
+    ///     q_transform: Query<&Transform>,
+    ///     mut checked: ResMut<Checked>,
+    /// ) {
+    ///     for value in &q_value {
+    ///         let transform = q_transform.single();
+    ///         assert_eq!(transform.translation.x, 10. * value.0);
+    ///         checked.0 += 1;
+    ///     }
+    /// }
+    ///
+    #[doc = crate::utils::doc_app_test_boilerplate!()]
+    /// app.init_resource::<Checked>()
+    ///     .add_systems(PostUpdate, validate.in_set(TweenSystemSet::PostApply));
+    ///
+    /// let entity = app.world_mut().spawn(Transform::default()).id();
+    /// app.world_mut().commands().animation().insert(tween(
+    ///     Duration::from_secs(1),
+    ///     EaseKind::Linear,
+    ///     entity.into_target().with(translation(Vec3::ZERO, Vec3::X * 10.)),
+    /// ));
+    /// app.world_mut().flush();
+    ///
+    /// for _ in 0..5 {
+    ///     app.update();
+    /// }
+    /// assert!(app.world().resource::<Checked>().0 > 0);
+    /// ```
+    PostApply,
+}
+
+/// Order of [`TweenSystemSet::TweenEvent`] relative to
+/// [`TweenSystemSet::ApplyTween`], configured by [`TweenCorePlugin`].
+///
+/// Both orders run after [`TweenSystemSet::UpdateInterpolationValue`] so
+/// [`TweenEvent::interpolation_value`](tween_event::TweenEvent::interpolation_value)
+/// is always the value sampled in the same frame. Observers triggered by tween
+/// events run when commands are applied and see the applied values either way.
+///
+/// # Examples
+///
+/// Reacting to a fully applied value in an event handler
+///
+/// ```
+/// use bevy_tween::combinator::{event_for, parallel, tween};
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::TweenSystemSet;
+///
+/// #[derive(Resource, Default)]
+/// struct Checked(usize);
+///
+/// fn handler(
+///     mut events: EventReader<TweenEvent<&'static str>>,
+///     q_transform: Query<&Transform>,
+///     targets: Query<Entity, With<Transform>>,
+///     mut checked: ResMut<Checked>,
+/// ) {
+///     for event in events.read() {
+///         let value = event.progress.now_percentage;
+///         let transform = q_transform.get(targets.single()).unwrap();
+///         assert!((transform.translation.x - 10. * value).abs() < 1e-4);
+///         checked.0 += 1;
+///     }
+/// }
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.init_resource::<Checked>()
+///     .add_systems(PostUpdate, handler.after(TweenSystemSet::TweenEvent));
+///
+/// let entity = app.world_mut().spawn(Transform::default()).id();
+/// let secs = Duration::from_secs_f32;
+/// app.world_mut().commands().animation().insert(parallel((
+///     tween(
+///         secs(1.),
+///         EaseKind::Linear,
+///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X * 10.)),
+///     ),
+///     event_for(secs(1.), "moving"),
+/// )));
+/// app.world_mut().flush();
+///
+/// for _ in 0..5 {
+///     app.update();
+/// }
+/// assert!(app.world().resource::<Checked>().0 > 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TweenEventOrder {
+    /// Fire events after tweens are applied. Event readers see the values
+    /// applied in the same frame.
+    #[default]
+    AfterApply,
+    /// Fire events before tweens are applied. Event readers see the values
+    /// applied in the previous frame.
+    BeforeApply,
 }
 
 /// Helper trait to add systems by this crate to your app and avoid mistake
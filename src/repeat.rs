@@ -0,0 +1,160 @@
+//! Module containing helpers for counting repeats
+//!
+//! # Repeat
+//!
+//! **Traits**:
+//! - [`RepeatExt`]
+//! - [`TimeRunnerRepeatExt`]
+//!
+//! [`Repeat`] counts how many times the [`TimeRunner`] has reached an end
+//! while [`RepeatExt`] and [`TimeRunnerRepeatExt`] answer how many plays or
+//! cycles are done and remain, for example, to show "3/5 loops" in a debug
+//! overlay. Both traits are in the [prelude](crate::prelude).
+//!
+//! # Cycles
+//!
+//! A play is a single pass of the [`TimeRunner`] from one end to the other
+//! and [`Repeat::times`] repeats a play `times` times, so it plays `times + 1`
+//! times. With [`RepeatStyle::WrapAround`] a cycle is a play. With
+//! [`RepeatStyle::PingPong`] a cycle is a full there-and-back of two plays,
+//! and an odd number of plays ends with a half cycle which is counted once the
+//! animation completes.
+
+use bevy_time_runner::{Repeat, RepeatStyle, TimeRunner};
+
+/// Extension trait for counting the plays of a [`Repeat`]
+pub trait RepeatExt {
+    /// Repeat to play `plays` times in total, which is
+    /// [`Repeat::times`] of `plays - 1`. Zero plays is the same as one play.
+    fn plays(plays: usize) -> Repeat;
+
+    /// Number of times repeated so far. Always `0` for [`Repeat::Infinitely`]
+    /// since it's not counted.
+    fn times_repeated(&self) -> usize;
+
+    /// Number of repeats left or `None` if repeating infinitely
+    fn remaining(&self) -> Option<usize>;
+}
+
+impl RepeatExt for Repeat {
+    fn plays(plays: usize) -> Repeat {
+        Repeat::times(plays.saturating_sub(1))
+    }
+
+    fn times_repeated(&self) -> usize {
+        match self {
+            Repeat::Infinitely => 0,
+            Repeat::InfinitelyCounted { times_repeated } => *times_repeated,
+            Repeat::Times { times_repeated, .. } => *times_repeated,
+        }
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        match self {
+            Repeat::Infinitely | Repeat::InfinitelyCounted { .. } => None,
+            Repeat::Times {
+                times,
+                times_repeated,
+            } => Some(times.saturating_sub(*times_repeated)),
+        }
+    }
+}
+
+/// Extension trait for counting the cycles of a [`TimeRunner`].
+/// See [cycles](self#cycles) for what a cycle is.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeRunner;
+/// use bevy_tween::combinator::forward;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// fn runner(app: &App, root: Entity) -> &TimeRunner {
+///     app.world().get::<TimeRunner>(root).unwrap()
+/// }
+///
+/// let spawn = |app: &mut App, repeat_style| {
+///     let root = app
+///         .world_mut()
+///         .commands()
+///         .animation()
+///         .repeat(Repeat::times(3))
+///         .repeat_style(repeat_style)
+///         .insert(forward(Duration::from_millis(500)))
+///         .id();
+///     app.world_mut().flush();
+///     root
+/// };
+/// let wrap_around = spawn(&mut app, RepeatStyle::WrapAround);
+/// let ping_pong = spawn(&mut app, RepeatStyle::PingPong);
+///
+/// assert_eq!(runner(&app, wrap_around).cycles_total(), Some(4));
+/// assert_eq!(runner(&app, ping_pong).cycles_total(), Some(2));
+///
+/// let mut wrap_around_done = vec![0];
+/// let mut ping_pong_done = vec![0];
+/// for _ in 0..30 {
+///     app.update();
+///     for (root, done) in [
+///         (wrap_around, &mut wrap_around_done),
+///         (ping_pong, &mut ping_pong_done),
+///     ] {
+///         let cycles_done = runner(&app, root).cycles_done();
+///         if done.last() != Some(&cycles_done) {
+///             done.push(cycles_done);
+///         }
+///     }
+/// }
+///
+/// assert_eq!(wrap_around_done, [0, 1, 2, 3, 4]);
+/// assert_eq!(ping_pong_done, [0, 1, 2]);
+/// let (repeat, _) = runner(&app, wrap_around).repeat().unwrap();
+/// assert_eq!(repeat.remaining(), Some(0));
+/// ```
+pub trait TimeRunnerRepeatExt {
+    /// Number of plays done including the current one if completed
+    fn plays_done(&self) -> usize;
+
+    /// Number of cycles done including the current one if completed
+    fn cycles_done(&self) -> usize;
+
+    /// Number of cycles in total or `None` if repeating infinitely
+    fn cycles_total(&self) -> Option<usize>;
+}
+
+impl TimeRunnerRepeatExt for TimeRunner {
+    fn plays_done(&self) -> usize {
+        let times_repeated = self
+            .repeat()
+            .map(|(repeat, _)| repeat.times_repeated())
+            .unwrap_or_default();
+        times_repeated + usize::from(self.is_completed())
+    }
+
+    fn cycles_done(&self) -> usize {
+        match self.repeat() {
+            Some((_, RepeatStyle::PingPong)) => {
+                if self.is_completed() {
+                    self.cycles_total().unwrap_or_default()
+                } else {
+                    self.plays_done() / 2
+                }
+            }
+            _ => self.plays_done(),
+        }
+    }
+
+    fn cycles_total(&self) -> Option<usize> {
+        match self.repeat() {
+            None => Some(1),
+            Some((repeat, repeat_style)) => {
+                let plays = repeat.remaining()? + repeat.times_repeated() + 1;
+                match repeat_style {
+                    RepeatStyle::PingPong => Some(plays.div_ceil(2)),
+                    RepeatStyle::WrapAround => Some(plays),
+                }
+            }
+        }
+    }
+}
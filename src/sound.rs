@@ -0,0 +1,169 @@
+//! Module containing implementations for playing sounds from tween events
+//!
+//! # Sound
+//!
+//! **Plugins**:
+//! - [`TweenSoundPlugin`]
+//!
+//! **Systems**
+//! - [`tween_sound_system`]
+//!
+//! **Combinators**:
+//! - [`sound()`]
+//!
+//! Playing a sound at a keyframe is one of the most common uses of tween
+//! events. The [`sound()`] combinator emits [`TweenEvent<TweenSound>`] and
+//! [`tween_sound_system`] spawns an [`AudioPlayer`] for it.
+//!
+//! # Playing once per crossing
+//!
+//! [`TweenEvent`]s spanning a duration fire on every frame their entity has
+//! [`TimeSpanProgress`], for example, every frame of an
+//! [`event_for`](crate::combinator::event_for) span, while
+//! [instant events](crate::tween_event#instant-events) already fire once.
+//! [`tween_sound_system`] only plays
+//! a sound in the frame the event's entity gets its progress, so a sound plays
+//! once every time the animation crosses or enters the event.
+//!
+//! # Handle contract
+//!
+//! The [`AudioSource`] handle is cloned into every spawned [`AudioPlayer`].
+//! The handle should be strong, usually loaded with the [`AssetServer`], so
+//! the source stays loaded as long as the animation exists. Bevy plays the
+//! sound once the source finishes loading, which can delay the first playback
+//! of a sound that's still loading.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_time_runner::TimeSpanProgress;
+
+use crate::combinator::{event, AnimationCommands};
+use crate::tween_event::{TweenEvent, TweenEventPlugin};
+
+/// Plugin for playing [`TweenSound`]s of the [`sound()`] combinator.
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+pub struct TweenSoundPlugin;
+
+impl Plugin for TweenSoundPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist");
+        app.add_systems(
+            app_resource.schedule,
+            tween_sound_system.after(crate::TweenSystemSet::TweenEvent),
+        )
+        .add_plugins(TweenEventPlugin::<TweenSound>::default());
+    }
+}
+
+/// Event data emitted by the [`sound()`] combinator
+#[derive(Debug, Clone)]
+pub struct TweenSound {
+    /// Source to play
+    pub source: Handle<AudioSource>,
+    /// Settings of the spawned [`AudioPlayer`]
+    pub settings: PlaybackSettings,
+}
+
+impl TweenSound {
+    /// Create a new [`TweenSound`] with [`PlaybackSettings::DESPAWN`] so the
+    /// spawned player is despawned after playing
+    pub fn new(source: Handle<AudioSource>) -> TweenSound {
+        TweenSound {
+            source,
+            settings: PlaybackSettings::DESPAWN,
+        }
+    }
+
+    /// Play with the provided settings instead
+    pub fn with_settings(mut self, settings: PlaybackSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+/// Spawn an [`AudioPlayer`] for every [`TweenEvent<TweenSound>`] fired in the
+/// frame its entity got [`TimeSpanProgress`].
+///
+/// See [playing once per crossing](self#playing-once-per-crossing).
+pub fn tween_sound_system(
+    mut commands: Commands,
+    mut events: EventReader<TweenEvent<TweenSound>>,
+    q_progress: Query<Ref<TimeSpanProgress>>,
+) {
+    for event in events.read() {
+        let entered = q_progress
+            .get(event.entity)
+            .is_ok_and(|progress| progress.is_added());
+        if !entered {
+            continue;
+        }
+        commands.spawn((
+            AudioPlayer(event.data.source.clone()),
+            event.data.settings,
+        ));
+    }
+}
+
+/// Combinator for playing a sound at the current position.
+///
+/// Position is unchanged.
+///
+/// <div class="warning">
+///
+/// [`TweenSoundPlugin`] has to be added for sounds to play.
+///
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{event_for, forward, sequence};
+/// use bevy_tween::sound::{sound, TweenSound, TweenSoundPlugin};
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_plugins(TweenSoundPlugin);
+///
+/// let source = Handle::<AudioSource>::default();
+/// app.world_mut().commands().animation().insert(sequence((
+///     forward(Duration::from_millis(250)),
+///     sound(source.clone()),
+///     forward(Duration::from_millis(250)),
+///     // Fires for several frames but only plays once
+///     event_for(Duration::from_millis(300), TweenSound::new(source.clone())),
+///     forward(Duration::from_millis(250)),
+/// )));
+/// app.world_mut().flush();
+///
+/// let mut q_player = app.world_mut().query::<&AudioPlayer>();
+/// let mut played = 0;
+/// for _ in 0..20 {
+///     app.update();
+///     played += q_player.iter(app.world()).count();
+///     // Despawn players to only count new ones
+///     let players: Vec<Entity> = app
+///         .world_mut()
+///         .query_filtered::<Entity, With<AudioPlayer>>()
+///         .iter(app.world())
+///         .collect();
+///     for player in players {
+///         app.world_mut().despawn(player);
+///     }
+/// }
+/// assert_eq!(played, 2);
+/// ```
+pub fn sound(
+    source: Handle<AudioSource>,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    event(TweenSound::new(source))
+}
@@ -0,0 +1,213 @@
+//! Module containing implementations for motion trails
+//!
+//! # Trail
+//!
+//! **Plugins**:
+//! - [`TrailPlugin`]
+//!
+//! **Components**:
+//! - [`TrailGhost`]
+//!
+//! **Systems**
+//! - [`trail_ghost_spawn_system`]
+//! - [`trail_ghost_despawn_system`]
+//!
+//! **Combinators**:
+//! - [`trail()`]
+//!
+//! A trail periodically spawns fading copies of a target [`Sprite`] at the
+//! target's current position. The [`trail()`] combinator emits
+//! [`TweenEvent<TrailGhostSpawn>`] at an interval, [`trail_ghost_spawn_system`]
+//! spawns a [`TrailGhost`] with its own fade-out animation for each event, and
+//! [`trail_ghost_despawn_system`] despawns the ghost once it has faded.
+//!
+//! # Performance
+//!
+//! Every ghost is a new sprite entity with its own [`TimeRunner`] so a short
+//! interval or a long fade duration results in many entities alive at once,
+//! roughly `fade_duration / interval` per trail. Ghosts are always despawned
+//! after fading, even if the trail's animation is despawned early.
+//!
+//! [`TimeRunner`]: bevy_time_runner::TimeRunner
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_time_runner::TimeRunnerEnded;
+
+use crate::combinator::{event_every, AnimationBuilderExt, AnimationCommands};
+use crate::interpolate::sprite_color;
+use crate::interpolation::EaseKind;
+use crate::tween::IntoTarget;
+use crate::tween_event::{TweenEvent, TweenEventPlugin};
+
+/// Plugin for spawning and despawning [`TrailGhost`]s of the [`trail()`]
+/// combinator.
+///
+/// This should be added after [`TweenCorePlugin`](crate::TweenCorePlugin)
+/// or [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+pub struct TrailPlugin;
+
+impl Plugin for TrailPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` resource doesn't exist");
+        app.add_systems(
+            app_resource.schedule,
+            (trail_ghost_despawn_system, trail_ghost_spawn_system)
+                .after(crate::TweenSystemSet::ApplyTween)
+                .after(crate::TweenSystemSet::TweenEvent),
+        )
+        .add_plugins(TweenEventPlugin::<TrailGhostSpawn>::default())
+        .register_type::<TrailGhost>();
+    }
+}
+
+/// Event data emitted by the [`trail()`] combinator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrailGhostSpawn {
+    /// Entity with [`Sprite`] and [`GlobalTransform`] to copy
+    pub target: Entity,
+    /// Duration for a ghost to fade out
+    pub fade_duration: Duration,
+}
+
+/// Fading copy of a trail's target spawned by [`trail_ghost_spawn_system`].
+///
+/// This entity is also the [`TimeRunner`](bevy_time_runner::TimeRunner) of
+/// its own fade-out animation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TrailGhost;
+
+/// Spawn a [`TrailGhost`] for every [`TweenEvent<TrailGhostSpawn>`] at the
+/// target's [`GlobalTransform`], copying its [`Sprite`] and fading the copy's
+/// color to transparent.
+///
+/// Events of targets without [`Sprite`] or [`GlobalTransform`] are ignored.
+pub fn trail_ghost_spawn_system(
+    mut commands: Commands,
+    mut events: EventReader<TweenEvent<TrailGhostSpawn>>,
+    q_target: Query<(&Sprite, &GlobalTransform)>,
+) {
+    for event in events.read() {
+        let TrailGhostSpawn {
+            target,
+            fade_duration,
+        } = event.data;
+        let Ok((sprite, global_transform)) = q_target.get(target) else {
+            continue;
+        };
+        let start = sprite.color;
+        let end = start.with_alpha(0.);
+        let mut ghost = commands.spawn((
+            TrailGhost,
+            sprite.clone(),
+            global_transform.compute_transform(),
+        ));
+        let ghost_target = ghost.id().into_target();
+        ghost.animation().insert_tween_here(
+            fade_duration,
+            EaseKind::Linear,
+            ghost_target.with(sprite_color(start, end)),
+        );
+    }
+}
+
+/// Despawn [`TrailGhost`]s that have completed fading
+pub fn trail_ghost_despawn_system(
+    mut commands: Commands,
+    q_ghost: Query<(), With<TrailGhost>>,
+    mut ended: EventReader<TimeRunnerEnded>,
+) {
+    for ended in ended.read() {
+        if ended.is_completed() && q_ghost.contains(ended.time_runner) {
+            commands.entity(ended.time_runner).despawn_recursive();
+        }
+    }
+}
+
+/// Combinator for spawning a motion trail of `target`.
+///
+/// A [`TrailGhost`] is spawned every `interval` starting at the current
+/// position for provided `length`, each fading out for `fade_duration`.
+/// Ghost spawning follows [`event_every`] so the count is
+/// `ceil(length / interval)`.
+///
+/// Position is at the end of the length.
+///
+/// <div class="warning">
+///
+/// [`TrailPlugin`] has to be added for ghosts to spawn.
+///
+/// </div>
+///
+/// # Panics
+///
+/// Panics if `interval` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::time::TimeUpdateStrategy;
+/// use bevy::utils::HashSet;
+/// use bevy_tween::combinator::{parallel, tween};
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::trail::{trail, TrailGhost, TrailPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, DefaultTweenPlugins, TrailPlugin))
+///     .insert_resource(TimeUpdateStrategy::ManualDuration(
+///         Duration::from_millis(30),
+///     ));
+///
+/// let secs = Duration::from_secs_f32;
+/// let entity = app.world_mut().spawn(Sprite::default()).id();
+/// app.world_mut().commands().animation().insert(parallel((
+///     tween(
+///         secs(0.3),
+///         EaseKind::Linear,
+///         entity.into_target().with(translation(Vec3::ZERO, Vec3::X * 30.)),
+///     ),
+///     trail(entity, secs(0.1), secs(0.3), secs(0.15)),
+/// )));
+/// app.world_mut().flush();
+///
+/// let mut ghosts = HashSet::new();
+/// for _ in 0..40 {
+///     app.update();
+///     let mut q_ghost = app.world_mut().query_filtered::<Entity, With<TrailGhost>>();
+///     ghosts.extend(q_ghost.iter(app.world()));
+/// }
+///
+/// // Spawned at 0.0, 0.1, and 0.2 seconds
+/// assert_eq!(ghosts.len(), 3);
+/// // All despawned after fading
+/// for ghost in ghosts {
+///     assert!(app.world().get_entity(ghost).is_err());
+/// }
+/// ```
+pub fn trail(
+    target: Entity,
+    interval: Duration,
+    length: Duration,
+    fade_duration: Duration,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    event_every(
+        interval,
+        length,
+        TrailGhostSpawn {
+            target,
+            fade_duration,
+        },
+    )
+}
@@ -2,18 +2,35 @@
 //!
 //! # Tween
 //!
+//! **Resources**:
+//! - [`TweenApplyMode`]
+//!
 //! **Components**:
 //! - [`Tween<T, I>`]
 //! - [`SkipTween`]
+//! - [`PrewarmTween`]
+//! - [`EaseFirstPlayOnly`]
+//! - [`RetainFinalValue`]
+//! - [`ReverseTween`]
+//! - [`TweenSpeed`]
+//! - [`FirstCycleOnly`]
+//! - [`TargetGracePeriod`]
+//! - [`AnimationSeed`]
+//! - [`TimelineMarkers`]
 //! - [`TweenInterpolationValue`]
+//! - [`TweenInterpolationValueHistory`]
 //!
 //! **Systems**
 //! - [`component_tween_system`]
 //! - [`resource_tween_system`]
 //! - [`asset_tween_system`]
+//! - [`interpolation_value_history_system`]
+//! - [`target_grace_period_system`]
+//! - [`first_cycle_only_system`]
 //!
 //! **Targets**:
 //! - [`TargetComponent`]
+//! - [`TargetQuery`]
 //! - [`TargetResource`]
 //! - [`TargetAsset`]
 //!
@@ -205,13 +222,120 @@
 //! # }
 //! ```
 //!
+//! ## Reflection and scenes
+//!
+//! Animations are plain entities so they can be cloned through reflection or
+//! saved in a Bevy scene as long as every component is registered:
+//! - [`TimeRunner`](bevy_time_runner::TimeRunner), [`TimeSpan`](bevy_time_runner::TimeSpan),
+//!   and [`TimeSpanProgress`](bevy_time_runner::TimeSpanProgress) are registered
+//!   by [`TimeRunnerPlugin`](bevy_time_runner::TimeRunnerPlugin).
+//! - [`EaseKind`](crate::interpolation::EaseKind), the markers in this module, and
+//!   [`TweenEventData`](crate::tween_event::TweenEventData) of the
+//!   [default events](crate::tween_event::DefaultTweenEventPlugins) are
+//!   registered by [`DefaultTweenPlugins`].
+//! - [`ComponentTween`] and [`AssetTween`] of the built-in interpolators are
+//!   registered by [`DefaultInterpolatorsPlugin`] except for
+//!   [`TranslationCurve`](crate::interpolate::TranslationCurve),
+//!   [`RotationCurve`](crate::interpolate::RotationCurve), and
+//!   [`ScaleCurve`](crate::interpolate::ScaleCurve) which hold trait objects.
+//!   Dynamic interpolators and closures can't be reflected either.
+//!
+//! Custom interpolators and event data have to be registered with
+//! `register_type::<ComponentTween<I>>()` and
+//! `register_type::<TweenEventData<Data>>()`. Entities are referred to by
+//! [`TargetComponent`] and the hierarchy, so they have to be mapped when a
+//! scene is spawned into another world.
+//!
+//! ```
+//! use std::any::TypeId;
+//!
+//! use bevy::ecs::reflect::ReflectComponent;
+//! use bevy_tween::combinator::{event, sequence, tween};
+//! use bevy_tween::interpolate::{translation, Translation};
+//! use bevy_tween::tween::TargetComponent;
+//!
+//! // Clone the reflected components of `source` except for the hierarchy
+//! fn clone_entity(world: &mut World, source: Entity) -> Entity {
+//!     let registry = world.resource::<AppTypeRegistry>().clone();
+//!     let registry = registry.read();
+//!     let hierarchy = [TypeId::of::<Parent>(), TypeId::of::<Children>()];
+//!     let components: Vec<_> = world
+//!         .inspect_entity(source)
+//!         .filter_map(|info| info.type_id())
+//!         .filter(|type_id| !hierarchy.contains(type_id))
+//!         .map(|type_id| {
+//!             let reflect_component = registry
+//!                 .get_type_data::<ReflectComponent>(type_id)
+//!                 .expect("component should be registered")
+//!                 .clone();
+//!             let value = reflect_component
+//!                 .reflect(world.entity(source))
+//!                 .unwrap()
+//!                 .clone_value();
+//!             (reflect_component, value)
+//!         })
+//!         .collect();
+//!     let mut destination = world.spawn_empty();
+//!     for (reflect_component, value) in &components {
+//!         reflect_component.insert(&mut destination, value.as_ref(), &registry);
+//!     }
+//!     destination.id()
+//! }
+#![doc = crate::utils::doc_app_test_boilerplate!()]
+//!
+//! let a = app.world_mut().spawn(Transform::default()).id();
+//! let b = app.world_mut().spawn(Transform::default()).id();
+//! let root = app
+//!     .world_mut()
+//!     .commands()
+//!     .animation()
+//!     .insert(sequence((
+//!         tween(
+//!             Duration::from_secs(1),
+//!             EaseKind::CubicInOut,
+//!             a.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+//!         ),
+//!         event("done"),
+//!     )))
+//!     .id();
+//! app.world_mut().flush();
+//!
+//! // Clone the animation subtree and make the clone target `b`
+//! let world = app.world_mut();
+//! let children: Vec<Entity> = world.get::<Children>(root).unwrap().to_vec();
+//! let cloned_root = clone_entity(world, root);
+//! for child in children {
+//!     let cloned_child = clone_entity(world, child);
+//!     world.entity_mut(cloned_root).add_child(cloned_child);
+//!     if let Some(mut tween) =
+//!         world.get_mut::<ComponentTween<Translation>>(cloned_child)
+//!     {
+//!         tween.target = TargetComponent::entity(b);
+//!     }
+//! }
+//!
+//! for _ in 0..15 {
+//!     app.update();
+//!     let translation = |entity| {
+//!         app.world().get::<Transform>(entity).unwrap().translation
+//!     };
+//!     assert_eq!(translation(a), translation(b));
+//! }
+//! assert_eq!(app.world().get::<Transform>(b).unwrap().translation, Vec3::X);
+//! ```
+//!
 //! [`BevyTweenRegisterSystems`]: crate::BevyTweenRegisterSystems
 //! [`interpolate`]: crate::interpolate
 //! [`DefaultTweenPlugins`]: crate::DefaultTweenPlugins
 //! [`DefaultInterpolatorsPlugin`]: crate::interpolate::DefaultInterpolatorsPlugin
 //! [`DefaultDynInterpolatorsPlugin`]: crate::interpolate::DefaultDynInterpolatorsPlugin
 
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::time::Duration;
+
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 
 use crate::combinator::TargetState;
 use crate::interpolate::Interpolator;
@@ -222,19 +346,482 @@ pub use systems::{
     apply_asset_tween_system, asset_dyn_tween_system, asset_tween_system,
 };
 pub use systems::{
-    apply_component_tween_system, component_dyn_tween_system,
-    component_tween_system,
+    apply_component_query_tween_system, apply_component_tween_system,
+    component_dyn_tween_system, component_tween_system,
+    component_tween_system_filtered,
 };
 pub use systems::{
     apply_resource_tween_system, resource_dyn_tween_system,
     resource_tween_system,
 };
+pub use systems::{
+    first_cycle_only_system, interpolation_value_history_system,
+    target_grace_period_system,
+};
 
 /// Skip a tween from tweening.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component)]
 pub struct SkipTween;
 
+/// Sample and apply this tween's start value as soon as it's spawned, before
+/// the [`TimeRunner`] has produced any [`TimeSpanProgress`] for it.
+///
+/// This is consumed by [`sample_interpolations_system`] in
+/// [`TweenSystemSet::UpdateInterpolationValue`] which then removes this
+/// component. Only tweens with a [`TimeSpan`] starting at zero are prewarmed.
+/// See [`AnimationBuilder::prewarm`].
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+/// [`TimeSpanProgress`]: bevy_time_runner::TimeSpanProgress
+/// [`TimeSpan`]: bevy_time_runner::TimeSpan
+/// [`sample_interpolations_system`]: crate::interpolation::sample_interpolations_system
+/// [`TweenSystemSet::UpdateInterpolationValue`]: crate::TweenSystemSet::UpdateInterpolationValue
+/// [`AnimationBuilder::prewarm`]: crate::combinator::AnimationBuilder::prewarm
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct PrewarmTween;
+
+/// Ease this tween only on its first play and snap it to the end on replays.
+///
+/// Useful for UI that should animate in the first time it's shown but appear
+/// instantly on every following show.
+///
+/// [`sample_interpolations_system`] samples the interpolation normally until
+/// the tween's progress reaches the end, which sets [`Self::played`]. After
+/// that, the interpolation is always sampled at `1.` whenever the tween
+/// progresses. Set [`Self::played`] back to `false` to ease again.
+/// See [`AnimationBuilder::ease_first_play_only`].
+///
+/// [`sample_interpolations_system`]: crate::interpolation::sample_interpolations_system
+/// [`AnimationBuilder::ease_first_play_only`]: crate::combinator::AnimationBuilder::ease_first_play_only
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct EaseFirstPlayOnly {
+    /// Whether the first play has reached the end
+    pub played: bool,
+}
+
+/// Keep this tween's [`TweenInterpolationValue`] after its
+/// [`TimeSpanProgress`] is removed, so the final value keeps being applied
+/// every frame. For example, to keep overriding other systems writing to the
+/// same component after the animation completed.
+///
+/// Normally [`sample_interpolations_system`] removes the value together with
+/// the progress which stops the tween from being applied. The retained value is
+/// the last one sampled, which is the clamped end of the traveled direction
+/// when the time runner passed the tween. See
+/// [`AnimationBuilder::retain_final_value`].
+///
+/// The tradeoff is that apply systems keep running for this tween until this
+/// component or the tween is removed. Since a tween loses its progress whenever
+/// the time runner leaves its span, not only on completion, a retained tween in
+/// the middle of a sequence will fight with the later tweens targeting the same
+/// component. Use it on the last tweens of an animation.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+/// use bevy_tween::interpolate::{translation, Translation};
+/// use bevy_tween::interpolation::sample_interpolations_system;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::{apply_component_tween_system, RetainFinalValue};
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(
+///     (
+///         sample_interpolations_system::<EaseKind>,
+///         apply_component_tween_system::<Translation>,
+///     )
+///         .chain(),
+/// );
+///
+/// let target = world.spawn(Transform::default()).id();
+/// let tween = world
+///     .spawn((
+///         TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+///         EaseKind::Linear,
+///         target.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///         RetainFinalValue,
+///         TimeSpanProgress {
+///             now_percentage: 1.,
+///             now: 1.,
+///             previous_percentage: 0.5,
+///             previous: 0.5,
+///         },
+///     ))
+///     .id();
+/// schedule.run(&mut world);
+///
+/// // Completed
+/// world.entity_mut(tween).remove::<TimeSpanProgress>();
+/// schedule.run(&mut world);
+///
+/// // Another system moved the target
+/// world.get_mut::<Transform>(target).unwrap().translation = Vec3::Y;
+/// schedule.run(&mut world);
+/// assert_eq!(world.get::<Transform>(target).unwrap().translation, Vec3::X);
+/// ```
+///
+/// [`TimeSpanProgress`]: bevy_time_runner::TimeSpanProgress
+/// [`sample_interpolations_system`]: crate::interpolation::sample_interpolations_system
+/// [`AnimationBuilder::retain_final_value`]: crate::combinator::AnimationBuilder::retain_final_value
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct RetainFinalValue;
+
+/// How [`apply_component_tween_system`] writes tweens to their targets.
+///
+/// [`TweenInterpolationValue`] only changes when the tween's progress changes,
+/// so a paused tween or one keeping its value with [`RetainFinalValue`] writes
+/// the same value on every frame. With [`TweenApplyMode::OnChange`], a target
+/// is only written when the tween's value changed or when the target was
+/// changed by something else since the last apply, so an external system
+/// resetting the component is still overridden on the next frame.
+///
+/// The default is [`TweenApplyMode::EveryFrame`] since interpolators
+/// depending on more than the value, such as additive or delta time based
+/// ones, expect to be applied on every frame. A target written by several
+/// tween systems, for example, [`Translation`](crate::interpolate::Translation)
+/// and [`Scale`](crate::interpolate::Scale) tweens on the same [`Transform`],
+/// looks changed to each of them and is still written on every frame.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+/// use bevy_tween::interpolate::{translation, Translation};
+/// use bevy_tween::interpolation::sample_interpolations_system;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::{apply_component_tween_system, TweenApplyMode};
+///
+/// let mut world = World::new();
+/// world.insert_resource(TweenApplyMode::OnChange);
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(
+///     (
+///         sample_interpolations_system::<EaseKind>,
+///         apply_component_tween_system::<Translation>,
+///     )
+///         .chain(),
+/// );
+///
+/// let target = world.spawn(Transform::default()).id();
+/// world.spawn((
+///     TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+///     EaseKind::Linear,
+///     target.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     TimeSpanProgress {
+///         now_percentage: 0.5,
+///         now: 0.5,
+///         previous_percentage: 0.,
+///         previous: 0.,
+///     },
+/// ));
+/// schedule.run(&mut world);
+/// assert_eq!(world.get::<Transform>(target).unwrap().translation.x, 0.5);
+///
+/// let last_changed = |world: &World| {
+///     world.entity(target).get_ref::<Transform>().unwrap().last_changed()
+/// };
+/// let written = last_changed(&world);
+/// // Progress is unchanged so the target isn't written
+/// for _ in 0..3 {
+///     schedule.run(&mut world);
+/// }
+/// assert_eq!(last_changed(&world), written);
+///
+/// // Another system reset the target
+/// world.get_mut::<Transform>(target).unwrap().translation = Vec3::ZERO;
+/// schedule.run(&mut world);
+/// assert_eq!(world.get::<Transform>(target).unwrap().translation.x, 0.5);
+/// ```
+///
+/// A paused animation doesn't write its targets.
+///
+/// ```
+/// use bevy_tween::bevy_time_runner::TimeRunner;
+/// use bevy_tween::combinator::tween;
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::tween::TweenApplyMode;
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.insert_resource(TweenApplyMode::OnChange);
+///
+/// let target = app.world_mut().spawn(Transform::default()).id();
+/// let root = app
+///     .world_mut()
+///     .commands()
+///     .animation()
+///     .insert(tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         target.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     ))
+///     .id();
+/// app.world_mut().flush();
+/// for _ in 0..3 {
+///     app.update();
+/// }
+///
+/// app.world_mut()
+///     .get_mut::<TimeRunner>(root)
+///     .unwrap()
+///     .set_paused(true);
+/// app.update();
+/// let last_changed = |app: &App| {
+///     app.world()
+///         .entity(target)
+///         .get_ref::<Transform>()
+///         .unwrap()
+///         .last_changed()
+/// };
+/// let written = last_changed(&app);
+/// for _ in 0..3 {
+///     app.update();
+/// }
+/// assert_eq!(last_changed(&app), written);
+/// ```
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Resource, Reflect,
+)]
+#[reflect(Resource)]
+pub enum TweenApplyMode {
+    /// Write every active tween on every frame
+    #[default]
+    EveryFrame,
+    /// Only write targets when the tween's value or the target changed
+    OnChange,
+}
+
+/// Play this tween backward within its span: the interpolation is sampled
+/// with `1 - progress` so the tween starts at its end value and ends at its
+/// start value. Inserted by the [`reverse`](crate::combinator::reverse)
+/// combinator.
+///
+/// See [`sample_interpolations_system`].
+///
+/// [`sample_interpolations_system`]: crate::interpolation::sample_interpolations_system
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct ReverseTween;
+
+/// Play this tween faster or slower within its span. The progress is
+/// multiplied by the speed before sampling the interpolation.
+///
+/// The span is fixed in the time runner's timeline so the tween still starts
+/// and stops being active at the same time, only the sampling within the span
+/// changes. Progress is clamped to 0–1 after scaling: a tween faster than `1.`
+/// reaches its end early and holds the end value for the rest of its span, and
+/// a tween slower than `1.` doesn't reach its end value before its span ends.
+/// With [`ReverseTween`], the speed is applied before reversing.
+///
+/// See [`sample_interpolations_system`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::bevy_time_runner::{TimeSpan, TimeSpanProgress};
+/// use bevy_tween::interpolation::sample_interpolations_system;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::{TweenInterpolationValue, TweenSpeed};
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(sample_interpolations_system::<EaseKind>);
+///
+/// let entity = world
+///     .spawn((
+///         TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+///         EaseKind::Linear,
+///         TweenSpeed(2.),
+///     ))
+///     .id();
+/// let mut progress_to = |world: &mut World, percentage: f32| {
+///     world.entity_mut(entity).insert(TimeSpanProgress {
+///         now_percentage: percentage,
+///         now: percentage,
+///         previous_percentage: 0.,
+///         previous: 0.,
+///     });
+///     schedule.run(world);
+///     world.get::<TweenInterpolationValue>(entity).unwrap().0
+/// };
+///
+/// assert_eq!(progress_to(&mut world, 0.25), 0.5);
+/// // Reaches the end at the span's midpoint and holds it
+/// assert_eq!(progress_to(&mut world, 0.5), 1.);
+/// assert_eq!(progress_to(&mut world, 0.75), 1.);
+/// ```
+///
+/// [`sample_interpolations_system`]: crate::interpolation::sample_interpolations_system
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TweenSpeed(pub f32);
+
+impl Default for TweenSpeed {
+    fn default() -> Self {
+        TweenSpeed(1.)
+    }
+}
+
+/// Only play this tween in the first cycle of a repeating animation, for
+/// example, an intro inside an infinitely repeating loop. Inserted by the
+/// [`first_cycle_only`](crate::combinator::first_cycle_only) combinator.
+///
+/// [`first_cycle_only_system`] inserts [`SkipTween`] to this tween and
+/// removes this component as soon as its time runner repeats for the first
+/// time, so the tween keeps the value it applied in the first cycle. With
+/// [`RepeatStyle::PingPong`], the first cycle is the first forward pass.
+/// Remove [`SkipTween`] to play the tween again.
+///
+/// [`RepeatStyle::PingPong`]: bevy_time_runner::RepeatStyle::PingPong
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct FirstCycleOnly;
+
+/// Silently wait for this tween's target to exist instead of logging errors
+/// right away, for example, when the target entity is spawned asynchronously
+/// or the target asset is still loading.
+///
+/// While the grace period lasts, [`apply_component_tween_system`] and
+/// [`apply_asset_tween_system`] skip this tween without logging if the target
+/// cannot be found, and apply it as usual as soon as the target appears.
+/// The tween keeps progressing while waiting, so a late target starts at the
+/// current value rather than the start. Once the grace period is over,
+/// missing targets are logged as usual. [`TargetResource`] is not affected.
+///
+/// The grace period starts when the tween first has
+/// [`TimeSpanProgress`](bevy_time_runner::TimeSpanProgress) and lasts until
+/// both [`Self::frames`] and [`Self::timeout`] have passed. Leave one of them
+/// at zero to only use the other. [`target_grace_period_system`] keeps track
+/// of the elapsed frames and time. See
+/// [`AnimationBuilder::target_grace_period`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::interpolate::translation;
+/// use bevy_tween::tween::TargetGracePeriod;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// // The target doesn't have `Transform` yet
+/// let target = app.world_mut().spawn_empty().id();
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .target_grace_period(TargetGracePeriod::frames(5))
+///     .insert_tween_here(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         target.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///     );
+/// app.world_mut().flush();
+///
+/// for _ in 0..3 {
+///     app.update();
+/// }
+/// app.world_mut().entity_mut(target).insert(Transform::default());
+/// app.update();
+///
+/// let transform = app.world().get::<Transform>(target).unwrap();
+/// assert!(transform.translation.x > 0.);
+/// ```
+///
+/// [`apply_component_tween_system`]: crate::tween::apply_component_tween_system
+/// [`apply_asset_tween_system`]: crate::tween::apply_asset_tween_system
+/// [`AnimationBuilder::target_grace_period`]: crate::combinator::AnimationBuilder::target_grace_period
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TargetGracePeriod {
+    /// Number of frames to wait
+    pub frames: u32,
+    /// Duration to wait
+    pub timeout: Duration,
+    elapsed_frames: u32,
+    elapsed: Duration,
+}
+
+impl TargetGracePeriod {
+    /// Create a new [`TargetGracePeriod`] waiting for the provided frames and
+    /// timeout
+    pub fn new(frames: u32, timeout: Duration) -> TargetGracePeriod {
+        TargetGracePeriod {
+            frames,
+            timeout,
+            elapsed_frames: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Create a new [`TargetGracePeriod`] waiting for the provided frames
+    pub fn frames(frames: u32) -> TargetGracePeriod {
+        TargetGracePeriod::new(frames, Duration::ZERO)
+    }
+
+    /// Create a new [`TargetGracePeriod`] waiting for the provided timeout
+    pub fn timeout(timeout: Duration) -> TargetGracePeriod {
+        TargetGracePeriod::new(0, timeout)
+    }
+
+    /// Advance the elapsed frames by one and the elapsed time by `delta`
+    pub fn tick(&mut self, delta: Duration) {
+        self.elapsed_frames = self.elapsed_frames.saturating_add(1);
+        self.elapsed = self.elapsed.saturating_add(delta);
+    }
+
+    /// Returns `true` if the grace period is still lasting
+    pub fn is_waiting(&self) -> bool {
+        self.elapsed_frames <= self.frames || self.elapsed < self.timeout
+    }
+
+    /// Start the grace period over
+    pub fn reset(&mut self) {
+        self.elapsed_frames = 0;
+        self.elapsed = Duration::ZERO;
+    }
+}
+
+/// Seed of an animation set by [`AnimationBuilder::with_seed`] on the
+/// animation root entity.
+///
+/// The seed is applied when the animation is built: tweens spawned with
+/// [`AnimationCommands::spawn`] get seeds for their [`EaseKind::Noise`] drawn
+/// from [`AnimationCommands::next_seed`], which is a deterministic stream
+/// started from this seed, so the same build always produces identical
+/// motion. Changing this component afterwards doesn't reseed the tweens.
+///
+/// [`AnimationBuilder::with_seed`]: crate::combinator::AnimationBuilder::with_seed
+/// [`AnimationCommands::spawn`]: crate::combinator::AnimationCommands::spawn
+/// [`AnimationCommands::next_seed`]: crate::combinator::AnimationCommands::next_seed
+/// [`EaseKind::Noise`]: crate::interpolation::EaseKind::Noise
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AnimationSeed(pub u64);
+
+/// Named points in time of an animation recorded by the
+/// [`marker`](crate::combinator::marker) combinator on the animation root
+/// entity, for example, to seek to a point of a cutscene by name with
+/// [`seek_to_marker`](crate::combinator::seek_to_marker).
+///
+/// Names are unique per animation. A marker recorded later with the same name
+/// replaces the earlier one.
+#[derive(Debug, Default, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TimelineMarkers(pub HashMap<String, Duration>);
+
+impl TimelineMarkers {
+    /// Get the time of the marker named `name`
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.0.get(name).copied()
+    }
+}
+
 /// Automatically managed by an [`Interpolation`] such as [`EaseKind`] and
 /// [`EaseClosure`] when a tween has the component [`TimeSpanProgress`](bevy_time_runner::TimeSpanProgress).
 /// See [`sample_interpolations_system`]
@@ -247,6 +834,98 @@ pub struct SkipTween;
 #[reflect(Component)] // might want to use sparseset but i'm not sure yet
 pub struct TweenInterpolationValue(pub f32);
 
+/// Ring buffer of the most recent [`TweenInterpolationValue`]s of a tween,
+/// for effects like motion blur or echo that need the last few eased values.
+///
+/// Insert this to a tween entity and [`interpolation_value_history_system`]
+/// will record the value whenever it changes, dropping the oldest value once
+/// `capacity` is reached.
+///
+/// Each entity stores up to `capacity` [`f32`]s (4 bytes each) allocated up
+/// front, so keep the capacity small when used on many tweens.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_tween::tween::{
+///     interpolation_value_history_system, TweenInterpolationValue,
+///     TweenInterpolationValueHistory,
+/// };
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(TweenInterpolationValueHistory::new(3)).id();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(interpolation_value_history_system);
+///
+/// for value in [0.1, 0.2, 0.3, 0.4, 0.5] {
+///     world.entity_mut(entity).insert(TweenInterpolationValue(value));
+///     schedule.run(&mut world);
+/// }
+///
+/// let history = world.get::<TweenInterpolationValueHistory>(entity).unwrap();
+/// assert_eq!(history.iter().collect::<Vec<_>>(), [0.3, 0.4, 0.5]);
+/// assert_eq!(history.latest(), Some(0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TweenInterpolationValueHistory {
+    values: VecDeque<f32>,
+    capacity: usize,
+}
+
+impl TweenInterpolationValueHistory {
+    /// Create a new [`TweenInterpolationValueHistory`] holding up to
+    /// `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        TweenInterpolationValueHistory {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Maximum number of values held
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of values currently held
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no value has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterate values from the oldest to the latest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = f32> + '_ {
+        self.values.iter().copied()
+    }
+
+    /// The latest recorded value
+    pub fn latest(&self) -> Option<f32> {
+        self.values.back().copied()
+    }
+
+    /// Record a value, dropping the oldest one if full
+    pub fn push(&mut self, value: f32) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// Remove all recorded values
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
 /// Containing `target` and `interpolator`
 #[derive(
     Debug, Default, Component, Clone, Copy, PartialEq, Eq, Hash, Reflect,
@@ -445,6 +1124,99 @@ where
 
 impl<C> ComponentDynTween<C> where C: Component {}
 
+/// Tell the tween to target every entity with the component `F`, for example,
+/// every enemy without enumerating them.
+///
+/// Entities are queried again every frame, so entities that get or lose `F`
+/// in the middle of an animation are picked up or dropped. Entities with `F`
+/// but without the tweened component are skipped silently.
+///
+/// Tween systems for this target are generic over the filter so they have to
+/// be registered for every interpolator and filter pair with
+/// [`component_tween_system_filtered`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::tween;
+/// use bevy_tween::interpolate::{translation, Translation};
+/// use bevy_tween::tween::{component_tween_system_filtered, TargetQuery};
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_tween_systems(
+///     component_tween_system_filtered::<Translation, Enemy>(),
+/// );
+///
+/// let enemies: Vec<Entity> = (0..3)
+///     .map(|_| app.world_mut().spawn((Transform::default(), Enemy)).id())
+///     .collect();
+/// let other = app.world_mut().spawn(Transform::default()).id();
+/// app.world_mut().commands().animation().insert(tween(
+///     Duration::from_secs(1),
+///     EaseKind::Linear,
+///     TargetQuery::<Enemy>::new().with(translation(Vec3::ZERO, Vec3::X)),
+/// ));
+/// app.world_mut().flush();
+/// for _ in 0..15 {
+///     app.update();
+/// }
+///
+/// for enemy in enemies {
+///     let transform = app.world().get::<Transform>(enemy).unwrap();
+///     assert_eq!(transform.translation, Vec3::X);
+/// }
+/// let transform = app.world().get::<Transform>(other).unwrap();
+/// assert_eq!(transform.translation, Vec3::ZERO);
+/// ```
+pub struct TargetQuery<F>(PhantomData<fn() -> F>);
+
+impl<F> TargetQuery<F>
+where
+    F: Component,
+{
+    /// New query target
+    pub fn new() -> TargetQuery<F> {
+        TargetQuery(PhantomData)
+    }
+
+    /// Create a new [`TargetState`] with the initial value out of this target.
+    pub fn state<V>(&self, value: V) -> TargetState<Self, V> {
+        TargetState::new(self.clone(), value)
+    }
+
+    /// Create a new tween with the supplied interpolator out of this target.
+    pub fn with<I>(&self, interpolator: I) -> Tween<Self, I> {
+        Tween {
+            target: self.clone(),
+            interpolator,
+        }
+    }
+}
+
+impl<F> Default for TargetQuery<F>
+where
+    F: Component,
+{
+    fn default() -> Self {
+        TargetQuery::new()
+    }
+}
+
+impl<F> Clone for TargetQuery<F> {
+    fn clone(&self) -> Self {
+        TargetQuery(PhantomData)
+    }
+}
+
+impl<F> core::fmt::Debug for TargetQuery<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "TargetQuery<{}>", std::any::type_name::<F>())
+    }
+}
+
 /// Convenient alias for [`Tween`] that [`TargetResource`] with generic [`Interpolator`].
 pub type ResourceTween<I> = Tween<TargetResource, I>;
 
@@ -463,6 +1235,50 @@ impl TargetResource {
     }
 
     /// Create a new [`TargetState`] with the initial value out of this target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_tween::prelude::*;
+    /// use bevy_tween::tween::TargetResource;
+    ///
+    /// #[derive(Resource)]
+    /// struct Volume(f32);
+    ///
+    /// struct InterpolateVolume {
+    ///     start: f32,
+    ///     end: f32,
+    /// }
+    ///
+    /// impl Interpolator for InterpolateVolume {
+    ///     type Item = Volume;
+    ///
+    ///     fn interpolate(&self, item: &mut Self::Item, value: f32) {
+    ///         item.0 = self.start + (self.end - self.start) * value;
+    ///     }
+    /// }
+    ///
+    /// fn volume_to(to: f32) -> impl Fn(&mut f32) -> InterpolateVolume {
+    ///     move |state| {
+    ///         let start = *state;
+    ///         *state = to;
+    ///         InterpolateVolume { start, end: to }
+    ///     }
+    /// }
+    ///
+    /// let mut volume = TargetResource::new().state(1.);
+    ///
+    /// // Fade out then back in, each from the previous value
+    /// let fade_out = volume.with(volume_to(0.));
+    /// let fade_in = volume.with(volume_to(1.));
+    ///
+    /// let mut resource = Volume(1.);
+    /// fade_out.interpolator.interpolate(&mut resource, 1.);
+    /// assert_eq!(resource.0, 0.);
+    /// fade_in.interpolator.interpolate(&mut resource, 0.5);
+    /// assert_eq!(resource.0, 0.5);
+    /// ```
     pub fn state<V>(&self, value: V) -> TargetState<Self, V> {
         TargetState::new(self.clone(), value)
     }
@@ -493,6 +1309,77 @@ impl TargetResource {
     }
 }
 
+/// Create a new [`TargetResource`] to start a resource tween fluently, like
+/// [`IntoTarget::into_target`] does for entities.
+///
+/// There's only one resource of each type so the target holds no data. Which
+/// resource is tweened is decided by the [`Interpolator::Item`] of the tween,
+/// so the same target can be used for every resource.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{sequence, tween};
+/// use bevy_tween::tween::resource_target;
+///
+/// #[derive(Resource)]
+/// struct Score(f32);
+///
+/// struct InterpolateScore {
+///     start: f32,
+///     end: f32,
+/// }
+///
+/// impl Interpolator for InterpolateScore {
+///     type Item = Score;
+///
+///     fn interpolate(&self, item: &mut Self::Item, value: f32) {
+///         item.0 = self.start + (self.end - self.start) * value;
+///     }
+/// }
+///
+/// fn score_to(to: f32) -> impl Fn(&mut f32) -> InterpolateScore {
+///     move |state| {
+///         let start = *state;
+///         *state = to;
+///         InterpolateScore { start, end: to }
+///     }
+/// }
+///
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+/// app.add_tween_systems(
+///     bevy_tween::resource_tween_system::<InterpolateScore>(),
+/// )
+/// .insert_resource(Score(0.));
+///
+/// let mut score = resource_target().state(0.);
+/// app.world_mut().commands().animation().insert(sequence((
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         score.with(score_to(10.)),
+///     ),
+///     tween(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         score.with(score_to(5.)),
+///     ),
+/// )));
+/// app.world_mut().flush();
+///
+/// let mut highest = 0.;
+/// for _ in 0..30 {
+///     app.update();
+///     highest = app.world().resource::<Score>().0.max(highest);
+/// }
+/// // Counted up toward 10 then down to 5
+/// assert!(highest > 9. && highest <= 10.);
+/// assert_eq!(app.world().resource::<Score>().0, 5.);
+/// ```
+pub fn resource_target() -> TargetResource {
+    TargetResource::new()
+}
+
 /// Convenient alias for [`Tween`] that [`TargetAsset`] with generic [`Interpolator`].
 #[cfg(feature = "bevy_asset")]
 pub type AssetTween<I> = Tween<TargetAsset<<I as Interpolator>::Item>, I>;
@@ -531,6 +1418,34 @@ impl<A: Asset> TargetAsset<A> {
     }
 
     /// Create a new [`TargetState`] with the initial value out of this target.
+    ///
+    /// Every [`TargetState::with`] clones this target. Cloning
+    /// [`TargetAsset::Asset`] only clones the handle while
+    /// [`TargetAsset::Assets`] clones its [`Vec`] of handles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_tween::interpolate::color_material_to;
+    /// use bevy_tween::prelude::*;
+    ///
+    /// let red = Color::srgb(1., 0., 0.);
+    /// # let material = Handle::<ColorMaterial>::default();
+    /// let my_target = material.into_target();
+    /// let mut my_material_color = my_target.state(Color::WHITE);
+    ///
+    /// // Creating AssetTweens that are tweening from previous value to red
+    /// // then back to white
+    /// let to_red = my_material_color.with(color_material_to(red));
+    /// let to_white = my_material_color.with(color_material_to(Color::WHITE));
+    ///
+    /// let mut material = ColorMaterial::default();
+    /// to_red.interpolator.interpolate(&mut material, 0.);
+    /// assert_eq!(material.color, Color::WHITE);
+    /// to_white.interpolator.interpolate(&mut material, 0.);
+    /// assert_eq!(material.color, red);
+    /// ```
     pub fn state<V>(&self, value: V) -> TargetState<Self, V> {
         TargetState::new(self.clone(), value)
     }
@@ -788,12 +1703,15 @@ pub fn tween_event_system<Data>(
         (
             Entity,
             &TweenEventData<Data>,
-            &bevy_time_runner::TimeSpanProgress,
+            Ref<bevy_time_runner::TimeSpanProgress>,
+            Option<&bevy_time_runner::TimeSpan>,
             Option<&TweenInterpolationValue>,
+            Option<&mut crate::tween_event::RepeatableEvent>,
         ),
         Without<SkipTween>,
     >,
     event_writer: EventWriter<TweenEvent<Data>>,
+    counters: crate::diagnostics::TweenCounters,
 ) where
     Data: Clone + Send + Sync + 'static,
 {
@@ -801,5 +1719,6 @@ pub fn tween_event_system<Data>(
         commands,
         q_tween_event_data,
         event_writer,
+        counters,
     )
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::diagnostics::TweenCounters;
 use bevy::{
     ecs::{query::QueryEntityError, schedule::SystemConfigs},
     utils::{HashMap, HashSet},
@@ -65,6 +66,9 @@ impl core::fmt::Display for QueryEntityErrorWithoutWorld {
 /// make your interpolators work. The trait is only necessary to be used with
 /// this built-in system.
 ///
+/// Tweens are applied on every frame unless [`TweenApplyMode::OnChange`] is
+/// set.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -109,120 +113,209 @@ impl core::fmt::Display for QueryEntityErrorWithoutWorld {
 pub fn apply_component_tween_system<I>(
     q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
     q_tween: Query<
-        (Entity, &Tween<TargetComponent, I>, &TweenInterpolationValue),
+        (
+            Entity,
+            &Tween<TargetComponent, I>,
+            Ref<TweenInterpolationValue>,
+            Option<&TargetGracePeriod>,
+        ),
         Without<SkipTween>,
     >,
     mut q_component: Query<&mut I::Item>,
     mut last_entity_error: Local<HashMap<Entity, QueryEntityErrorWithoutWorld>>,
     mut last_search_error: Local<HashSet<Entity>>,
+    mode: Option<Res<TweenApplyMode>>,
+    counters: TweenCounters,
 ) where
     I: Interpolator + Send + Sync + 'static,
     I::Item: Component,
 {
     let mut entity_error = HashMap::new();
     let mut search_error = HashSet::new();
+    let mut applied = 0;
+    let mut target_errors = 0;
+    let on_change = mode.as_deref().copied().unwrap_or_default()
+        == TweenApplyMode::OnChange;
     q_tween
         .iter()
-        .for_each(|(entity, tween, ease_value)| match &tween.target {
-            TargetComponent::Entities(e) => {
-                e.iter().for_each(|target| {
-                    let mut target_component =
-                        match q_component.get_mut(*target) {
-                            Ok(target_component) => target_component,
-                            Err(e) => {
-                                let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
-                                if last_entity_error
-                                    .get(target)
-                                    .map(|old_e| old_e != &e_no_world)
-                                    .unwrap_or(true)
-                                    && entity_error
+        .for_each(|(entity, tween, ease_value, grace_period)| {
+            let waiting =
+                grace_period.is_some_and(TargetGracePeriod::is_waiting);
+            let skip_unchanged = on_change && !ease_value.is_changed();
+            match &tween.target {
+                TargetComponent::Entities(e) => {
+                    e.iter().for_each(|target| {
+                        let mut target_component =
+                            match q_component.get_mut(*target) {
+                                Ok(target_component) => target_component,
+                                Err(_) if waiting => {
+                                    target_errors += 1;
+                                    return;
+                                }
+                                Err(e) => {
+                                    let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
+                                    if last_entity_error
                                         .get(target)
                                         .map(|old_e| old_e != &e_no_world)
                                         .unwrap_or(true)
-                                {
-                                    error!(
-                                        "{} attempted to tween {} component but got query error: {e}",
-                                        type_name::<I>(),
-                                        type_name::<I::Item>()
-                                    );
+                                        && entity_error
+                                            .get(target)
+                                            .map(|old_e| old_e != &e_no_world)
+                                            .unwrap_or(true)
+                                    {
+                                        error!(
+                                            "{} attempted to tween {} component but got query error: {e}",
+                                            type_name::<I>(),
+                                            type_name::<I::Item>()
+                                        );
+                                    }
+                                    target_errors += 1;
+                                    entity_error.insert(*target, e_no_world);
+                                    return;
                                 }
-                                entity_error.insert(*target, e_no_world);
-                                return;
-                            }
-                        };
-                    tween
-                        .interpolator
-                        .interpolate(&mut target_component, ease_value.0);
-                });
-            }
-            _ => {
-                let target = match &tween.target {
-                    TargetComponent::Marker => {
-                        let mut curr = entity;
-                        let found = 'l: loop {
-                            match q_animation_target.get(curr) {
-                                Ok((parent, has_marker)) => {
-                                    if has_marker {
-                                        break 'l Some(curr);
-                                    } else {
-                                        match parent {
-                                            Some(parent) => curr = parent.get(),
-                                            None => break 'l None,
+                            };
+                        if skip_unchanged && !target_component.is_changed() {
+                            return;
+                        }
+                        tween
+                            .interpolator
+                            .interpolate(&mut target_component, ease_value.0);
+                        applied += 1;
+                    });
+                }
+                _ => {
+                    let target = match &tween.target {
+                        TargetComponent::Marker => {
+                            let mut curr = entity;
+                            let found = 'l: loop {
+                                match q_animation_target.get(curr) {
+                                    Ok((parent, has_marker)) => {
+                                        if has_marker {
+                                            break 'l Some(curr);
+                                        } else {
+                                            match parent {
+                                                Some(parent) => curr = parent.get(),
+                                                None => break 'l None,
+                                            }
                                         }
                                     }
+                                    _ => break 'l None,
                                 }
-                                _ => break 'l None,
-                            }
-                        };
-                        match found {
-                            Some(found) => found,
-                            None => {
-                                if !last_search_error.contains(&entity) && !search_error.contains(&entity) {
-                                    error!(
-                                        "Tween {:?} {} cannot find AnimationTarget marker",
-                                        entity,
-                                        type_name::<I>(),
-                                    );
+                            };
+                            match found {
+                                Some(found) => found,
+                                None if waiting => {
+                                    target_errors += 1;
+                                    return;
                                 }
-                                search_error.insert(entity);
-                                return;
-                            },
+                                None => {
+                                    if !last_search_error.contains(&entity) && !search_error.contains(&entity) {
+                                        error!(
+                                            "Tween {:?} {} cannot find AnimationTarget marker",
+                                            entity,
+                                            type_name::<I>(),
+                                        );
+                                    }
+                                    target_errors += 1;
+                                    search_error.insert(entity);
+                                    return;
+                                },
+                            }
                         }
-                    }
-                    TargetComponent::Entity(e) => *e,
-                    _ => unreachable!(),
-                };
+                        TargetComponent::Entity(e) => *e,
+                        _ => unreachable!(),
+                    };
 
-                let mut target_component = match q_component.get_mut(target) {
-                    Ok(target_component) => target_component,
-                    Err(e) => {
-                        let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
-                        if last_entity_error
-                            .get(&target)
-                            .map(|old_e| old_e != &e_no_world)
-                            .unwrap_or(true)
-                            && entity_error
+                    let mut target_component = match q_component.get_mut(target) {
+                        Ok(target_component) => target_component,
+                        Err(_) if waiting => {
+                            target_errors += 1;
+                            return;
+                        }
+                        Err(e) => {
+                            let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
+                            if last_entity_error
                                 .get(&target)
                                 .map(|old_e| old_e != &e_no_world)
                                 .unwrap_or(true)
-                        {
-                            error!(
-                                "{} attempted to tween {} component but got query error: {e}",
-                                type_name::<I>(),
-                                type_name::<I::Item>()
-                            );
+                                && entity_error
+                                    .get(&target)
+                                    .map(|old_e| old_e != &e_no_world)
+                                    .unwrap_or(true)
+                            {
+                                error!(
+                                    "{} attempted to tween {} component but got query error: {e}",
+                                    type_name::<I>(),
+                                    type_name::<I::Item>()
+                                );
+                            }
+                            target_errors += 1;
+                            entity_error.insert(target, e_no_world);
+                            return;
                         }
-                        entity_error.insert(target, e_no_world);
+                    };
+                    if skip_unchanged && !target_component.is_changed() {
                         return;
                     }
-                };
-                tween
-                    .interpolator
-                    .interpolate(&mut target_component, ease_value.0);
+                    tween
+                        .interpolator
+                        .interpolate(&mut target_component, ease_value.0);
+                    applied += 1;
+                }
             }
         });
     *last_entity_error = entity_error;
     *last_search_error = search_error;
+    counters.add_applied(applied);
+    counters.add_target_errors(target_errors);
+}
+
+/// Alias for [`apply_component_query_tween_system`] and may contains more
+/// systems in the future.
+pub fn component_tween_system_filtered<I, F>() -> SystemConfigs
+where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component,
+    F: Component,
+{
+    apply_component_query_tween_system::<I, F>.into_configs()
+}
+
+/// Apply any [`Tween`] with the [`Interpolator`] that [`TargetQuery`] with
+/// value provided by [`TweenInterpolationValue`] component.
+///
+/// Every tween is applied to every entity with the component `F`, see
+/// [`TargetQuery`].
+#[allow(clippy::type_complexity)]
+pub fn apply_component_query_tween_system<I, F>(
+    q_tween: Query<
+        (&Tween<TargetQuery<F>, I>, Ref<TweenInterpolationValue>),
+        Without<SkipTween>,
+    >,
+    mut q_component: Query<&mut I::Item, With<F>>,
+    mode: Option<Res<TweenApplyMode>>,
+    counters: TweenCounters,
+) where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component,
+    F: Component,
+{
+    let mut applied = 0;
+    let on_change = mode.as_deref().copied().unwrap_or_default()
+        == TweenApplyMode::OnChange;
+    q_tween.iter().for_each(|(tween, ease_value)| {
+        let skip_unchanged = on_change && !ease_value.is_changed();
+        q_component.iter_mut().for_each(|mut target_component| {
+            if skip_unchanged && !target_component.is_changed() {
+                return;
+            }
+            tween
+                .interpolator
+                .interpolate(&mut target_component, ease_value.0);
+            applied += 1;
+        });
+    });
+    counters.add_applied(applied);
 }
 
 /// System alias for [`component_tween_system`] that uses boxed dynamic [`Interpolator`]. (`Box<dyn Interpolator`)
@@ -304,11 +397,13 @@ pub fn apply_resource_tween_system<I>(
     >,
     resource: Option<ResMut<I::Item>>,
     mut last_error: Local<bool>,
+    counters: TweenCounters,
 ) where
     I: Interpolator,
     I::Item: Resource,
 {
     let Some(mut resource) = resource else {
+        counters.add_target_errors(q_tween.iter().len());
         if !*last_error {
             error!(
                 "{} resource tween system cannot find the resource",
@@ -321,7 +416,8 @@ pub fn apply_resource_tween_system<I>(
     *last_error = false;
     q_tween.iter().for_each(|(tween, ease_value)| {
         tween.interpolator.interpolate(&mut resource, ease_value.0);
-    })
+    });
+    counters.add_applied(q_tween.iter().len());
 }
 
 /// System alias for [`apply_resource_tween_system`] that uses boxed dynamic [`Interpolator`]. (`Box<dyn Interpolator`)
@@ -398,19 +494,27 @@ where
 #[allow(clippy::type_complexity)]
 pub fn apply_asset_tween_system<I>(
     q_tween: Query<
-        (&Tween<TargetAsset<I::Item>, I>, &TweenInterpolationValue),
+        (
+            &Tween<TargetAsset<I::Item>, I>,
+            &TweenInterpolationValue,
+            Option<&TargetGracePeriod>,
+        ),
         Without<SkipTween>,
     >,
     asset: Option<ResMut<Assets<I::Item>>>,
     mut last_resource_error: Local<bool>,
     mut last_asset_error: Local<HashSet<AssetId<I::Item>>>,
+    counters: TweenCounters,
 ) where
     I: Interpolator,
     I::Item: Asset,
 {
     let mut asset_error = HashSet::new();
+    let mut applied = 0;
+    let mut target_errors = 0;
 
     let Some(mut asset) = asset else {
+        counters.add_target_errors(q_tween.iter().len());
         if !*last_resource_error {
             error!(
                 "{} asset tween system cannot find the asset resource",
@@ -423,46 +527,62 @@ pub fn apply_asset_tween_system<I>(
     *last_resource_error = false;
     q_tween
         .iter()
-        .for_each(|(tween, ease_value)| match &tween.target {
-            TargetAsset::Asset(a) => {
-                let Some(asset) = asset.get_mut(a) else {
-                    if !last_asset_error.contains(&a.id())
-                        && !asset_error.contains(&a.id())
-                    {
-                        error!(
-                            "{} attempted to tween {} asset {} but it does not exists",
-                                type_name::<I>(),
-                            type_name::<I::Item>(),
-                            a.id()
-                        );
-                    }
-                    asset_error.insert(a.id());
-                    return;
-                };
-                tween.interpolator.interpolate(asset, ease_value.0);
-            }
-            TargetAsset::Assets(assets) => {
-                for a in assets {
-                    let Some(a) = asset.get_mut(a) else {
+        .for_each(|(tween, ease_value, grace_period)| {
+            let waiting =
+                grace_period.is_some_and(TargetGracePeriod::is_waiting);
+            match &tween.target {
+                TargetAsset::Asset(a) => {
+                    let Some(asset) = asset.get_mut(a) else {
+                        target_errors += 1;
+                        if waiting {
+                            return;
+                        }
                         if !last_asset_error.contains(&a.id())
                             && !asset_error.contains(&a.id())
                         {
                             error!(
                                 "{} attempted to tween {} asset {} but it does not exists",
-                                type_name::<I>(),
+                                    type_name::<I>(),
                                 type_name::<I::Item>(),
                                 a.id()
                             );
                         }
                         asset_error.insert(a.id());
-                        continue;
+                        return;
                     };
-                    tween.interpolator.interpolate(a, ease_value.0);
+                    tween.interpolator.interpolate(asset, ease_value.0);
+                    applied += 1;
+                }
+                TargetAsset::Assets(assets) => {
+                    for a in assets {
+                        let Some(a) = asset.get_mut(a) else {
+                            target_errors += 1;
+                            if waiting {
+                                continue;
+                            }
+                            if !last_asset_error.contains(&a.id())
+                                && !asset_error.contains(&a.id())
+                            {
+                                error!(
+                                    "{} attempted to tween {} asset {} but it does not exists",
+                                    type_name::<I>(),
+                                    type_name::<I::Item>(),
+                                    a.id()
+                                );
+                            }
+                            asset_error.insert(a.id());
+                            continue;
+                        };
+                        tween.interpolator.interpolate(a, ease_value.0);
+                        applied += 1;
+                    }
                 }
             }
         });
 
     *last_asset_error = asset_error;
+    counters.add_applied(applied);
+    counters.add_target_errors(target_errors);
 }
 
 /// System alias for [`apply_asset_tween_system`] that uses boxed dynamic [`Interpolator`]. (`Box<dyn Interpolator`)
@@ -476,3 +596,108 @@ where
 {
     apply_asset_tween_system::<Box<dyn Interpolator<Item = A>>>.into_configs()
 }
+
+/// Record [`TweenInterpolationValue`] into [`TweenInterpolationValueHistory`]
+/// whenever the value changed.
+pub fn interpolation_value_history_system(
+    mut query: Query<
+        (
+            &TweenInterpolationValue,
+            &mut TweenInterpolationValueHistory,
+        ),
+        Changed<TweenInterpolationValue>,
+    >,
+) {
+    query.iter_mut().for_each(|(value, mut history)| {
+        history.push(value.0);
+    });
+}
+
+/// Insert [`SkipTween`] to tweens with [`FirstCycleOnly`] and remove
+/// [`FirstCycleOnly`] once their time runner repeats for the first time.
+///
+/// This runs after [`TimeRunnerSet::Progress`] and before
+/// [`TweenSystemSet::UpdateInterpolationValue`] so the tweens are skipped
+/// before they're sampled and applied in the second cycle.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{first_cycle_only, forward, sequence, tween};
+/// use bevy_tween::interpolate::translation;
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// let intro = app.world_mut().spawn(Transform::default()).id();
+/// // Cycles of a second with the intro in the first half
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .repeat(Repeat::Infinitely)
+///     .insert(sequence((
+///         first_cycle_only(tween(
+///             Duration::from_millis(500),
+///             EaseKind::Linear,
+///             intro.into_target().with(translation(Vec3::ZERO, Vec3::X)),
+///         )),
+///         forward(Duration::from_millis(500)),
+///     )));
+/// app.world_mut().flush();
+///
+/// let translation = |app: &App| {
+///     app.world().get::<Transform>(intro).unwrap().translation
+/// };
+/// // Past the intro of the first cycle
+/// for _ in 0..8 {
+///     app.update();
+/// }
+/// assert_eq!(translation(&app), Vec3::X);
+///
+/// app.world_mut().get_mut::<Transform>(intro).unwrap().translation = Vec3::Y;
+/// // Through the start of the second cycle, the intro is never applied
+/// for _ in 0..10 {
+///     app.update();
+///     assert_eq!(translation(&app), Vec3::Y);
+/// }
+/// ```
+///
+/// [`TimeRunnerSet::Progress`]: bevy_time_runner::TimeRunnerSet::Progress
+/// [`TweenSystemSet::UpdateInterpolationValue`]: crate::TweenSystemSet::UpdateInterpolationValue
+pub fn first_cycle_only_system(
+    mut commands: Commands,
+    mut ended: EventReader<bevy_time_runner::TimeRunnerEnded>,
+    q_children: Query<&Children>,
+    q_first_cycle_only: Query<(), With<FirstCycleOnly>>,
+) {
+    for ended in ended.read() {
+        if ended.is_completed() {
+            continue;
+        }
+        let children = q_children
+            .get(ended.time_runner)
+            .map(|children| &children[..])
+            .unwrap_or_default();
+        std::iter::once(&ended.time_runner)
+            .chain(children)
+            .filter(|entity| q_first_cycle_only.contains(**entity))
+            .for_each(|entity| {
+                commands
+                    .entity(*entity)
+                    .insert(SkipTween)
+                    .remove::<FirstCycleOnly>();
+            });
+    }
+}
+
+/// Tick [`TargetGracePeriod`] of tweens that have
+/// [`TimeSpanProgress`](bevy_time_runner::TimeSpanProgress).
+pub fn target_grace_period_system(
+    time: Res<Time>,
+    mut query: Query<
+        &mut TargetGracePeriod,
+        With<bevy_time_runner::TimeSpanProgress>,
+    >,
+) {
+    query.iter_mut().for_each(|mut grace_period| {
+        grace_period.tick(time.delta());
+    });
+}
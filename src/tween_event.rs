@@ -8,9 +8,12 @@
 //!
 //! **Components**:
 //! - [`TweenEventData`]
+//! - [`AnyTweenEvent`]
+//! - [`RepeatableEvent`]
 //!
 //! **Systems**
 //! - [`tween_event_system`]
+//! - [`repeatable_event_system`]
 //!
 //! **Events**:
 //! - [`TweenEvent<Data>`]
@@ -24,17 +27,54 @@
 //!
 //! See [`DefaultTweenEventPlugins`] for default events which is also added in
 //! [`DefaultTweenPlugins`](crate::DefaultTweenPlugins)
+//!
+//! # Instant events
+//!
+//! Events spanning a duration, such as
+//! [`event_for`](crate::combinator::event_for), fire on every frame their
+//! span is active. Events with a zero-length span, such as
+//! [`event`](crate::combinator::event), fire once every time the animation
+//! crosses or enters their instant instead, even if the animation stays at the
+//! instant for several frames, for example, an event at the end of a
+//! completed animation. Leaving the instant re-arms the event so it fires
+//! again on the next repeat or after seeking back across it.
+//!
+//! An instant that's crossed again without being left, for example, by an
+//! animation that repeats faster than a frame or wraps around onto the
+//! instant, keeps its progress and doesn't fire again. Insert
+//! [`RepeatableEvent`] to the event, as
+//! [`repeatable_event`](crate::combinator::repeatable_event) does, to fire it
+//! once in every cycle of a repeating animation its instant is crossed in
+//! instead. With [`RepeatStyle::PingPong`] every pass is a cycle, so the event
+//! fires on both directions crossing the instant and once for an instant where
+//! the animation turns around.
+//!
+//! [`RepeatStyle::PingPong`]: bevy_time_runner::RepeatStyle::PingPong
+//!
+//! # Interpolation value
+//!
+//! An event fired from an entity that's also a tween carries the tween's
+//! sampled value in [`TweenEvent::interpolation_value`], for example, to spawn
+//! a particle at the animated position. Use
+//! [`with_event`](crate::combinator::with_event) to fire events from tweens.
+//! Events on their own entity carry `None`.
 
 use std::marker::PhantomData;
 
 use bevy::{app::PluginGroupBuilder, prelude::*};
 
-use bevy_time_runner::TimeSpanProgress;
+use bevy_time_runner::{TimeRunnerEnded, TimeSpan, TimeSpanProgress};
 
+use crate::diagnostics::TweenCounters;
 use crate::tween::{SkipTween, TweenInterpolationValue};
 
 /// Plugin for simple generic event that fires at a specific time span.
-#[derive(Default)]
+///
+/// [`tween_event_system`] is added in [`TweenSystemSet::TweenEvent`].
+/// See [`TweenEventOrder`] for its order relative to applying tweens.
+///
+/// [`TweenSystemSet::TweenEvent`]: crate::TweenSystemSet::TweenEvent
+/// [`TweenEventOrder`]: crate::TweenEventOrder
 pub struct TweenEventPlugin<Data>
 where
     Data: Send + Sync + 'static + Clone,
@@ -42,6 +82,17 @@ where
     marker: PhantomData<Data>,
 }
 
+impl<Data> Default for TweenEventPlugin<Data>
+where
+    Data: Send + Sync + 'static + Clone,
+{
+    fn default() -> Self {
+        TweenEventPlugin {
+            marker: PhantomData,
+        }
+    }
+}
+
 impl<Data> Plugin for TweenEventPlugin<Data>
 where
     Data: Send + Sync + 'static + Clone,
@@ -54,7 +105,7 @@ where
         app.add_systems(
             app_resource.schedule,
             (tween_event_system::<Data>)
-                .in_set(crate::TweenSystemSet::ApplyTween),
+                .in_set(crate::TweenSystemSet::TweenEvent),
         )
         .add_event::<TweenEvent<Data>>();
     }
@@ -78,6 +129,7 @@ impl PluginGroup for DefaultTweenEventPlugins {
 /// Fires [`TweenEvent`] whenever [`TimeSpanProgress`] and [`TweenEventData`] exist in the same entity.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Component, Reflect)]
 #[reflect(Component)]
+#[require(AnyTweenEvent)]
 pub struct TweenEventData<Data = ()>(pub Data)
 where
     Data: Send + Sync + 'static;
@@ -96,6 +148,27 @@ impl TweenEventData<()> {
     }
 }
 
+/// Marker for entities with [`TweenEventData`] of any data type, required by
+/// [`TweenEventData`] so events can be found without knowing their data type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AnyTweenEvent;
+
+/// Fire this instant event once in every cycle of its time runner the
+/// instant is crossed in, even if the instant is never left.
+/// See [instant events](self#instant-events).
+///
+/// [`repeatable_event_system`] counts the cycles from [`TimeRunnerEnded`] of
+/// the time runner this event is a child of.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct RepeatableEvent {
+    /// Cycles the time runner started since this was inserted
+    pub cycle: u32,
+    /// [`Self::cycle`] the event last fired in
+    pub fired_cycle: Option<u32>,
+}
+
 /// Fires whenever [`TimeSpanProgress`] and [`TweenEventData`] exist in the same entity
 /// by [`tween_event_system`].
 #[derive(Debug, Clone, PartialEq, Event, Reflect)]
@@ -113,24 +186,186 @@ pub struct TweenEvent<Data = ()> {
 /// Fires [`TweenEvent`] with optional user data whenever [`TimeSpanProgress`]
 /// and [`TweenEventData`] exist in the same entity and data is `Some`,
 /// cloning the data.
+///
+/// Events with a zero-length [`TimeSpan`] only fire in the frame their entity
+/// gets [`TimeSpanProgress`], or also the first frame it's changed in a new
+/// cycle for events with [`RepeatableEvent`].
+/// See [instant events](self#instant-events).
+///
+/// # Examples
+///
+/// ```
+/// use bevy_tween::combinator::{event, event_for, forward, sequence};
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// app.world_mut().commands().animation().insert(sequence((
+///     event_for(Duration::from_millis(300), "during"),
+///     forward(Duration::from_millis(200)),
+///     event("end"),
+/// )));
+/// app.world_mut().flush();
+///
+/// let mut cursor = app
+///     .world()
+///     .resource::<Events<TweenEvent<&'static str>>>()
+///     .get_cursor();
+/// let mut fired = Vec::new();
+/// for _ in 0..10 {
+///     app.update();
+///     let events = app.world().resource::<Events<TweenEvent<&'static str>>>();
+///     fired.extend(cursor.read(events).map(|event| event.data));
+/// }
+///
+/// // Fires every frame of the span
+/// assert!(fired.iter().filter(|data| **data == "during").count() > 1);
+/// // Fires once while staying at the end
+/// assert_eq!(fired.iter().filter(|data| **data == "end").count(), 1);
+/// ```
+///
+/// A [repeatable](RepeatableEvent) event fires on every cycle of an
+/// infinitely repeating animation.
+///
+/// ```
+/// use bevy_tween::combinator::{forward, repeatable_event, sequence};
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// for repeat_style in [RepeatStyle::WrapAround, RepeatStyle::PingPong] {
+///     app.world_mut()
+///         .commands()
+///         .animation()
+///         .repeat(Repeat::Infinitely)
+///         .repeat_style(repeat_style)
+///         .insert(sequence((
+///             forward(Duration::from_millis(450)),
+///             repeatable_event("footstep"),
+///             forward(Duration::from_millis(550)),
+///         )));
+/// }
+/// app.world_mut().flush();
+///
+/// let mut cursor = app
+///     .world()
+///     .resource::<Events<TweenEvent<&'static str>>>()
+///     .get_cursor();
+/// let mut fired_frames = Vec::new();
+/// // Three cycles of a second
+/// for frame in 0..30 {
+///     app.update();
+///     let events = app.world().resource::<Events<TweenEvent<&'static str>>>();
+///     for _ in cursor.read(events) {
+///         fired_frames.push(frame);
+///     }
+/// }
+///
+/// // Once per second for each animation
+/// assert_eq!(fired_frames.len(), 6);
+/// for second in 0..3 {
+///     let range = second * 10..(second + 1) * 10;
+///     let fired = fired_frames.iter().filter(|frame| range.contains(*frame));
+///     assert_eq!(fired.count(), 2);
+/// }
+/// ```
+///
+/// A repeatable event at the start of a loop fires once per cycle, and one
+/// where a [`RepeatStyle::PingPong`] animation turns around fires once per
+/// turn.
+///
+/// ```
+/// use bevy_tween::combinator::{forward, repeatable_event, sequence};
+#[doc = crate::utils::doc_app_test_boilerplate!()]
+///
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .repeat(Repeat::Infinitely)
+///     .insert(sequence((
+///         repeatable_event("start"),
+///         forward(Duration::from_secs(1)),
+///     )));
+/// app.world_mut()
+///     .commands()
+///     .animation()
+///     .repeat(Repeat::Infinitely)
+///     .repeat_style(RepeatStyle::PingPong)
+///     .insert(sequence((
+///         forward(Duration::from_secs(1)),
+///         repeatable_event("turn"),
+///     )));
+/// app.world_mut().flush();
+///
+/// let mut cursor = app
+///     .world()
+///     .resource::<Events<TweenEvent<&'static str>>>()
+///     .get_cursor();
+/// let mut fired = Vec::new();
+/// // Two and a half seconds
+/// for frame in 0..25 {
+///     app.update();
+///     let events = app.world().resource::<Events<TweenEvent<&'static str>>>();
+///     fired.extend(cursor.read(events).map(|event| (event.data, frame)));
+/// }
+///
+/// let frames = |data| {
+///     fired
+///         .iter()
+///         .filter(|(d, _)| *d == data)
+///         .map(|(_, frame)| *frame)
+///         .collect::<Vec<_>>()
+/// };
+/// // The start of each of the three cycles
+/// let start = frames("start");
+/// assert_eq!(start.len(), 3);
+/// assert!(start.windows(2).all(|w| w[1] - w[0] >= 5));
+/// // Turning around at the end, but not at the start
+/// assert_eq!(frames("turn").len(), 1);
+/// ```
+///
+/// [`RepeatStyle::PingPong`]: bevy_time_runner::RepeatStyle::PingPong
 #[allow(clippy::type_complexity)]
 pub fn tween_event_system<Data>(
     mut commands: Commands,
-    q_tween_event_data: Query<
+    mut q_tween_event_data: Query<
         (
             Entity,
             &TweenEventData<Data>,
-            &TimeSpanProgress,
+            Ref<TimeSpanProgress>,
+            Option<&TimeSpan>,
             Option<&TweenInterpolationValue>,
+            Option<&mut RepeatableEvent>,
         ),
         Without<SkipTween>,
     >,
     mut event_writer: EventWriter<TweenEvent<Data>>,
+    counters: TweenCounters,
 ) where
     Data: Clone + Send + Sync + 'static,
 {
-    q_tween_event_data.iter().for_each(
-        |(entity, event_data, progress, interpolation_value)| {
+    let mut fired = 0;
+    q_tween_event_data.iter_mut().for_each(
+        |(
+            entity,
+            event_data,
+            progress,
+            span,
+            interpolation_value,
+            repeatable,
+        )| {
+            let instant = span.is_some_and(|span| {
+                span.min().duration() == span.max().duration()
+            });
+            if instant && !progress.is_added() {
+                // Crossed again in a new cycle without being left
+                let crossed_again = progress.is_changed()
+                    && repeatable.as_ref().is_some_and(|repeatable| {
+                        repeatable.fired_cycle != Some(repeatable.cycle)
+                    });
+                if !crossed_again {
+                    return;
+                }
+            }
+            if let Some(mut repeatable) = repeatable {
+                repeatable.fired_cycle = Some(repeatable.cycle);
+            }
             let event = TweenEvent {
                 data: event_data.0.clone(),
                 progress: *progress,
@@ -139,6 +374,37 @@ pub fn tween_event_system<Data>(
             };
             commands.trigger_targets(event.clone(), entity);
             event_writer.send(event);
+            fired += 1;
         },
     );
+    counters.add_events_fired(fired);
+}
+
+/// Count the cycles of [`RepeatableEvent`]s when their time runner repeats.
+///
+/// This runs after [`TimeRunnerSet::Progress`] and before
+/// [`TweenSystemSet::UpdateInterpolationValue`] so the new cycle is counted
+/// before [`tween_event_system`] runs in the same frame.
+///
+/// [`TimeRunnerSet::Progress`]: bevy_time_runner::TimeRunnerSet::Progress
+/// [`TweenSystemSet::UpdateInterpolationValue`]: crate::TweenSystemSet::UpdateInterpolationValue
+pub fn repeatable_event_system(
+    mut ended: EventReader<TimeRunnerEnded>,
+    q_children: Query<&Children>,
+    mut q_repeatable: Query<&mut RepeatableEvent>,
+) {
+    for ended in ended.read() {
+        if ended.is_completed() {
+            continue;
+        }
+        let children = q_children
+            .get(ended.time_runner)
+            .map(|children| &children[..])
+            .unwrap_or_default();
+        for entity in std::iter::once(&ended.time_runner).chain(children) {
+            if let Ok(mut repeatable) = q_repeatable.get_mut(*entity) {
+                repeatable.cycle = repeatable.cycle.wrapping_add(1);
+            }
+        }
+    }
 }
@@ -54,19 +54,33 @@ macro_rules! doc_app_test_boilerplate {
     () => {
         "\
         # use bevy_tween::prelude::*;
-        # use bevy::ecs::world::CommandQueue;
         # use bevy::prelude::*;
+        # use bevy::time::TimeUpdateStrategy;
         #
         # let mut app = App::new();
-        # app.add_plugins((MinimalPlugins, DefaultTweenPlugins));
-        #
-        # let mut queue = CommandQueue::default();
-        # let mut commands = Commands::new(&mut queue, &app.world);\
+        # app.add_plugins((MinimalPlugins, DefaultTweenPlugins))
+        #     .insert_resource(TimeUpdateStrategy::ManualDuration(
+        #         Duration::from_millis(100),
+        #     ));\
         "
     };
 }
 pub(crate) use doc_app_test_boilerplate;
 
+macro_rules! doc_world_test_boilerplate {
+    () => {
+        "\
+        # use bevy_tween::prelude::*;
+        # use bevy::ecs::world::CommandQueue;
+        # use bevy::prelude::*;
+        #
+        # let mut world = World::new();
+        # let mut queue = CommandQueue::default();\
+        "
+    };
+}
+pub(crate) use doc_world_test_boilerplate;
+
 macro_rules! doc_test_boilerplate {
     () => {
         "\
@@ -134,3 +148,12 @@ pub(crate) use doc_test_boilerplate;
 //     }
 //     true
 // }
+
+/// Advance a splitmix64 `state` and return the next pseudo-random value.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}